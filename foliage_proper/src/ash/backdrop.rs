@@ -0,0 +1,284 @@
+use crate::ginkgo::{Ginkgo, Uniform};
+use crate::{Physical, Section};
+use bytemuck::{Pod, Zeroable};
+use wgpu::{
+    BindGroup, BindGroupDescriptor, BindGroupLayout, BindGroupLayoutDescriptor, ColorTargetState,
+    CommandEncoder, Extent3d, LoadOp, Operations, Origin3d, PipelineLayoutDescriptor,
+    RenderPassColorAttachment, RenderPassDescriptor, RenderPipeline, RenderPipelineDescriptor,
+    Sampler, ShaderModuleDescriptor, ShaderSource, ShaderStages, StoreOp, TexelCopyTextureInfo,
+    Texture, TextureDescriptor, TextureDimension, TextureSampleType, TextureUsages, TextureView,
+    TextureViewDescriptor, TextureViewDimension, VertexState,
+};
+
+/// One panel's [`BackdropBlur`](crate::BackdropBlur) as the frame draws it: the Gaussian's
+/// deviation and the box the panel covers, both in physical pixels, and how faded it is.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct BackdropRegion {
+    pub(crate) sigma: f32,
+    pub(crate) bounds: Section<Physical>,
+    pub(crate) opacity: f32,
+}
+/// What each of the three backdrop passes reads from its uniform: which way the blur runs,
+/// how wide it is, and -- for the composite -- how faded the panel is.
+#[repr(C)]
+#[derive(Pod, Zeroable, Copy, Clone, Debug, Default, PartialEq)]
+pub(crate) struct BlurParameters {
+    direction: [f32; 2],
+    sigma: f32,
+    opacity: f32,
+}
+/// Blurs what is already on the surface beneath a panel, for that panel to composite.
+///
+/// The region is copied out of the surface into `scene`, blurred across into `across`,
+/// then down into `down`, which the panel's backdrop pipeline samples. Only the region is
+/// touched, so the rest of each texture holds whatever an earlier frame left there. The
+/// textures follow the surface's size, and are remade when it changes.
+pub(crate) struct Backdrop {
+    pipeline: RenderPipeline,
+    layout: BindGroupLayout,
+    sampler: Sampler,
+    across: Uniform<BlurParameters>,
+    down: Uniform<BlurParameters>,
+    composite: Uniform<BlurParameters>,
+    targets: Option<Targets>,
+}
+/// Each pass's target, with what that pass binds to read the one before it.
+struct Targets {
+    extent: (u32, u32),
+    scene: Texture,
+    across: (TextureView, BindGroup),
+    down: (TextureView, BindGroup),
+    composite: BindGroup,
+}
+impl Backdrop {
+    /// Deviations of blur a tap reaches out to; past three the Gaussian has nothing left.
+    const REACH: f32 = 3.0;
+    pub(crate) fn new(ginkgo: &Ginkgo) -> Self {
+        let shader = ginkgo.create_shader(ShaderModuleDescriptor {
+            label: Some("backdrop-shader"),
+            source: ShaderSource::Wgsl(include_str!("backdrop.wgsl").into()),
+        });
+        let layout = Self::layout(ginkgo);
+        let pipeline_layout = ginkgo.create_pipeline_layout(&PipelineLayoutDescriptor {
+            label: Some("backdrop-pipeline-layout-descriptor"),
+            bind_group_layouts: &[Some(&layout)],
+            immediate_size: 0,
+        });
+        let pipeline = ginkgo.create_pipeline(&RenderPipelineDescriptor {
+            label: Some("backdrop-render-pipeline"),
+            layout: Option::from(&pipeline_layout),
+            vertex: VertexState {
+                module: &shader,
+                entry_point: Option::from("vertex_entry"),
+                compilation_options: Default::default(),
+                buffers: &[],
+            },
+            primitive: Ginkgo::triangle_list_primitive(),
+            depth_stencil: None,
+            multisample: Default::default(),
+            fragment: Ginkgo::fragment_state(
+                &shader,
+                "fragment_entry",
+                &[Some(ColorTargetState {
+                    format: ginkgo.configuration().config.format,
+                    blend: None,
+                    write_mask: Default::default(),
+                })],
+            ),
+            multiview_mask: None,
+            cache: None,
+        });
+        Self {
+            pipeline,
+            layout,
+            sampler: ginkgo.create_sampler(true),
+            across: Uniform::new(ginkgo.context(), BlurParameters::default()),
+            down: Uniform::new(ginkgo.context(), BlurParameters::default()),
+            composite: Uniform::new(ginkgo.context(), BlurParameters::default()),
+            targets: None,
+        }
+    }
+    /// A texture to sample, its sampler and the pass's [`BlurParameters`] -- what the blur
+    /// passes read, and what a pipeline compositing the result binds it through.
+    pub(crate) fn layout(ginkgo: &Ginkgo) -> BindGroupLayout {
+        ginkgo.create_bind_group_layout(&BindGroupLayoutDescriptor {
+            label: Some("backdrop-bind-group-layout"),
+            entries: &[
+                Ginkgo::bind_group_layout_entry(0)
+                    .at_stages(ShaderStages::FRAGMENT)
+                    .texture_entry(
+                        TextureViewDimension::D2,
+                        TextureSampleType::Float { filterable: true },
+                    ),
+                Ginkgo::bind_group_layout_entry(1)
+                    .at_stages(ShaderStages::FRAGMENT)
+                    .sampler_entry(true),
+                Ginkgo::bind_group_layout_entry(2)
+                    .at_stages(ShaderStages::FRAGMENT)
+                    .uniform_entry(),
+            ],
+        })
+    }
+    /// Whether this surface can be read back at all. Not every platform lets its surface be
+    /// copied from, and a multisampled frame only resolves into it as each pass ends.
+    pub(crate) fn supported(ginkgo: &Ginkgo) -> bool {
+        let configuration = ginkgo.configuration();
+        configuration.msaa.samples() == 1
+            && configuration.config.usage.contains(TextureUsages::COPY_SRC)
+    }
+    /// Sets the blur and fade the next [`blur`](Self::blur) draws with. Lands with the next
+    /// submission, so everything drawn at the previous setting must be submitted first.
+    pub(crate) fn write(&mut self, ginkgo: &Ginkgo, region: BackdropRegion) {
+        let parameters = |direction| BlurParameters {
+            direction,
+            sigma: region.sigma,
+            opacity: region.opacity,
+        };
+        self.across.write(ginkgo.context(), parameters([1.0, 0.0]));
+        self.down.write(ginkgo.context(), parameters([0.0, 1.0]));
+        self.composite
+            .write(ginkgo.context(), parameters([0.0, 0.0]));
+    }
+    /// Copies `region` of `surface` and blurs it, returning what to bind for compositing it
+    /// through `composite_layout`. `None` when the region is off the surface.
+    pub(crate) fn blur(
+        &mut self,
+        ginkgo: &Ginkgo,
+        encoder: &mut CommandEncoder,
+        surface: &Texture,
+        region: BackdropRegion,
+        composite_layout: &BindGroupLayout,
+    ) -> Option<&BindGroup> {
+        let extent = (surface.width(), surface.height());
+        if self.targets.as_ref().map(|t| t.extent) != Some(extent) {
+            self.targets
+                .replace(self.targets(ginkgo, extent, composite_layout));
+        }
+        let targets = self.targets.as_ref().unwrap();
+        let reach = (region.sigma * Self::REACH).ceil();
+        let drawn = Self::pixels(region.bounds, 0.0, extent)?;
+        let read = Self::pixels(region.bounds, reach, extent)?;
+        encoder.copy_texture_to_texture(
+            TexelCopyTextureInfo {
+                texture: surface,
+                mip_level: 0,
+                origin: Origin3d {
+                    x: read.0,
+                    y: read.1,
+                    z: 0,
+                },
+                aspect: Default::default(),
+            },
+            TexelCopyTextureInfo {
+                texture: &targets.scene,
+                mip_level: 0,
+                origin: Origin3d {
+                    x: read.0,
+                    y: read.1,
+                    z: 0,
+                },
+                aspect: Default::default(),
+            },
+            Extent3d {
+                width: read.2,
+                height: read.3,
+                depth_or_array_layers: 1,
+            },
+        );
+        // Across over every row the pass down will read, then down over the panel alone.
+        for ((view, source), scissor) in [(&targets.across, read), (&targets.down, drawn)] {
+            let mut pass = encoder.begin_render_pass(&RenderPassDescriptor {
+                multiview_mask: None,
+                label: Some("backdrop-pass"),
+                color_attachments: &[Some(RenderPassColorAttachment {
+                    view,
+                    depth_slice: None,
+                    resolve_target: None,
+                    ops: Operations {
+                        load: LoadOp::Load,
+                        store: StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            pass.set_pipeline(&self.pipeline);
+            pass.set_bind_group(0, source, &[]);
+            pass.set_scissor_rect(scissor.0, scissor.1, scissor.2, scissor.3);
+            pass.draw(0..3, 0..1);
+        }
+        Some(&targets.composite)
+    }
+    /// `bounds` grown by `by`, out to whole pixels and cut to the surface, as
+    /// `(x, y, width, height)`. `None` if nothing is left.
+    fn pixels(
+        bounds: Section<Physical>,
+        by: f32,
+        extent: (u32, u32),
+    ) -> Option<(u32, u32, u32, u32)> {
+        let left = (bounds.left() - by).floor().max(0.0);
+        let top = (bounds.top() - by).floor().max(0.0);
+        let right = (bounds.right() + by).ceil().min(extent.0 as f32);
+        let bottom = (bounds.bottom() + by).ceil().min(extent.1 as f32);
+        if right <= left || bottom <= top {
+            return None;
+        }
+        Some((
+            left as u32,
+            top as u32,
+            (right - left) as u32,
+            (bottom - top) as u32,
+        ))
+    }
+    fn targets(
+        &self,
+        ginkgo: &Ginkgo,
+        extent: (u32, u32),
+        composite_layout: &BindGroupLayout,
+    ) -> Targets {
+        let texture = |label, usage| {
+            ginkgo.context().device.create_texture(&TextureDescriptor {
+                label: Some(label),
+                size: Extent3d {
+                    width: extent.0,
+                    height: extent.1,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: TextureDimension::D2,
+                format: ginkgo.configuration().config.format,
+                usage: usage | TextureUsages::TEXTURE_BINDING,
+                view_formats: &[],
+            })
+        };
+        let scene = texture("backdrop-scene", TextureUsages::COPY_DST);
+        let across = texture("backdrop-across", TextureUsages::RENDER_ATTACHMENT)
+            .create_view(&TextureViewDescriptor::default());
+        let down = texture("backdrop-down", TextureUsages::RENDER_ATTACHMENT)
+            .create_view(&TextureViewDescriptor::default());
+        let bind_group = |layout, view, uniform| {
+            ginkgo.create_bind_group(&BindGroupDescriptor {
+                label: Some("backdrop-bind-group"),
+                layout,
+                entries: &[
+                    Ginkgo::texture_bind_group_entry(view, 0),
+                    Ginkgo::sampler_bind_group_entry(&self.sampler, 1),
+                    Ginkgo::uniform_bind_group_entry(uniform, 2),
+                ],
+            })
+        };
+        let scene_view = scene.create_view(&TextureViewDescriptor::default());
+        let read_scene = bind_group(&self.layout, &scene_view, &self.across);
+        let read_across = bind_group(&self.layout, &across, &self.down);
+        let composite = bind_group(composite_layout, &down, &self.composite);
+        Targets {
+            extent,
+            scene,
+            across: (across, read_scene),
+            down: (down, read_across),
+            composite,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+// One direction of a separable Gaussian, over whatever the scissor leaves of the target.
+// `blur.sigma` is the deviation in physical pixels; `blur.direction` is across or down.
+struct Blur {
+    direction: vec2<f32>,
+    sigma: f32,
+    opacity: f32,
+};
+@group(0)
+@binding(0)
+var source: texture_2d<f32>;
+@group(0)
+@binding(1)
+var source_sampler: sampler;
+@group(0)
+@binding(2)
+var<uniform> blur: Blur;
+@vertex
+fn vertex_entry(@builtin(vertex_index) index: u32) -> @builtin(position) vec4<f32> {
+    // One triangle over the whole target; the scissor keeps it to the region.
+    let corner = vec2<f32>(f32((index << 1u) & 2u), f32(index & 2u));
+    return vec4<f32>(corner * vec2<f32>(2.0, -2.0) + vec2<f32>(-1.0, 1.0), 0.0, 1.0);
+}
+@fragment
+fn fragment_entry(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    let size = vec2<f32>(textureDimensions(source));
+    if blur.sigma <= 0.0 {
+        return textureSampleLevel(source, source_sampler, position.xy / size, 0.0);
+    }
+    // Taps out to three deviations, at most 24 a side: a wider blur spaces them further
+    // apart and leans on the sampler's filtering between them.
+    let reach = 3.0 * blur.sigma;
+    let taps = i32(min(ceil(reach), 24.0));
+    let step = reach / f32(taps);
+    var sum = vec4<f32>(0.0);
+    var total = 0.0;
+    for (var i = -taps; i <= taps; i++) {
+        let x = f32(i) * step;
+        let weight = exp(-0.5 * x * x / (blur.sigma * blur.sigma));
+        let at = (position.xy + blur.direction * x) / size;
+        sum += weight * textureSampleLevel(source, source_sampler, at, 0.0);
+        total += weight;
+    }
+    return sum / total;
+}
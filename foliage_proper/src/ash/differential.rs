@@ -150,20 +150,27 @@ impl<R: Clone + Send + Sync + 'static> RenderRemoveQueue<R> {
 }
 pub(crate) struct RenderQueueHandle<'a> {
     pub(crate) world: &'a mut World,
+    /// Every entity something was read for this frame, updated or removed -- what a
+    /// [`Layer`](crate::Layer) holding one of them must be redrawn for.
+    pub(crate) touched: HashSet<Entity>,
 }
 impl<'a> RenderQueueHandle<'a> {
     pub(crate) fn new(world: &'a mut World) -> Self {
-        Self { world }
+        Self {
+            world,
+            touched: HashSet::new(),
+        }
     }
     /// Read without draining, so [`attribute`](Self::attribute) can still see what was
     /// removed. Cleared once per frame by [`clear_removes`](Self::clear_removes).
     pub(crate) fn removes<R: Clone + Send + Sync + 'static>(&mut self) -> Vec<Entity> {
-        self.world
+        let removed = self
+            .world
             .get_resource::<RenderRemoveQueue<R>>()
             .unwrap()
-            .all()
-            .into_iter()
-            .collect()
+            .all();
+        self.touched.extend(removed.iter().copied());
+        removed.into_iter().collect()
     }
     /// Ends the frame for `R`'s removals. Called once, after every pipeline has prepared.
     pub(crate) fn clear_removes<R: Clone + Send + Sync + 'static>(&mut self) {
@@ -207,12 +214,16 @@ impl<'a> RenderQueueHandle<'a> {
             .get_resource::<RenderRemoveQueue<R>>()
             .map(|q| q.all())
             .unwrap_or_default();
-        self.world
+        let drained = self
+            .world
             .get_resource_mut::<RenderQueue<R, RP>>()
             .unwrap()
             .queue
             .drain()
             .filter(|(entity, _)| !removed.contains(entity))
-            .collect()
+            .collect::<Vec<_>>();
+        self.touched
+            .extend(drained.iter().map(|(entity, _)| *entity));
+        drained
     }
 }
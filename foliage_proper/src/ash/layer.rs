@@ -0,0 +1,385 @@
+use crate::ash::node::Node;
+use crate::ash::render::{ContiguousSpan, PipelineId};
+use crate::ginkgo::depth::Depth;
+use crate::ginkgo::{Ginkgo, Uniform};
+use crate::opacity::{InheritedOpacity, Opacity};
+use crate::{AsTree, Component, Parent, Physical, ResolvedElevation, ResolvedVisibility, Section};
+use bevy_ecs::entity::Entity;
+use bevy_ecs::lifecycle::HookContext;
+use bevy_ecs::query::With;
+use bevy_ecs::world::{DeferredWorld, World};
+use bytemuck::{Pod, Zeroable};
+use std::collections::{HashMap, HashSet};
+use wgpu::{
+    BindGroup, BindGroupDescriptor, BindGroupLayout, BindGroupLayoutDescriptor, BlendState,
+    ColorTargetState, CompareFunction, DepthStencilState, Extent3d, PipelineLayoutDescriptor,
+    RenderPass, RenderPipeline, RenderPipelineDescriptor, ShaderModuleDescriptor, ShaderSource,
+    ShaderStages, TextureDescriptor, TextureDimension, TextureSampleType, TextureUsages,
+    TextureView, TextureViewDescriptor, TextureViewDimension, VertexState,
+};
+
+/// Draws an element and everything beneath it into a texture of its own, then lays that
+/// texture over the frame in one piece. Set with [`layer`](crate::Sprout::layer).
+///
+/// Two things come of it. [`Opacity`] fades the subtree as one picture: without a layer each
+/// element is faded on its own, so a half-transparent card shows its own background through
+/// its text, and every overlap inside it, where a layer shows the card as it would look
+/// opaque, then faded. And the texture is kept: until something inside changes -- a color,
+/// a move, an element shown or hidden -- later frames lay the same texture down again
+/// rather than drawing the subtree, so a busy but static one costs one draw. Fading or
+/// restacking the layer itself redraws nothing inside it.
+///
+/// Each layer holds a texture the size of the window, four bytes a pixel, for as long as it
+/// is shown -- reach for it on a handful of elements, not every row of a list. A subtree
+/// that changes every frame gains no caching, and pays a pass of its own besides.
+///
+/// Everything inside stacks with the layer's root: something elsewhere in the tree lands
+/// wholly in front of or behind it, never between two of its elements, and a
+/// [`clip_to_viewport`](crate::Sprout::clip_to_viewport) overlay inside rises no further
+/// than the root does. A [`BackdropBlur`](crate::BackdropBlur) inside has nothing beneath it
+/// to blur and draws as a plain panel. Layers nest.
+#[derive(Component, Copy, Clone, Default, Debug, PartialEq)]
+#[component(on_insert = Layer::on_insert)]
+#[component(on_remove = Layer::on_remove)]
+pub struct Layer;
+impl Layer {
+    /// Sends opacity back down from here: inside a layer the root draws as if unfaded, and
+    /// its fade waits for the composite.
+    fn on_insert(mut world: DeferredWorld, ctx: HookContext) {
+        if let Some(inherited) = world.get::<InheritedOpacity>(ctx.entity).copied() {
+            world.tree().write_to(ctx.entity, inherited);
+        }
+    }
+    /// Multiplies the root's fade back down the subtree, now that no composite applies it.
+    fn on_remove(mut world: DeferredWorld, ctx: HookContext) {
+        let this = ctx.entity;
+        // A despawn reaches this hook too; only a root still standing once this lands has had
+        // its layer taken off. Written again, its `Opacity` blends without the layer.
+        world.commands().queue(move |world: &mut World| {
+            let Some(opacity) = world.get::<Opacity>(this).copied() else {
+                return;
+            };
+            world.tree().write_to(this, opacity);
+        });
+    }
+}
+/// One shown layer as the frame draws it.
+pub(crate) struct Layered {
+    /// The layer this one is drawn into, if it is nested; the frame itself otherwise.
+    pub(crate) outer: Option<Entity>,
+    /// Where the composite stacks among whatever is drawn alongside it.
+    pub(crate) node: Node,
+    /// The root's own fade and everything above it, applied once, at the composite.
+    pub(crate) opacity: f32,
+    /// What the layer's own pass draws, in order -- its members, and the composites of the
+    /// layers nested directly inside it.
+    pub(crate) spans: Vec<ContiguousSpan>,
+}
+#[repr(C)]
+#[derive(Pod, Zeroable, Copy, Clone, Debug, Default, PartialEq)]
+struct Composite {
+    opacity: f32,
+    _padding: [f32; 3],
+}
+/// One layer's texture, with what the composite binds to read it.
+struct Target {
+    view: TextureView,
+    composite: Uniform<Composite>,
+    bind_group: BindGroup,
+}
+/// Every shown [`Layer`], what each draws, and the textures they draw into.
+///
+/// A layer's pass runs before the frame's, so it borrows the frame's depth and multisample
+/// targets and leaves them to be cleared again. Its texture is redrawn only when marked
+/// stale: something inside it was sent to the renderer, a layer nested in it changed, or
+/// the surface or viewport moved under all of them.
+pub(crate) struct Layers {
+    pipeline: RenderPipeline,
+    layout: BindGroupLayout,
+    pub(crate) shown: HashMap<Entity, Layered>,
+    /// Which layer each drawn entity landed in, for entities gone from the world by the time
+    /// their removal is read.
+    pub(crate) membership: HashMap<Entity, Entity>,
+    targets: HashMap<Entity, Target>,
+    stale: HashSet<Entity>,
+    fitted: Option<((u32, u32), Section<Physical>)>,
+}
+impl Layers {
+    pub(crate) fn new(ginkgo: &Ginkgo) -> Self {
+        let shader = ginkgo.create_shader(ShaderModuleDescriptor {
+            label: Some("layer-shader"),
+            source: ShaderSource::Wgsl(include_str!("layer.wgsl").into()),
+        });
+        let layout = ginkgo.create_bind_group_layout(&BindGroupLayoutDescriptor {
+            label: Some("layer-bind-group-layout"),
+            entries: &[
+                Ginkgo::bind_group_layout_entry(0)
+                    .at_stages(ShaderStages::FRAGMENT)
+                    .texture_entry(
+                        TextureViewDimension::D2,
+                        TextureSampleType::Float { filterable: false },
+                    ),
+                Ginkgo::bind_group_layout_entry(1)
+                    .at_stages(ShaderStages::FRAGMENT)
+                    .uniform_entry(),
+            ],
+        });
+        let pipeline_layout = ginkgo.create_pipeline_layout(&PipelineLayoutDescriptor {
+            label: Some("layer-pipeline-layout-descriptor"),
+            bind_group_layouts: &[Some(&layout)],
+            immediate_size: 0,
+        });
+        let pipeline = ginkgo.create_pipeline(&RenderPipelineDescriptor {
+            label: Some("layer-render-pipeline"),
+            layout: Option::from(&pipeline_layout),
+            vertex: VertexState {
+                module: &shader,
+                entry_point: Option::from("vertex_entry"),
+                compilation_options: Default::default(),
+                buffers: &[],
+            },
+            primitive: Ginkgo::triangle_list_primitive(),
+            // Covers the whole frame, so it neither tests nor writes depth: the sort already
+            // put it where it goes, and its empty pixels must not hide what comes after.
+            depth_stencil: Some(DepthStencilState {
+                format: Depth::FORMAT,
+                depth_write_enabled: Some(false),
+                depth_compare: Some(CompareFunction::Always),
+                stencil: Default::default(),
+                bias: Default::default(),
+            }),
+            multisample: ginkgo.msaa_state(),
+            fragment: Ginkgo::fragment_state(
+                &shader,
+                "fragment_entry",
+                // Drawn over transparent with straight alpha, the texture holds premultiplied
+                // color.
+                &[Some(ColorTargetState {
+                    format: ginkgo.configuration().config.format,
+                    blend: Some(BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+                    write_mask: Default::default(),
+                })],
+            ),
+            multiview_mask: None,
+            cache: None,
+        });
+        Self {
+            pipeline,
+            layout,
+            shown: HashMap::new(),
+            membership: HashMap::new(),
+            targets: HashMap::new(),
+            stale: HashSet::new(),
+            fitted: None,
+        }
+    }
+    /// Reads which layers are shown, where each stacks and how faded it is. `true` when the
+    /// set or its stacking changed, and the frame's spans must be cut again.
+    pub(crate) fn scan(&mut self, world: &mut World) -> bool {
+        let found = world
+            .query_filtered::<(
+                Entity,
+                &ResolvedVisibility,
+                Option<&ResolvedElevation>,
+                &Opacity,
+                &InheritedOpacity,
+            ), With<Layer>>()
+            .iter(world)
+            .filter(|(_, visibility, ..)| visibility.visible())
+            .map(|(entity, _, elevation, opacity, inherited)| {
+                (
+                    entity,
+                    elevation.copied().unwrap_or_default(),
+                    opacity.value * inherited.value,
+                )
+            })
+            .collect::<Vec<_>>();
+        let keys = found.iter().map(|(e, ..)| *e).collect::<HashSet<_>>();
+        let mut changed = false;
+        for (gone, layered) in self.shown.extract_if(|e, _| !keys.contains(e)) {
+            self.targets.remove(&gone);
+            self.stale.extend(layered.outer);
+            changed = true;
+        }
+        for (entity, elevation, opacity) in found {
+            let outer = world
+                .get::<Parent>(entity)
+                .and_then(|p| p.id)
+                .and_then(|p| Self::enclosing(world, p, &keys));
+            let node = Node::new(
+                elevation,
+                PipelineId::Layer,
+                entity.to_bits(),
+                0,
+                Parent::none(),
+                0,
+            );
+            match self.shown.get_mut(&entity) {
+                Some(layered) => {
+                    layered.opacity = opacity;
+                    if layered.outer != outer || layered.node.elevation != node.elevation {
+                        self.stale.extend(layered.outer);
+                        self.stale.extend(outer);
+                        layered.outer = outer;
+                        layered.node = node;
+                        changed = true;
+                    }
+                }
+                None => {
+                    self.stale.extend(outer);
+                    self.shown.insert(
+                        entity,
+                        Layered {
+                            outer,
+                            node,
+                            opacity,
+                            spans: vec![],
+                        },
+                    );
+                    changed = true;
+                }
+            }
+        }
+        changed
+    }
+    /// The nearest of `layers` at or above `entity`.
+    pub(crate) fn enclosing(
+        world: &World,
+        entity: Entity,
+        layers: &HashSet<Entity>,
+    ) -> Option<Entity> {
+        let mut at = Some(entity);
+        while let Some(current) = at {
+            if layers.contains(&current) {
+                return Some(current);
+            }
+            at = world.get::<Parent>(current).and_then(|p| p.id);
+        }
+        None
+    }
+    /// Marks stale every layer holding `entity`, out to the outermost.
+    pub(crate) fn touch(&mut self, world: &World, entity: Entity) {
+        if self.shown.is_empty() {
+            return;
+        }
+        let mut layer = if world.get_entity(entity).is_ok() {
+            let keys = self.shown.keys().copied().collect();
+            Self::enclosing(world, entity, &keys)
+        } else {
+            self.membership.get(&entity).copied()
+        };
+        while let Some(current) = layer {
+            if !self.stale.insert(current) {
+                break;
+            }
+            layer = self.shown.get(&current).and_then(|l| l.outer);
+        }
+    }
+    /// Marks stale every layer drawing under `clip`, whose bounds just changed.
+    pub(crate) fn touch_clip(&mut self, clip: Parent) {
+        let hit = self
+            .shown
+            .iter()
+            .filter(|(_, l)| l.spans.iter().any(|s| s.clip_context == clip))
+            .map(|(e, _)| *e)
+            .collect::<Vec<_>>();
+        self.stale.extend(hit);
+    }
+    /// Drops every texture when the surface is resized or the viewport moves, since what
+    /// each holds was drawn for the old one.
+    pub(crate) fn fit(&mut self, extent: (u32, u32), viewport: Section<Physical>) {
+        if self.fitted != Some((extent, viewport)) {
+            self.targets.clear();
+            self.fitted.replace((extent, viewport));
+        }
+    }
+    /// The layers to draw this frame, innermost first, so each composite reads a texture its
+    /// own pass already finished. Clears the marks.
+    pub(crate) fn stale(&mut self) -> Vec<Entity> {
+        let mut stale = self
+            .shown
+            .keys()
+            .filter(|e| self.stale.contains(e) || !self.targets.contains_key(e))
+            .map(|e| (*e, self.depth(*e)))
+            .collect::<Vec<_>>();
+        stale.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        self.stale.clear();
+        stale.into_iter().map(|(e, _)| e).collect()
+    }
+    fn depth(&self, entity: Entity) -> usize {
+        let mut depth = 0;
+        let mut at = self.shown.get(&entity).and_then(|l| l.outer);
+        while let Some(outer) = at {
+            depth += 1;
+            at = self.shown.get(&outer).and_then(|l| l.outer);
+        }
+        depth
+    }
+    /// The texture `entity`'s pass draws into, made on first use.
+    pub(crate) fn target(&mut self, ginkgo: &Ginkgo, entity: Entity) -> &TextureView {
+        let (width, height) = self.fitted.map(|f| f.0).unwrap_or((1, 1));
+        let layout = &self.layout;
+        &self
+            .targets
+            .entry(entity)
+            .or_insert_with(|| {
+                let view = ginkgo
+                    .context()
+                    .device
+                    .create_texture(&TextureDescriptor {
+                        label: Some("layer"),
+                        size: Extent3d {
+                            width,
+                            height,
+                            depth_or_array_layers: 1,
+                        },
+                        mip_level_count: 1,
+                        sample_count: 1,
+                        dimension: TextureDimension::D2,
+                        format: ginkgo.configuration().config.format,
+                        usage: TextureUsages::RENDER_ATTACHMENT | TextureUsages::TEXTURE_BINDING,
+                        view_formats: &[],
+                    })
+                    .create_view(&TextureViewDescriptor::default());
+                let composite = Uniform::new(ginkgo.context(), Composite::default());
+                let bind_group = ginkgo.create_bind_group(&BindGroupDescriptor {
+                    label: Some("layer-bind-group"),
+                    layout,
+                    entries: &[
+                        Ginkgo::texture_bind_group_entry(&view, 0),
+                        Ginkgo::uniform_bind_group_entry(&composite, 1),
+                    ],
+                });
+                Target {
+                    view,
+                    composite,
+                    bind_group,
+                }
+            })
+            .view
+    }
+    /// Brings each texture's fade up to date with its layer's.
+    pub(crate) fn write(&mut self, ginkgo: &Ginkgo) {
+        for (entity, target) in self.targets.iter_mut() {
+            let Some(layered) = self.shown.get(entity) else {
+                continue;
+            };
+            let composite = Composite {
+                opacity: layered.opacity,
+                ..Default::default()
+            };
+            if target.composite.data != composite {
+                target.composite.data = composite;
+                target.composite.write(ginkgo.context(), composite);
+            }
+        }
+    }
+    /// Lays `entity`'s texture over whatever the pass has drawn so far.
+    pub(crate) fn composite(&self, render_pass: &mut RenderPass, entity: Entity) {
+        if let Some(target) = self.targets.get(&entity) {
+            render_pass.set_pipeline(&self.pipeline);
+            render_pass.set_bind_group(0, &target.bind_group, &[]);
+            render_pass.draw(0..3, 0..1);
+        }
+    }
+}
@@ -0,0 +1,21 @@
+// One `Layer`'s texture laid over the frame, faded by the layer's opacity. The texture is
+// the surface's size and holds premultiplied color, so each fragment reads its own pixel.
+struct Composite {
+    opacity: f32,
+};
+@group(0)
+@binding(0)
+var layer: texture_2d<f32>;
+@group(0)
+@binding(1)
+var<uniform> composite: Composite;
+@vertex
+fn vertex_entry(@builtin(vertex_index) index: u32) -> @builtin(position) vec4<f32> {
+    // One triangle over the whole target; the scissor keeps it to the region.
+    let corner = vec2<f32>(f32((index << 1u) & 2u), f32(index & 2u));
+    return vec4<f32>(corner * vec2<f32>(2.0, -2.0) + vec2<f32>(-1.0, 1.0), 0.0, 1.0);
+}
+@fragment
+fn fragment_entry(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    return textureLoad(layer, vec2<i32>(position.xy), 0) * composite.opacity;
+}
@@ -1,25 +1,31 @@
+use crate::ash::backdrop::{Backdrop, BackdropRegion};
 use crate::ash::clip::{ClipSection, ResolvedClip};
 use crate::ash::differential::RenderQueueHandle;
+use crate::ash::layer::Layers;
 use crate::coordinate::elevation::StackKey;
 use crate::foliage::Foliage;
 use crate::ginkgo::viewport::ViewportHandle;
 use crate::ginkgo::{Ginkgo, ScaleFactor};
 use crate::image::Image;
 use crate::line::LineQuad;
+use crate::path::Path;
 use crate::polygon::Polygon;
+use crate::shader::Shader;
 use crate::willow::NearFarDescriptor;
-use crate::{Attachment, Color, Icon, Panel, Parent, ResolvedElevation, Text};
+use crate::{Attachment, Color, Icon, Panel, Parent, Physical, ResolvedElevation, Section, Text};
 use bevy_ecs::entity::Entity;
 use bevy_ecs::prelude::Resource;
 use bevy_ecs::world::World;
 use node::Node;
-use render::{ContiguousSpan, PipelineId, Render, Renderer};
+use render::{ContiguousSpan, Parameters, PipelineId, Render, Renderer};
 use std::collections::{HashMap, HashSet};
-use wgpu::{CommandEncoderDescriptor, RenderPassDescriptor, TextureViewDescriptor};
+use wgpu::{CommandEncoderDescriptor, RenderPass, RenderPassDescriptor, TextureViewDescriptor};
 
+pub(crate) mod backdrop;
 pub(crate) mod clip;
 pub(crate) mod differential;
 pub(crate) mod instance;
+pub(crate) mod layer;
 pub(crate) mod node;
 pub(crate) mod render;
 
@@ -60,6 +66,10 @@ pub(crate) struct Ash {
     pub(crate) icon: Option<Renderer<Icon>>,
     pub(crate) line: Option<Renderer<LineQuad>>,
     pub(crate) polygon: Option<Renderer<Polygon>>,
+    pub(crate) path: Option<Renderer<Path>>,
+    pub(crate) shader: Option<Renderer<Shader>>,
+    pub(crate) backdrop: Option<Backdrop>,
+    pub(crate) layers: Option<Layers>,
     pub(crate) clip: HashMap<Parent, ClipSection>,
     /// entities with a `StackKey`, sorted least-in-front-first by `(StackKey, entity id)`
     /// (`StackKey`'s own `Ord`: greater = more in front; the entity-id tiebreak gives genuine
@@ -89,6 +99,10 @@ impl Ash {
             icon: None,
             line: None,
             polygon: None,
+            path: None,
+            shader: None,
+            backdrop: None,
+            layers: None,
             clip: Default::default(),
             elevation_order: vec![],
             stack_key_cache: HashMap::new(),
@@ -101,6 +115,10 @@ impl Ash {
         self.icon.replace(Icon::renderer(ginkgo));
         self.line.replace(LineQuad::renderer(ginkgo));
         self.polygon.replace(Polygon::renderer(ginkgo));
+        self.path.replace(Path::renderer(ginkgo));
+        self.shader.replace(Shader::renderer(ginkgo));
+        self.backdrop.replace(Backdrop::new(ginkgo));
+        self.layers.replace(Layers::new(ginkgo));
     }
     /// Keeps `ResolvedElevation` (the GPU-facing f32 every pipeline already consumes,
     /// unchanged) consistent with `StackKey`'s tree-structured ordering, via a gapped/
@@ -219,8 +237,10 @@ impl Ash {
     pub(crate) fn prepare(&mut self, world: &mut World, ginkgo: &Ginkgo) {
         self.assign_elevations(world);
         let mut queues = RenderQueueHandle::new(world);
+        let mut clips = vec![];
         for (entity, clip) in queues.attribute::<(), ResolvedClip>() {
             self.clip.insert(Parent::some(entity), ClipSection(clip.0));
+            clips.push(Parent::some(entity));
         }
         let mut nodes = vec![];
         let mut to_remove = vec![];
@@ -242,6 +262,12 @@ impl Ash {
         let polygon_nodes = Render::prepare(self.polygon.as_mut().unwrap(), &mut queues, ginkgo);
         nodes.extend(polygon_nodes.updated);
         to_remove.extend(polygon_nodes.removed);
+        let path_nodes = Render::prepare(self.path.as_mut().unwrap(), &mut queues, ginkgo);
+        nodes.extend(path_nodes.updated);
+        to_remove.extend(path_nodes.removed);
+        let shader_nodes = Render::prepare(self.shader.as_mut().unwrap(), &mut queues, ginkgo);
+        nodes.extend(shader_nodes.updated);
+        to_remove.extend(shader_nodes.removed);
         // Removals stay readable for the whole pass so `attribute` can skip entities that
         // were torn down this frame; this is where the frame ends for them. Before the early
         // return below, or a frame with nothing to draw would leave them queued forever.
@@ -251,7 +277,21 @@ impl Ash {
         queues.clear_removes::<Icon>();
         queues.clear_removes::<LineQuad>();
         queues.clear_removes::<Polygon>();
-        if nodes.is_empty() && to_remove.is_empty() {
+        queues.clear_removes::<Path>();
+        queues.clear_removes::<Shader>();
+        let restack = std::mem::take(&mut self.panel.as_mut().unwrap().resources.backdrop_changed);
+        // Read before the early return: a layer faded or restacked with nothing inside it
+        // changing still has to be composited differently.
+        let touched = std::mem::take(&mut queues.touched);
+        let layers = self.layers.as_mut().unwrap();
+        let relayer = layers.scan(world);
+        for entity in touched {
+            layers.touch(world, entity);
+        }
+        for clip in clips {
+            layers.touch_clip(clip);
+        }
+        if nodes.is_empty() && to_remove.is_empty() && !restack && !relayer {
             return;
         }
         let mut idxs = to_remove
@@ -288,7 +328,57 @@ impl Ash {
         for node in to_add {
             self.nodes.push(node);
         }
-        self.nodes.sort_by(|lhs, rhs| {
+        Self::sort(&mut self.nodes);
+        let layers = self.layers.as_ref().unwrap();
+        if layers.shown.is_empty() {
+            self.contiguous = self.contiguous(&self.nodes, true);
+            self.layers.as_mut().unwrap().membership.clear();
+            return;
+        }
+        // Each node to the nearest layer above it, or the frame; each layer's composite to
+        // the one it is nested in.
+        let keys = layers.shown.keys().copied().collect::<HashSet<_>>();
+        let mut frame = vec![];
+        let mut within = HashMap::<Entity, Vec<Node>>::new();
+        let mut membership = HashMap::new();
+        for node in self.nodes.iter() {
+            let entity = match node.pipeline {
+                PipelineId::Text | PipelineId::Path | PipelineId::Shader => {
+                    Entity::from_bits(node.group)
+                }
+                _ => Entity::from_bits(node.instance_id),
+            };
+            match Layers::enclosing(world, entity, &keys) {
+                Some(layer) => {
+                    membership.insert(entity, layer);
+                    within.entry(layer).or_default().push(*node);
+                }
+                None => frame.push(*node),
+            }
+        }
+        for layered in layers.shown.values() {
+            match layered.outer {
+                Some(outer) => within.entry(outer).or_default().push(layered.node),
+                None => frame.push(layered.node),
+            }
+        }
+        Self::sort(&mut frame);
+        self.contiguous = self.contiguous(&frame, true);
+        let mut spans = HashMap::new();
+        for (layer, mut nodes) in within {
+            Self::sort(&mut nodes);
+            // A layer's pass draws into its own texture, which has no frame beneath it to
+            // blur.
+            spans.insert(layer, self.contiguous(&nodes, false));
+        }
+        let layers = self.layers.as_mut().unwrap();
+        for (layer, layered) in layers.shown.iter_mut() {
+            layered.spans = spans.remove(layer).unwrap_or_default();
+        }
+        layers.membership = membership;
+    }
+    fn sort(nodes: &mut [Node]) {
+        nodes.sort_by(|lhs, rhs| {
             lhs.elevation
                 .front_to_back(&rhs.elevation)
                 .then_with(|| lhs.pipeline.cmp(&rhs.pipeline))
@@ -296,13 +386,26 @@ impl Ash {
                 .then_with(|| lhs.clip_context.partial_cmp(&rhs.clip_context).unwrap())
                 .then_with(|| lhs.order.cmp(&rhs.order))
         });
-        self.contiguous.clear();
+    }
+    /// `nodes`, sorted, cut into runs that draw in one call. A backdrop panel is a run of its
+    /// own where `backdrops` are drawn at all.
+    fn contiguous(&self, nodes: &[Node], backdrops: bool) -> Vec<ContiguousSpan> {
+        let mut spans = vec![];
+        let panels = &self.panel.as_ref().unwrap().resources;
+        let backdrop = |node: &Node| {
+            (backdrops
+                && node.pipeline == PipelineId::Panel
+                && panels.backdrop(node.instance_id).is_some())
+            .then_some(node.instance_id)
+        };
         let mut contiguous = 1;
         let mut range_start = None;
-        for (index, node) in self.nodes.iter().enumerate() {
-            let next = self.nodes.get(index + 1).copied();
+        for (index, node) in nodes.iter().enumerate() {
+            let next = nodes.get(index + 1).copied();
             if let Some(next) = next {
                 if node.pipeline == next.pipeline
+                    && backdrop(node).is_none()
+                    && backdrop(&next).is_none()
                     && node.group == next.group
                     && node.order + 1 == next.order
                     && node.clip_context == next.clip_context
@@ -315,14 +418,16 @@ impl Ash {
                 }
             }
             let start = range_start.take().unwrap_or(node.order);
-            self.contiguous.push(ContiguousSpan {
+            spans.push(ContiguousSpan {
                 pipeline: node.pipeline,
                 group: node.group,
                 range: start..start + contiguous,
                 clip_context: node.clip_context,
+                backdrop: backdrop(node),
             });
             contiguous = 1;
         }
+        spans
     }
     pub(crate) fn render(&mut self, ginkgo: &Ginkgo, clear: ClearColor) {
         if let Some(surface_texture) = ginkgo.surface_texture() {
@@ -336,59 +441,103 @@ impl Ash {
                     .create_command_encoder(&CommandEncoderDescriptor {
                         label: Some("present-encoder"),
                     });
-            let mut rpass = encoder.begin_render_pass(&RenderPassDescriptor {
-                multiview_mask: None,
-                label: Some("render-pass"),
-                color_attachments: &ginkgo.color_attachment(&view, clear.0),
-                depth_stencil_attachment: ginkgo.depth_stencil_attachment(),
-                timestamp_writes: None,
-                occlusion_query_set: None,
-            });
-            for span in self.contiguous.iter() {
-                let mut section = ginkgo.viewport().section();
-                if let Some(clip) = self.clip.get(&span.clip_context) {
-                    section = section
-                        .intersection(
-                            clip.0
-                                .to_physical(ginkgo.configuration().scale_factor.value()),
-                        )
-                        .unwrap_or_default();
+            let extent = (
+                surface_texture.texture.width(),
+                surface_texture.texture.height(),
+            );
+            let layers = self.layers.as_mut().unwrap();
+            layers.fit(extent, ginkgo.viewport().section());
+            for layer in layers.stale() {
+                let mut rpass = encoder
+                    .begin_render_pass(&RenderPassDescriptor {
+                        multiview_mask: None,
+                        label: Some("layer-pass"),
+                        color_attachments: &ginkgo.color_attachment(
+                            self.layers.as_mut().unwrap().target(ginkgo, layer),
+                            Some(Color::new(0.0, 0.0, 0.0, 0.0)),
+                        ),
+                        depth_stencil_attachment: ginkgo.depth_stencil_attachment(true),
+                        timestamp_writes: None,
+                        occlusion_query_set: None,
+                    })
+                    .forget_lifetime();
+                let spans = self.layers.as_ref().unwrap().shown[&layer].spans.clone();
+                for span in spans {
+                    let section = self.section(ginkgo, span.clip_context);
+                    scissor(&mut rpass, section);
+                    self.draw(&mut rpass, span.pipeline, span.parameters(section));
                 }
-                // Expanded outward to whole pixels rather than truncated: a fractional
-                // scale factor makes these physical rects fractional, and `as u32` would
-                // shave the right/bottom edge of every clipped region.
-                let left = section.left().floor().max(0.0);
-                let top = section.top().floor().max(0.0);
-                let right = section.right().ceil().max(left);
-                let bottom = section.bottom().ceil().max(top);
-                rpass.set_scissor_rect(
-                    left as u32,
-                    top as u32,
-                    (right - left) as u32,
-                    (bottom - top) as u32,
-                );
+            }
+            self.layers.as_mut().unwrap().write(ginkgo);
+            // Lifetime forgotten so a backdrop can end the pass, submit what it drew and pick
+            // the frame back up in a fresh encoder.
+            let mut rpass = encoder
+                .begin_render_pass(&RenderPassDescriptor {
+                    multiview_mask: None,
+                    label: Some("render-pass"),
+                    color_attachments: &ginkgo.color_attachment(&view, Some(clear.0)),
+                    depth_stencil_attachment: ginkgo.depth_stencil_attachment(true),
+                    timestamp_writes: None,
+                    occlusion_query_set: None,
+                })
+                .forget_lifetime();
+            let backdrops = Backdrop::supported(ginkgo);
+            let contiguous = std::mem::take(&mut self.contiguous);
+            for span in contiguous.iter() {
+                let section = self.section(ginkgo, span.clip_context);
+                scissor(&mut rpass, section);
                 let parameters = span.parameters(section);
-                match span.pipeline {
-                    PipelineId::Text => {
-                        Render::render(self.text.as_mut().unwrap(), &mut rpass, parameters);
-                    }
-                    PipelineId::Icon => {
-                        Render::render(self.icon.as_mut().unwrap(), &mut rpass, parameters);
-                    }
-                    PipelineId::Line => {
-                        Render::render(self.line.as_mut().unwrap(), &mut rpass, parameters);
-                    }
-                    PipelineId::Panel => {
-                        Render::render(self.panel.as_mut().unwrap(), &mut rpass, parameters);
-                    }
-                    PipelineId::Image => {
-                        Render::render(self.image.as_mut().unwrap(), &mut rpass, parameters);
-                    }
-                    PipelineId::Polygon => {
-                        Render::render(self.polygon.as_mut().unwrap(), &mut rpass, parameters);
+                let region = span
+                    .backdrop
+                    .filter(|_| backdrops)
+                    .and_then(|id| self.panel.as_ref().unwrap().resources.backdrop(id))
+                    .and_then(|region| {
+                        region
+                            .bounds
+                            .intersection(section)
+                            .map(|bounds| BackdropRegion { bounds, ..region })
+                    });
+                if let Some(region) = region {
+                    drop(rpass);
+                    ginkgo
+                        .context()
+                        .queue
+                        .submit(std::iter::once(encoder.finish()));
+                    let backdrop = self.backdrop.as_mut().unwrap();
+                    backdrop.write(ginkgo, region);
+                    encoder =
+                        ginkgo
+                            .context()
+                            .device
+                            .create_command_encoder(&CommandEncoderDescriptor {
+                                label: Some("backdrop-encoder"),
+                            });
+                    let panel = self.panel.as_ref().unwrap();
+                    let blurred = backdrop.blur(
+                        ginkgo,
+                        &mut encoder,
+                        &surface_texture.texture,
+                        region,
+                        &panel.resources.backdrop_layout,
+                    );
+                    rpass = encoder
+                        .begin_render_pass(&RenderPassDescriptor {
+                            multiview_mask: None,
+                            label: Some("render-pass"),
+                            color_attachments: &ginkgo.color_attachment(&view, None),
+                            depth_stencil_attachment: ginkgo.depth_stencil_attachment(false),
+                            timestamp_writes: None,
+                            occlusion_query_set: None,
+                        })
+                        .forget_lifetime();
+                    scissor(&mut rpass, section);
+                    if let Some(blurred) = blurred {
+                        Panel::render_backdrop(panel, &mut rpass, parameters.clone(), blurred);
                     }
                 }
+                self.draw(&mut rpass, span.pipeline, parameters);
             }
+            self.contiguous = contiguous;
             drop(rpass);
             ginkgo
                 .context()
@@ -397,4 +546,65 @@ impl Ash {
             ginkgo.context().queue.present(surface_texture);
         }
     }
+    /// The viewport, cut to `clip_context`'s clip where it has one.
+    fn section(&self, ginkgo: &Ginkgo, clip_context: Parent) -> Section<Physical> {
+        let mut section = ginkgo.viewport().section();
+        if let Some(clip) = self.clip.get(&clip_context) {
+            section = section
+                .intersection(
+                    clip.0
+                        .to_physical(ginkgo.configuration().scale_factor.value()),
+                )
+                .unwrap_or_default();
+        }
+        section
+    }
+    fn draw(&mut self, render_pass: &mut RenderPass, pipeline: PipelineId, parameters: Parameters) {
+        match pipeline {
+            PipelineId::Text => {
+                Render::render(self.text.as_mut().unwrap(), render_pass, parameters);
+            }
+            PipelineId::Icon => {
+                Render::render(self.icon.as_mut().unwrap(), render_pass, parameters);
+            }
+            PipelineId::Line => {
+                Render::render(self.line.as_mut().unwrap(), render_pass, parameters);
+            }
+            PipelineId::Panel => {
+                Render::render(self.panel.as_mut().unwrap(), render_pass, parameters);
+            }
+            PipelineId::Image => {
+                Render::render(self.image.as_mut().unwrap(), render_pass, parameters);
+            }
+            PipelineId::Polygon => {
+                Render::render(self.polygon.as_mut().unwrap(), render_pass, parameters);
+            }
+            PipelineId::Path => {
+                Render::render(self.path.as_mut().unwrap(), render_pass, parameters);
+            }
+            PipelineId::Shader => {
+                Render::render(self.shader.as_mut().unwrap(), render_pass, parameters);
+            }
+            PipelineId::Layer => {
+                let layer = Entity::from_bits(parameters.group);
+                self.layers.as_ref().unwrap().composite(render_pass, layer);
+            }
+        }
+    }
+}
+/// Keeps `render_pass` to `section`.
+fn scissor(render_pass: &mut RenderPass, section: Section<Physical>) {
+    // Expanded outward to whole pixels rather than truncated: a fractional scale factor makes
+    // these physical rects fractional, and `as u32` would shave the right/bottom edge of
+    // every clipped region.
+    let left = section.left().floor().max(0.0);
+    let top = section.top().floor().max(0.0);
+    let right = section.right().ceil().max(left);
+    let bottom = section.bottom().ceil().max(top);
+    render_pass.set_scissor_rect(
+        left as u32,
+        top as u32,
+        (right - left) as u32,
+        (bottom - top) as u32,
+    );
 }
@@ -1,5 +1,5 @@
 use crate::ash::differential::RenderQueueHandle;
-use crate::ash::instance::{InstanceCoordinator, Order};
+use crate::ash::instance::{InstanceCoordinator, InstanceId, Order};
 use crate::ash::node::Nodes;
 use crate::ginkgo::Ginkgo;
 use crate::{Parent, Physical, Section};
@@ -37,6 +37,9 @@ pub(crate) enum PipelineId {
     Panel,
     Image,
     Polygon,
+    Path,
+    Shader,
+    Layer,
 }
 #[derive(Clone)]
 pub(crate) struct ContiguousSpan {
@@ -44,6 +47,9 @@ pub(crate) struct ContiguousSpan {
     pub(crate) group: GroupId,
     pub(crate) range: Range<Order>,
     pub(crate) clip_context: Parent,
+    /// The one panel in this span, when it blurs what is beneath it: drawn alone, so the
+    /// blur can be made from everything before it.
+    pub(crate) backdrop: Option<InstanceId>,
 }
 impl ContiguousSpan {
     pub(crate) fn parameters(&self, clip: Section<Physical>) -> Parameters {
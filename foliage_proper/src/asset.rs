@@ -144,17 +144,23 @@ pub(crate) fn on_retrieve(
         }
     }
 }
+/// Moves every fetch that has landed into `assets`. One whose sender went away without
+/// sending -- a dropped file that could not be read -- never will, and stops being awaited.
 pub(crate) fn await_assets(mut asset_loader: ResMut<AssetLoader>) {
     if !asset_loader.awaiting.is_empty() {
-        let mut finished = Vec::<(AssetKey, Asset)>::new();
+        let mut finished = Vec::<(AssetKey, Option<Asset>)>::new();
         for (key, fetch) in asset_loader.awaiting.iter_mut() {
-            if let Ok(Some(f)) = fetch.recv.try_recv() {
-                finished.push((*key, f));
+            match fetch.recv.try_recv() {
+                Ok(Some(f)) => finished.push((*key, Some(f))),
+                Ok(None) => {}
+                Err(_) => finished.push((*key, None)),
             }
         }
         for (key, asset) in finished {
             asset_loader.awaiting.remove(&key);
-            asset_loader.assets.insert(key, asset);
+            if let Some(asset) = asset {
+                asset_loader.assets.insert(key, asset);
+            }
         }
     }
 }
@@ -167,6 +173,10 @@ impl AssetLoader {
     pub(crate) fn queue_fetch(&mut self, fetch: AssetFetch) {
         self.awaiting.insert(fetch.key, fetch);
     }
+    /// Whether `key` is still being fetched.
+    pub(crate) fn awaits(&self, key: AssetKey) -> bool {
+        self.awaiting.contains_key(&key)
+    }
     /// A fresh key, usable immediately -- before the asset it names has loaded. Internal:
     /// [`Foliage::load_asset`](crate::Foliage::load_asset) calls this and hands the key
     /// back, so an app never has to mint one itself.
@@ -39,6 +39,19 @@ macro_rules! tuning {
 tuning!(
     crate::ScrollMomentum,
     crate::AxisCommitment,
+    crate::GestureThresholds,
+    crate::FocusRing,
     crate::KeyBindings,
     crate::ClearColor
 );
+impl Sealed for crate::Breakpoints {}
+/// **Panics if** [`validate`](crate::Breakpoints::validate) fails -- when the app declares the
+/// set, rather than at the first resize that reads it.
+impl Tuning for crate::Breakpoints {
+    fn install(self, foliage: &mut Foliage) {
+        if let Err(error) = self.validate() {
+            panic!("{error}");
+        }
+        foliage.world.insert_resource(self);
+    }
+}
@@ -20,8 +20,15 @@ pub struct LeafSprout {
     /// specs travel through the boundary as a closed set. Naming each one instead keeps
     /// every spec its own type all the way to the queue.
     pub(crate) grid: Option<crate::Grid>,
+    pub(crate) flow: Option<crate::Flow>,
+    pub(crate) flow_item: Option<crate::FlowItem>,
+    pub(crate) template: Option<crate::GridTemplate>,
+    pub(crate) grid_item: Option<crate::GridItem>,
     pub(crate) anchor: Option<crate::Anchor>,
+    pub(crate) transition: Option<crate::Timing>,
     pub(crate) opacity: Option<crate::Opacity>,
+    pub(crate) transform: Option<crate::Transform>,
+    pub(crate) layer: bool,
     pub(crate) alignment: Option<(crate::HorizontalAlignment, crate::VerticalAlignment)>,
     pub(crate) listener: Option<crate::InteractionListener>,
     pub(crate) propagation: Option<crate::InteractionPropagation>,
@@ -33,6 +40,13 @@ pub struct LeafSprout {
     pub(crate) overscroll: Option<crate::OverscrollPropagation>,
     pub(crate) scroll_axes: Option<crate::ScrollAxes>,
     pub(crate) directional_lock: Option<crate::DirectionalLock>,
+    pub(crate) scroll_snap: Option<crate::ScrollSnap>,
+    pub(crate) sticky: Option<crate::Sticky>,
+    pub(crate) scrollbar: Option<crate::ScrollbarStyle>,
+    pub(crate) zoom: Option<crate::Zoom>,
+    pub(crate) drop_target: bool,
+    pub(crate) tab_index: Option<crate::TabIndex>,
+    pub(crate) cursor: Option<crate::CursorIcon>,
 }
 impl Default for LeafSprout {
     fn default() -> Self {
@@ -41,8 +55,15 @@ impl Default for LeafSprout {
             stem: Parent::none(),
             elevation: None,
             grid: None,
+            flow: None,
+            flow_item: None,
+            template: None,
+            grid_item: None,
             anchor: None,
+            transition: None,
             opacity: None,
+            transform: None,
+            layer: false,
             alignment: None,
             listener: None,
             propagation: None,
@@ -54,6 +75,13 @@ impl Default for LeafSprout {
             overscroll: None,
             scroll_axes: None,
             directional_lock: None,
+            scroll_snap: None,
+            sticky: None,
+            scrollbar: None,
+            zoom: None,
+            drop_target: false,
+            tab_index: None,
+            cursor: None,
         }
     }
 }
@@ -129,6 +157,30 @@ pub trait Sprout: Author {
         self.seed().grid = Some(grid);
         self
     }
+    /// Places children one after another by their own sizes -- see [`Flow`](crate::Flow).
+    /// Brings a single-cell grid with it when none is set, for the children's sizes to
+    /// resolve against.
+    fn flow(mut self, flow: crate::Flow) -> Self {
+        self.seed().flow = Some(flow);
+        self
+    }
+    /// How this element grows, shrinks and orders inside a parent's [`flow`](Self::flow).
+    fn flow_item(mut self, item: crate::FlowItem) -> Self {
+        self.seed().flow_item = Some(item);
+        self
+    }
+    /// Places children into named areas and auto-placed cells -- see
+    /// [`GridTemplate`](crate::GridTemplate). Brings a single-cell grid with it when none is
+    /// set, as [`flow`](Self::flow) does.
+    fn template(mut self, template: crate::GridTemplate) -> Self {
+        self.seed().template = Some(template);
+        self
+    }
+    /// Where this element goes in a parent's [`template`](Self::template).
+    fn grid_item(mut self, item: crate::GridItem) -> Self {
+        self.seed().grid_item = Some(item);
+        self
+    }
     /// Resolves [`anchor()`](crate::anchor) values in this element's `Location` against
     /// `leaf` rather than against its parent.
     ///
@@ -142,11 +194,32 @@ pub trait Sprout: Author {
         self.seed().anchor = Some(crate::Anchor::new(leaf.0));
         self
     }
+    /// Glides this element to each new place it resolves to over `timing`, rather than
+    /// snapping there -- whatever moved it: a new `Location`, a breakpoint, a reflow. A move
+    /// interrupted by another turns from wherever the box has got to. `repeat` and
+    /// `backtrack` do not apply.
+    fn transition(mut self, timing: crate::Timing) -> Self {
+        self.seed().transition = Some(timing);
+        self
+    }
     /// Starting opacity, inherited by everything beneath.
     fn opacity(mut self, value: f32) -> Self {
         self.seed().opacity = Some(crate::Opacity::new(value));
         self
     }
+    /// Turns and scales this element, and everything beneath it, as it is drawn. See
+    /// [`Transform`](crate::Transform).
+    fn transform(mut self, transform: crate::Transform) -> Self {
+        self.seed().transform = Some(transform);
+        self
+    }
+    /// Draws this element and everything beneath it into a texture of its own, faded by its
+    /// [`opacity`](Self::opacity) as one picture and reused until something inside changes.
+    /// See [`Layer`](crate::Layer).
+    fn layer(mut self) -> Self {
+        self.seed().layer = true;
+        self
+    }
     /// How content sits within this element's own box.
     fn align(mut self, h: crate::HorizontalAlignment, v: crate::VerticalAlignment) -> Self {
         self.seed().alignment = Some((h, v));
@@ -173,6 +246,28 @@ pub trait Sprout: Author {
         self.seed().propagation = Some(current.disable_drag());
         self
     }
+    /// Somewhere a dragged element can be let go. Reports
+    /// [`DragOver`](crate::Bloom::DragOver), [`DragLeave`](crate::Bloom::DragLeave) and
+    /// [`Dropped`](crate::Bloom::Dropped), naming both the element being dragged and this one.
+    ///
+    /// Hit-tested with the same shape as everything else, beneath the dragged element rather
+    /// than against it -- the element following the pointer is never its own drop target.
+    fn drop_target(mut self) -> Self {
+        self.seed().drop_target = true;
+        self
+    }
+    /// Where this element falls in the Tab order -- see [`TabIndex`](crate::TabIndex). A
+    /// negative index keeps it out of the Tab order while leaving it pressable.
+    fn tab_index(mut self, index: i32) -> Self {
+        self.seed().tab_index = Some(crate::TabIndex(index));
+        self
+    }
+    /// The pointer shown while the mouse is over this element, and over everything inside it
+    /// that sets none of its own.
+    fn cursor(mut self, icon: crate::CursorIcon) -> Self {
+        self.seed().cursor = Some(icon);
+        self
+    }
     /// Hit-tests as a circle rather than a rectangle.
     fn round_hit_area(mut self) -> Self {
         self.seed().shape = Some(crate::InteractionShape::Circle);
@@ -206,6 +301,31 @@ pub trait Sprout: Author {
         self.seed().directional_lock = Some(crate::DirectionalLock(locked));
         self
     }
+    /// Where this element comes to rest once a gesture lets go of it -- on a child, every so
+    /// many pixels, or a whole page, per axis. See [`ScrollSnap`](crate::ScrollSnap).
+    fn scroll_snap(mut self, snap: crate::ScrollSnap) -> Self {
+        self.seed().scroll_snap = Some(snap);
+        self
+    }
+    /// Holds this element `offset` pixels from an edge of the view scrolling it, once its own
+    /// place would scroll past, for as long as its parent is in sight. See
+    /// [`Sticky`](crate::Sticky).
+    fn sticky(mut self, edge: crate::StickyEdge, offset: crate::CoordinateUnit) -> Self {
+        self.seed().sticky = Some(crate::Sticky { edge, offset });
+        self
+    }
+    /// Draws a track and thumb along each axis this element can scroll, which drag and page it.
+    /// See [`ScrollbarStyle`](crate::ScrollbarStyle).
+    fn scrollbar(mut self, style: crate::ScrollbarStyle) -> Self {
+        self.seed().scrollbar = Some(style);
+        self
+    }
+    /// Lets this element's content be zoomed as well as panned, within `limits`. See
+    /// [`Zoom`](crate::Zoom).
+    fn zoomable(mut self, limits: crate::Zoom) -> Self {
+        self.seed().zoom = Some(limits);
+        self
+    }
     /// Clips this element to the viewport rather than to its nearest scrolling ancestor.
     fn clip_to_viewport(mut self) -> Self {
         self.seed().clip_to_viewport = true;
@@ -3,9 +3,11 @@ use crate::boundary::tween::Tween;
 use crate::coordinate::area::Area;
 use crate::coordinate::position::Position;
 use crate::{
-    AssetKey, InteractionMethod, Key, Layout, Logical, Modifiers, PhysicalKey, TextInputAction,
+    AssetKey, InteractionMethod, Key, Layout, Logical, Modifiers, PhysicalKey, SwipeDirection,
+    TextInputAction,
 };
 use bevy_ecs::resource::Resource;
+use std::path::PathBuf;
 
 /// Something the tree did. The whole of what foliage reports outward.
 ///
@@ -38,6 +40,44 @@ pub enum Bloom {
     /// The gesture that grabbed this element ended, however it ended. Always follows an
     /// [`Engaged`](Bloom::Engaged), whether or not a [`Clicked`](Bloom::Clicked) also fired.
     Disengaged(Leaf),
+    /// A second [`Clicked`](Bloom::Clicked) on this element, close enough in time and place to
+    /// the first to be one gesture. Follows that second `Clicked` rather than replacing it, so
+    /// an element that only cares about single clicks never waits to find out. Thresholds are
+    /// [`GestureThresholds`](crate::GestureThresholds).
+    DoubleClicked(Leaf),
+    /// The pointer has been held on this element without becoming a drag. Arrives while it is
+    /// still held, once; the release that follows is not also a [`Clicked`](Bloom::Clicked).
+    LongPressed(Leaf),
+    /// A drag on this element let go fast. Arrives at release, ahead of its
+    /// [`Disengaged`](Bloom::Disengaged).
+    ///
+    /// `velocity` is the smoothed rate [`Canopy::pointer_velocity`](crate::Canopy::pointer_velocity)
+    /// was reading at release, in px/ms, but signed the way the pointer travelled -- so it
+    /// agrees with `direction` -- rather than the way content under it would scroll.
+    Swiped {
+        leaf: Leaf,
+        direction: SwipeDirection,
+        velocity: Position<Logical>,
+    },
+    /// A drag moved over a [`drop_target`](crate::Sprout::drop_target). Once on the way in,
+    /// not every frame it stays.
+    DragOver {
+        source: Leaf,
+        target: Leaf,
+    },
+    /// The drag over this drop target moved off it, or ended without being let go on it.
+    /// Always follows a [`DragOver`](Bloom::DragOver) for the same pair.
+    DragLeave {
+        source: Leaf,
+        target: Leaf,
+    },
+    /// `source` was let go over `target`. In place of the
+    /// [`DragLeave`](Bloom::DragLeave) that drag would otherwise have ended with, and after
+    /// the source's own [`Disengaged`](Bloom::Disengaged).
+    Dropped {
+        source: Leaf,
+        target: Leaf,
+    },
     Focused(Leaf),
     Unfocused(Leaf),
     /// A key, as the layout produces it -- what to use for typed text.
@@ -51,6 +91,10 @@ pub enum Bloom {
         key: PhysicalKey,
         mods: Modifiers,
     },
+    /// The keys of a registered [`shortcut`](crate::Grows::shortcut) were pressed. Carries the
+    /// id it was registered under. Arrives after the [`PhysicalKey`](Bloom::PhysicalKey) that
+    /// completed it.
+    Shortcut(String),
     /// A text input's contents changed, by typing, pasting, or a write.
     TextChanged {
         leaf: Leaf,
@@ -74,6 +118,36 @@ pub enum Bloom {
     TimerFinished(Leaf),
     /// Every animation joined to this sequence has finished.
     SequenceFinished(Leaf),
+    /// A file dragged in from outside the window is over `leaf` -- the topmost
+    /// [`drop_target`](crate::Sprout::drop_target) beneath the pointer, or `None` over anything
+    /// else. One per file when several are dragged at once.
+    ///
+    /// Placed by where the pointer last was: not every platform reports the pointer moving while
+    /// it carries a file, so this is read once, on the way in, rather than followed. A browser
+    /// will not name a file until it is dropped, so on web `path` is empty.
+    FileHovered {
+        leaf: Option<Leaf>,
+        path: PathBuf,
+    },
+    /// The files that were hovering left the window, or the drag was abandoned. Once, however
+    /// many files there were.
+    FileHoverCancelled {
+        leaf: Option<Leaf>,
+    },
+    /// A file was let go over `leaf`, or over no drop target at all when `None`. Its bytes are
+    /// loading under `key` -- hand the key straight to an [`Image`](crate::Image), or read them
+    /// with [`Canopy::asset`](crate::Canopy::asset) once [`AssetLoaded`](Self::AssetLoaded)
+    /// names it. A file that cannot be read never loads, and is logged instead.
+    ///
+    /// Where the platform stops reporting the pointer while it carries a file, this waits for
+    /// the pointer's next move to learn where the file was let go, so `leaf` is the target
+    /// actually beneath it. A browser gives out no path, so on web `path` is only the file's
+    /// name.
+    FileDropped {
+        leaf: Option<Leaf>,
+        path: PathBuf,
+        key: AssetKey,
+    },
     /// An asset's bytes arrived and can now be read with
     /// [`Canopy::asset`](crate::Canopy::asset).
     AssetLoaded {
@@ -134,10 +208,36 @@ pub enum Bloom {
         delta: Position<Logical>,
         method: InteractionMethod,
     },
+    /// A [`ScrollSnap`](crate::ScrollSnap) view came to rest on a snap point: the `index`th
+    /// child, interval or page along `axis`. Once per axis that snapped, when the settle
+    /// arrives -- not when it starts, so a press that interrupts it reports nothing.
+    ///
+    /// Every gesture that ends on the view reports, including one that let go already on a
+    /// point and moved nowhere; a pager's indicator can follow this alone.
+    SnappedTo {
+        leaf: Leaf,
+        index: usize,
+        axis: crate::GestureAxis,
+    },
     LayoutChanged {
         new: Layout,
         short: bool,
     },
+    /// A [`virtualized`](crate::Grows::virtualize) view grew a new row container, `row`, for
+    /// the app to build the row's contents under. Once per container: it is recycled after,
+    /// not grown again, so what is built here is built for every index it will show.
+    RowGrown {
+        list: Leaf,
+        row: Leaf,
+    },
+    /// A row container of a virtualized view now shows `index`, and its contents should be
+    /// filled in from the app's data for it. Follows the container's
+    /// [`RowGrown`](Bloom::RowGrown) in the same frame when it is new.
+    RowBound {
+        list: Leaf,
+        row: Leaf,
+        index: usize,
+    },
 }
 
 /// Where the funnel observers deposit emissions until the frame collects them.
@@ -3,7 +3,7 @@ use crate::boundary::op::Op;
 use crate::coordinate::area::Area;
 use crate::coordinate::position::Position;
 use crate::interaction::Click;
-use crate::opacity::BlendedOpacity;
+use crate::opacity::{BlendedOpacity, InheritedOpacity, Opacity};
 use crate::{
     AssetKey, AssetLoader, Color, CurrentInteraction, FontSize, Layout, Logical,
     ResolvedVisibility, Section, Short, Text, Time, TimeDelta, View,
@@ -26,6 +26,12 @@ pub(crate) struct Reads<'w, 's> {
     pub(crate) progress: Query<'w, 's, &'static crate::ScrollProgress>,
     pub(crate) visibility: Query<'w, 's, &'static ResolvedVisibility>,
     pub(crate) opacity: Query<'w, 's, &'static BlendedOpacity>,
+    pub(crate) layers: Query<
+        'w,
+        's,
+        (&'static Opacity, &'static InheritedOpacity),
+        bevy_ecs::query::With<crate::Layer>,
+    >,
     pub(crate) elevation: Query<'w, 's, &'static crate::ResolvedElevation>,
     pub(crate) text: Query<'w, 's, &'static Text>,
     pub(crate) values: Query<'w, 's, &'static crate::TextValue>,
@@ -82,6 +88,8 @@ pub enum Sap {
     ScrollExtent,
     /// How far through its range a view is scrolled, per axis, 0.0..=1.0.
     ScrollProgress,
+    /// A [`Zoom`](crate::Zoom) view's own magnification, 1.0 at rest.
+    Zoom,
     /// The elements grown directly under this one.
     Children,
     /// The element this one sits under, if any.
@@ -259,20 +267,43 @@ impl<'w, 's> Canopy<'w, 's> {
 /// on behalf of a thread that cannot hold one. Written once so the two can never drift.
 pub(crate) fn sample<'a>(reads: &'a Reads<'_, '_>, leaf: Leaf, what: Sap) -> Option<Sample<'a>> {
     let entity = leaf.0;
+    // Through the zooms above, which scale the box only as it is drawn.
+    let on_screen = || {
+        let section = *reads.sections.get(entity).ok()?;
+        Some(
+            reads
+                .parents
+                .get(entity)
+                .ok()
+                .and_then(|p| p.id)
+                .and_then(|p| reads.views.get(p).ok())
+                .map_or(section, |view| view.child_on_screen(section)),
+        )
+    };
     Some(match what {
-        Sap::Section => Sample::Section(*reads.sections.get(entity).ok()?),
-        Sap::Position => Sample::Position(reads.sections.get(entity).ok()?.position),
-        Sap::Area => Sample::Area(reads.sections.get(entity).ok()?.area),
+        Sap::Section => Sample::Section(on_screen()?),
+        Sap::Position => Sample::Position(on_screen()?.position),
+        Sap::Area => Sample::Area(on_screen()?.area),
         Sap::LayoutSection => Sample::Section(reads.layout_sections.get(entity).ok()?.0),
         Sap::Points => Sample::Points(Cow::Borrowed(
             reads.points.get(entity).ok()?.data.as_slice(),
         )),
         Sap::Visible => Sample::Flag(reads.visibility.get(entity).ok()?.visible()),
-        Sap::Opacity => Sample::Scalar(reads.opacity.get(entity).ok()?.value),
+        Sap::Opacity => {
+            // Inside a layer the blend restarts at its root, whose share -- and every
+            // enclosing layer's -- is laid on at the composite instead.
+            let mut value = reads.opacity.get(entity).ok()?.value;
+            let mut at = Some(entity);
+            while let Some(current) = at {
+                if let Ok((own, inherited)) = reads.layers.get(current) {
+                    value *= own.value * inherited.value;
+                }
+                at = reads.parents.get(current).ok().and_then(|p| p.id);
+            }
+            Sample::Scalar(value)
+        }
         Sap::Elevation => Sample::Scalar(reads.elevation.get(entity).ok()?.value()),
-        Sap::Text => Sample::Text(Cow::Borrowed(
-            reads.text.get(entity).ok()?.value.as_str(),
-        )),
+        Sap::Text => Sample::Text(Cow::Borrowed(reads.text.get(entity).ok()?.value.as_str())),
         Sap::Value => Sample::Text(Cow::Borrowed(reads.values.get(entity).ok()?.0.as_str())),
         Sap::FontSize => Sample::Size(reads.font_sizes.get(entity).ok()?.xs),
         Sap::Color => Sample::Color(*reads.colors.get(entity).ok()?),
@@ -283,6 +314,7 @@ pub(crate) fn sample<'a>(reads: &'a Reads<'_, '_>, leaf: Leaf, what: Sap) -> Opt
             let progress = reads.progress.get(entity).ok()?;
             Sample::Pair(progress.x(), progress.y())
         }
+        Sap::Zoom => Sample::Scalar(reads.views.get(entity).ok()?.zoom()),
         // Only elements the app grew itself: the caret inside a text input is foliage's
         // business, and reporting it would hand back a `Leaf` naming something the app
         // never asked for and cannot meaningfully act on.
@@ -1,10 +1,12 @@
 use crate::boundary::bloom::{Bloom, Emissions};
 use crate::boundary::leaf::{Grown, Leaf};
+use crate::shortcut::ShortcutMatched;
 use crate::text_input::TextChanged;
 use crate::text_input::action::InputAction;
 use crate::{
-    Disengaged, DragStarted, Dragged, Engaged, Focused, InputSequence, Layout, OnClick,
-    OnRetrieval, Parent, PhysicalInputSequence, Short, Trigger, Unfocused,
+    Disengaged, DoubleClicked, DragLeave, DragOver, DragStarted, Dragged, Dropped, Engaged,
+    Focused, InputSequence, Layout, LongPressed, OnClick, OnRetrieval, Parent,
+    PhysicalInputSequence, Short, Swiped, Trigger, Unfocused,
 };
 use bevy_ecs::entity::Entity;
 use bevy_ecs::system::{Query, Res, ResMut};
@@ -15,7 +17,11 @@ use bevy_ecs::system::{Query, Res, ResMut};
 /// -- has no `Leaf`, so the walk climbs to the nearest ancestor that does. Without it a click
 /// landing on a widget's inner panel would simply vanish, which is never the answer anyone
 /// wants; attributing it to the element they actually grew is.
-fn attribute(entity: Entity, grown: &Query<&Grown>, parents: &Query<&Parent>) -> Option<Leaf> {
+pub(crate) fn attribute(
+    entity: Entity,
+    grown: &Query<&Grown>,
+    parents: &Query<&Parent>,
+) -> Option<Leaf> {
     let mut current = entity;
     loop {
         if grown.contains(current) {
@@ -45,9 +51,50 @@ gesture!(engaged, Engaged, Engaged);
 gesture!(dragged, Dragged, Dragged);
 gesture!(drag_started, DragStarted, DragStarted);
 gesture!(disengaged, Disengaged, Disengaged);
+gesture!(double_clicked, DoubleClicked, DoubleClicked);
+gesture!(long_pressed, LongPressed, LongPressed);
 gesture!(focused, Focused, Focused);
 gesture!(unfocused, Unfocused, Unfocused);
 
+fn swiped(
+    trigger: Trigger<Swiped>,
+    grown: Query<&Grown>,
+    parents: Query<&Parent>,
+    mut emissions: ResMut<Emissions>,
+) {
+    if let Some(leaf) = attribute(trigger.event_target(), &grown, &parents) {
+        emissions.push(Bloom::Swiped {
+            leaf,
+            direction: trigger.event().direction,
+            velocity: trigger.event().velocity,
+        });
+    }
+}
+
+/// The drop-target observers: the same shape as [`gesture!`], attributing both ends of the
+/// pair. Either failing to attribute drops the emission -- a drop that names only one side is
+/// not one an app can act on.
+macro_rules! drop_pair {
+    ($name:ident, $event:ty, $bloom:ident) => {
+        fn $name(
+            trigger: Trigger<$event>,
+            grown: Query<&Grown>,
+            parents: Query<&Parent>,
+            mut emissions: ResMut<Emissions>,
+        ) {
+            if let (Some(source), Some(target)) = (
+                attribute(trigger.event().source, &grown, &parents),
+                attribute(trigger.event_target(), &grown, &parents),
+            ) {
+                emissions.push(Bloom::$bloom { source, target });
+            }
+        }
+    };
+}
+drop_pair!(drag_over, DragOver, DragOver);
+drop_pair!(drag_leave, DragLeave, DragLeave);
+drop_pair!(dropped, Dropped, Dropped);
+
 fn key(trigger: Trigger<InputSequence>, mut emissions: ResMut<Emissions>) {
     let event = trigger.event();
     emissions.push(Bloom::Key {
@@ -64,6 +111,10 @@ fn physical_key(trigger: Trigger<PhysicalInputSequence>, mut emissions: ResMut<E
     });
 }
 
+fn shortcut(trigger: Trigger<ShortcutMatched>, mut emissions: ResMut<Emissions>) {
+    emissions.push(Bloom::Shortcut(trigger.event().0.clone()));
+}
+
 fn text_changed(
     trigger: Trigger<TextChanged>,
     grown: Query<&Grown>,
@@ -152,11 +203,17 @@ fn layout_changed(
 /// Every source of emissions, registered in one place. Adding a `Bloom` variant without a
 /// line here is the one failure this seam can have that nothing catches at compile time.
 ///
-/// One exception, deliberately: [`Bloom::ScrollRefused`](crate::Bloom::ScrollRefused) is pushed
+/// Three exceptions, deliberately. The file blooms -- [`Bloom::FileHovered`](crate::Bloom::FileHovered)
+/// and its pair -- are pushed by `FileDropAdapter::resolve`, since a file is not an entity and
+/// there is nothing for an observer to be triggered on. And
+/// [`Bloom::ScrollRefused`](crate::Bloom::ScrollRefused) is pushed
 /// by `grid::view::extent_check` rather than by an observer here. It reports a decision taken
 /// partway through resolving scroll -- how much of an adjustment an axis would not take -- and
 /// that number exists nowhere else. Re-deriving it from a trigger would mean redoing the clamp
-/// the resolver had just done, against state it had already moved past.
+/// the resolver had just done, against state it had already moved past. The row blooms --
+/// [`Bloom::RowGrown`](crate::Bloom::RowGrown) and [`Bloom::RowBound`](crate::Bloom::RowBound)
+/// -- are pushed by `grid::virtual_list::recycle`, which is the only thing that knows which
+/// index a row container was just handed; the container itself changed only its box.
 pub(crate) struct Funnel;
 
 impl crate::Attachment for Funnel {
@@ -166,10 +223,17 @@ impl crate::Attachment for Funnel {
         foliage.define(dragged);
         foliage.define(drag_started);
         foliage.define(disengaged);
+        foliage.define(double_clicked);
+        foliage.define(long_pressed);
+        foliage.define(swiped);
+        foliage.define(drag_over);
+        foliage.define(drag_leave);
+        foliage.define(dropped);
         foliage.define(focused);
         foliage.define(unfocused);
         foliage.define(key);
         foliage.define(physical_key);
+        foliage.define(shortcut);
         foliage.define(text_changed);
         foliage.define(text_action);
         foliage.define(ended);
@@ -72,6 +72,8 @@ spec_from!(
     ImageSprout => Image,
     LineSprout => Line,
     PolygonSprout => Polygon,
+    PathSprout => Path,
+    ShaderSprout => Shader,
     PolylineSprout => Polyline,
     TextInputSprout => TextInput,
 );
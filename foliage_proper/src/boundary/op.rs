@@ -7,7 +7,8 @@ use crate::{
 };
 
 /// What foliage grows. One variant per core primitive -- the set is closed on purpose: this
-/// is the whole vocabulary of things that can come into existence.
+/// is the whole vocabulary of things that can come into existence. [`Shader`](crate::Shader)
+/// is the one way out, for drawing what the others cannot.
 pub enum Spec {
     /// An element with no primitive of its own -- a container, a hit area, a group.
     Bare(crate::LeafSprout),
@@ -17,6 +18,8 @@ pub enum Spec {
     Image(crate::ImageSprout),
     Line(crate::LineSprout),
     Polygon(crate::PolygonSprout),
+    Path(crate::PathSprout),
+    Shader(crate::ShaderSprout),
     Polyline(crate::PolylineSprout),
     TextInput(crate::TextInputSprout),
 }
@@ -33,6 +36,8 @@ impl Spec {
             Spec::Image(s) => tree.grow_at(at, s, under),
             Spec::Line(s) => tree.grow_at(at, s, under),
             Spec::Polygon(s) => tree.grow_at(at, s, under),
+            Spec::Path(s) => tree.grow_at(at, s, under),
+            Spec::Shader(s) => tree.grow_at(at, s, under),
             Spec::Polyline(s) => tree.grow_at(at, s, under),
             Spec::TextInput(s) => tree.grow_at(at, s, under),
         }
@@ -50,6 +55,11 @@ pub enum Motion {
     Location(Location),
     Polygon(Polygon),
     Outline(Outline),
+    Transform(crate::Transform),
+    Fill(crate::Fill),
+    Shadow(crate::Shadow),
+    BackdropBlur(f32),
+    Uniforms(crate::ShaderUniforms),
 }
 
 /// When a tween runs and how it moves.
@@ -119,6 +129,22 @@ pub(crate) enum Op {
         leaf: Leaf,
         to: f32,
     },
+    Transform {
+        leaf: Leaf,
+        to: crate::Transform,
+    },
+    Fill {
+        leaf: Leaf,
+        to: crate::Fill,
+    },
+    Shadow {
+        leaf: Leaf,
+        to: crate::Shadow,
+    },
+    BackdropBlur {
+        leaf: Leaf,
+        radius: f32,
+    },
     Visible {
         leaf: Leaf,
         yes: bool,
@@ -156,6 +182,18 @@ pub(crate) enum Op {
         leaf: Leaf,
         to: Polygon,
     },
+    Path {
+        leaf: Leaf,
+        to: crate::Path,
+    },
+    Stroke {
+        leaf: Leaf,
+        to: crate::Stroke,
+    },
+    Uniforms {
+        leaf: Leaf,
+        to: crate::ShaderUniforms,
+    },
     Rounding {
         leaf: Leaf,
         to: Rounding,
@@ -192,6 +230,15 @@ pub(crate) enum Op {
         leaf: Leaf,
         to: ScrollTo,
     },
+    ScrollIntoView {
+        leaf: Leaf,
+        align: crate::Align,
+        animated: bool,
+    },
+    Zoom {
+        leaf: Leaf,
+        to: crate::ZoomTo,
+    },
     Name {
         leaf: Leaf,
         name: String,
@@ -227,6 +274,17 @@ pub(crate) enum Op {
         leaf: Leaf,
         constraint: LineConstraint,
     },
+    /// Registers a shortcut, app-wide or scoped to an element's subtree.
+    Shortcut {
+        keys: crate::Chord,
+        id: String,
+        scope: Option<Leaf>,
+    },
+    UnbindShortcut(String),
+    Virtualize {
+        leaf: Leaf,
+        list: crate::VirtualList,
+    },
 }
 
 impl Op {
@@ -237,6 +295,10 @@ impl Op {
             | Op::Text { leaf, .. }
             | Op::Color { leaf, .. }
             | Op::Opacity { leaf, .. }
+            | Op::Transform { leaf, .. }
+            | Op::Fill { leaf, .. }
+            | Op::Shadow { leaf, .. }
+            | Op::BackdropBlur { leaf, .. }
             | Op::Visible { leaf, .. }
             | Op::Location { leaf, .. }
             | Op::Anchor { leaf, .. }
@@ -246,25 +308,34 @@ impl Op {
             | Op::Points { leaf, .. }
             | Op::DrawProgress { leaf, .. }
             | Op::Polygon { leaf, .. }
+            | Op::Path { leaf, .. }
+            | Op::Stroke { leaf, .. }
+            | Op::Uniforms { leaf, .. }
             | Op::Rounding { leaf, .. }
             | Op::Icon { leaf, .. }
             | Op::Animate { leaf, .. }
             | Op::Scroll { leaf, .. }
+            | Op::ScrollIntoView { leaf, .. }
+            | Op::Zoom { leaf, .. }
             | Op::Name { leaf, .. }
             | Op::Watch { leaf, .. }
             | Op::Unwatch { leaf, .. }
             | Op::ImageView { leaf, .. }
             | Op::RoundingSide { leaf, .. }
-            | Op::LineConstraint { leaf, .. } => Some(*leaf),
+            | Op::LineConstraint { leaf, .. }
+            | Op::Virtualize { leaf, .. } => Some(*leaf),
             Op::Timer { leaf, .. } | Op::Hint { leaf, .. } | Op::InputStyle { leaf, .. } => {
                 Some(*leaf)
             }
             Op::Prune(leaf) | Op::Enable(leaf) | Op::Disable(leaf) => Some(*leaf),
             Op::Click { leaf, .. } => Some(*leaf),
+            Op::Shortcut { scope, .. } => *scope,
             // Its own leaf names something that does not exist yet, like a grow.
             Op::Sequence(_) => None,
             // Neither names an element: a tween is a stream of numbers, an asset is bytes.
             Op::Tween { .. } | Op::LoadAsset { .. } => None,
+            // A shortcut names keys; an app-wide one has no element at all.
+            Op::UnbindShortcut(_) => None,
         }
     }
 }
@@ -286,6 +357,9 @@ pub(crate) fn apply(world: &mut World, queue: &mut Vec<Op>) {
         // because both are liveness questions and both have to be answered before the tree
         // below takes the world.
         let mut joined = None;
+        // Whether an `Animate` of a `Transform` finds none to start from. Asked here for the
+        // same reason, and written through the tree along with the animation.
+        let mut unturned = false;
         // A grow's own leaf is *supposed* to name nothing yet -- that is what the op is for.
         // Everything else, including a grow's parent, has to still be alive.
         let subject = match &op {
@@ -295,13 +369,24 @@ pub(crate) fn apply(world: &mut World, queue: &mut Vec<Op>) {
                 Some(parent) => Some(parent.0),
                 None => None,
             },
-            Op::Sequence(_) | Op::Tween { .. } | Op::LoadAsset { .. } => None,
+            Op::Sequence(_)
+            | Op::Tween { .. }
+            | Op::LoadAsset { .. }
+            | Op::Shortcut { scope: None, .. }
+            | Op::UnbindShortcut(_) => None,
             // Resolved here, alongside every other liveness check, because the tree below
             // borrows the world mutably and this reads it.
-            Op::Animate { leaf, sequence, .. } => {
+            Op::Animate {
+                leaf, sequence, to, ..
+            } => {
                 if !alive(world, *leaf) {
                     continue;
                 }
+                // Every element can be turned, but only one that has been carries a
+                // `Transform` -- and a tween finds its start on the element or does not run.
+                // An untouched one starts from where it is drawn: upright, at full size.
+                unturned = matches!(to, Motion::Transform(_))
+                    && world.get::<crate::Transform>(leaf.0).is_none();
                 joined = sequence.filter(|seq| alive(world, *seq)).map(|seq| seq.0);
                 Some(leaf.0)
             }
@@ -376,6 +461,23 @@ pub(crate) fn apply(world: &mut World, queue: &mut Vec<Op>) {
                     .remove(leaf, sap);
                 continue;
             }
+            // A registry like `Watches`, for the same reason.
+            Op::Shortcut { keys, id, .. } => {
+                let mut shortcuts = world.resource_mut::<crate::shortcut::Shortcuts>();
+                // A scope already holding a binding is already watched for its wither.
+                let watched = subject.is_some_and(|scope| shortcuts.scoped(scope));
+                shortcuts.bind(keys, id, subject);
+                if let Some(scope) = subject.filter(|_| !watched) {
+                    crate::AsTree::tree(world).subscribe(scope, crate::shortcut::Shortcuts::prune);
+                }
+                continue;
+            }
+            Op::UnbindShortcut(id) => {
+                world
+                    .resource_mut::<crate::shortcut::Shortcuts>()
+                    .unbind(&id);
+                continue;
+            }
             _ => {}
         }
         let mut tree = crate::AsTree::tree(world);
@@ -383,7 +485,11 @@ pub(crate) fn apply(world: &mut World, queue: &mut Vec<Op>) {
             // Answered above, against the `World` this `Tree` has now taken, and `continue`s
             // there. Spelled out rather than swept into a catch-all so that adding an op and
             // forgetting to apply it stays a compile error.
-            Op::Click { .. } | Op::Watch { .. } | Op::Unwatch { .. } => {
+            Op::Click { .. }
+            | Op::Watch { .. }
+            | Op::Unwatch { .. }
+            | Op::Shortcut { .. }
+            | Op::UnbindShortcut(_) => {
                 unreachable!("answered before the tree is taken")
             }
             Op::Grow { leaf, spec, .. } => spec.grow(&mut tree, leaf.0, subject),
@@ -393,6 +499,12 @@ pub(crate) fn apply(world: &mut World, queue: &mut Vec<Op>) {
             Op::Text { value, .. } => tree.write_to(subject.unwrap(), crate::TextValue(value)),
             Op::Color { to, .. } => tree.write_to(subject.unwrap(), to),
             Op::Opacity { to, .. } => tree.write_to(subject.unwrap(), crate::Opacity::new(to)),
+            Op::Transform { to, .. } => tree.write_to(subject.unwrap(), to),
+            Op::Fill { to, .. } => tree.write_to(subject.unwrap(), to),
+            Op::Shadow { to, .. } => tree.write_to(subject.unwrap(), to),
+            Op::BackdropBlur { radius, .. } => {
+                tree.write_to(subject.unwrap(), crate::BackdropBlur::new(radius))
+            }
             Op::Visible { yes, .. } => tree.write_to(subject.unwrap(), crate::Visibility::new(yes)),
             Op::Location { to, .. } => tree.write_to(subject.unwrap(), to),
             Op::Anchor { to, .. } => tree.write_to(subject.unwrap(), crate::Anchor::new(to.0)),
@@ -404,11 +516,17 @@ pub(crate) fn apply(world: &mut World, queue: &mut Vec<Op>) {
                 tree.write_to(subject.unwrap(), crate::PolylineDrawProgress(to))
             }
             Op::Polygon { to, .. } => tree.write_to(subject.unwrap(), to),
+            Op::Path { to, .. } => tree.write_to(subject.unwrap(), to),
+            Op::Stroke { to, .. } => tree.write_to(subject.unwrap(), to),
+            Op::Uniforms { to, .. } => tree.write_to(subject.unwrap(), to),
             Op::Rounding { to, .. } => tree.write_to(subject.unwrap(), to),
             Op::Icon { to, .. } => tree.write_to(subject.unwrap(), crate::IconValue(to)),
             // A sequence whose leaf has withered is simply no sequence -- the animation still
             // runs, it just reports to nothing.
             Op::Animate { to, timing, .. } => {
+                if unturned {
+                    tree.write_to(subject.unwrap(), crate::Transform::default());
+                }
                 animate(&mut tree, subject.unwrap(), to, timing, joined)
             }
             Op::Sequence(leaf) => tree.sequence_at(leaf.0),
@@ -425,6 +543,15 @@ pub(crate) fn apply(world: &mut World, queue: &mut Vec<Op>) {
                 ));
             }
             Op::Scroll { to, .. } => tree.write_to(subject.unwrap(), to),
+            Op::ScrollIntoView {
+                align, animated, ..
+            } => tree.write_to(
+                subject.unwrap(),
+                crate::grid::reveal::ScrollIntoView { align, animated },
+            ),
+            Op::Zoom { to, .. } => {
+                tree.write_to(subject.unwrap(), crate::grid::zoom::ZoomRequest(to))
+            }
             Op::Name { name, .. } => tree.name(subject.unwrap(), name),
             Op::LoadAsset { key, source } => {
                 tree.send(crate::asset::LoadAsset { key, source });
@@ -432,6 +559,7 @@ pub(crate) fn apply(world: &mut World, queue: &mut Vec<Op>) {
             Op::ImageView { view, .. } => tree.write_to(subject.unwrap(), view),
             Op::RoundingSide { side, .. } => tree.write_to(subject.unwrap(), side),
             Op::LineConstraint { constraint, .. } => tree.write_to(subject.unwrap(), constraint),
+            Op::Virtualize { list, .. } => tree.write_to(subject.unwrap(), list),
         }
         // Flushed per op rather than once at the end: an element's own structure is built by
         // reactions that only run on flush, and a later op in this same queue may name what
@@ -484,5 +612,10 @@ fn animate(tree: &mut Tree, entity: Entity, to: Motion, timing: Timing, sequence
         Motion::Location(v) => run!(v),
         Motion::Polygon(v) => run!(v),
         Motion::Outline(v) => run!(v),
+        Motion::Transform(v) => run!(v),
+        Motion::Fill(v) => run!(v),
+        Motion::Shadow(v) => run!(v),
+        Motion::BackdropBlur(v) => run!(crate::BackdropBlur::new(v)),
+        Motion::Uniforms(v) => run!(v),
     }
 }
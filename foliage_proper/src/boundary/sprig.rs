@@ -1,7 +1,7 @@
+use crate::boundary::bloom::Bloom;
 use crate::boundary::leaf::Leaf;
 use crate::boundary::op::Op;
 use crate::boundary::verbs::Queues;
-use crate::boundary::bloom::Bloom;
 use crate::coordinate::section::Section;
 use crate::{Layout, Logical, TimeDelta};
 use bevy_ecs::entity::RemoteAllocator;
@@ -3,8 +3,8 @@ use crate::boundary::op::{Motion, Op, Spec, Timing};
 use crate::boundary::tween::{Channel, Tween};
 use crate::coordinate::position::Position;
 use crate::{
-    AssetKey, AssetSource, Color, Elevation, FontSize, GlyphColors, LineConstraint, Location,
-    Logical, Polygon, Rounding, ScrollTo, Side,
+    AssetKey, AssetSource, Chord, Color, Elevation, FontSize, GlyphColors, LineConstraint,
+    Location, Logical, Polygon, Rounding, ScrollTo, Side, VirtualList,
 };
 use crate::{ImageView, TextInputStyle};
 
@@ -68,7 +68,7 @@ pub trait Grows: Queues {
     /// click observers fire in their usual order, and anything reading the pointer -- a text input
     /// placing its caret -- sees a position rather than nothing.
     ///
-    /// This is the only way to move focus programmatically -- there is deliberately no way to
+    /// This and Tab are the only ways to move focus -- there is deliberately no way to
     /// hand an element the keyboard without also sending the press a real one would: nothing in
     /// the tree does anything on focus alone that a click doesn't already cover, and a text
     /// input's own caret placement/visibility runs *only* off the click path (`Engaged`), not off
@@ -110,6 +110,26 @@ pub trait Grows: Queues {
     fn opacity(&mut self, leaf: Leaf, to: f32) {
         self.push(Op::Opacity { leaf, to });
     }
+    /// Turns and scales an element, and everything beneath it, as it is drawn. See
+    /// [`Transform`](crate::Transform).
+    fn transform(&mut self, leaf: Leaf, to: crate::Transform) {
+        self.push(Op::Transform { leaf, to });
+    }
+    /// Paints a [`Panel`](crate::Panel) or [`Polygon`](crate::Polygon) with a gradient, or
+    /// back with its color. See [`Fill`](crate::Fill).
+    fn fill(&mut self, leaf: Leaf, to: crate::Fill) {
+        self.push(Op::Fill { leaf, to });
+    }
+    /// Casts a soft shadow beneath a [`Panel`](crate::Panel) or
+    /// [`Polygon`](crate::Polygon); [`Shadow::none`](crate::Shadow::none) takes it away.
+    fn shadow(&mut self, leaf: Leaf, to: crate::Shadow) {
+        self.push(Op::Shadow { leaf, to });
+    }
+    /// Frosts what is drawn beneath a [`Panel`](crate::Panel), blurred by `radius` logical
+    /// pixels; `0.0` clears it. See [`BackdropBlur`](crate::BackdropBlur).
+    fn backdrop_blur(&mut self, leaf: Leaf, radius: f32) {
+        self.push(Op::BackdropBlur { leaf, radius });
+    }
     /// Shows or hides an element and everything beneath it. A hidden element keeps its state
     /// and its `Leaf`; it is skipped by drawing and hit-testing.
     fn visible(&mut self, leaf: Leaf, yes: bool) {
@@ -154,6 +174,19 @@ pub trait Grows: Queues {
     fn polygon(&mut self, leaf: Leaf, to: Polygon) {
         self.push(Op::Polygon { leaf, to });
     }
+    /// Redraws a path's shape.
+    fn path(&mut self, leaf: Leaf, to: crate::Path) {
+        self.push(Op::Path { leaf, to });
+    }
+    /// A path's outline; [`Stroke::none`](crate::Stroke::none) takes it away.
+    fn stroke(&mut self, leaf: Leaf, to: crate::Stroke) {
+        self.push(Op::Stroke { leaf, to });
+    }
+    /// What a [`Shader`](crate::Shader)'s fragment reads. Set it every frame for motion the
+    /// fragment computes, or [`tween`](Grows::tween) it.
+    fn uniforms(&mut self, leaf: Leaf, to: crate::ShaderUniforms) {
+        self.push(Op::Uniforms { leaf, to });
+    }
     /// A panel's corner-radius bracket.
     fn rounding(&mut self, leaf: Leaf, to: Rounding) {
         self.push(Op::Rounding { leaf, to });
@@ -215,6 +248,31 @@ pub trait Grows: Queues {
     fn scroll(&mut self, leaf: Leaf, to: ScrollTo) {
         self.push(Op::Scroll { leaf, to });
     }
+    /// Scrolls every view above `leaf` just far enough to show it, innermost first, placing it
+    /// per `align` within each. Animated, the views ease there over
+    /// [`ScrollMomentum::reveal_ms`](crate::ScrollMomentum::reveal_ms); a press stops them.
+    ///
+    /// For jumping to a search result or a field that failed validation, where the offsets
+    /// involved belong to views the caller should not have to know about.
+    fn scroll_into_view(&mut self, leaf: Leaf, align: crate::Align, animated: bool) {
+        self.push(Op::ScrollIntoView {
+            leaf,
+            align,
+            animated,
+        });
+    }
+    /// Zooms a [`Zoom`](crate::Zoom) view to a scale about its middle, or to fit its content,
+    /// within its limits. A view without one ignores it.
+    fn zoom(&mut self, leaf: Leaf, to: crate::ZoomTo) {
+        self.push(Op::Zoom { leaf, to });
+    }
+    /// Makes a view's content a [`VirtualList`]: rows grown only while in sight, reported
+    /// through [`Bloom::RowGrown`](crate::Bloom::RowGrown) and
+    /// [`Bloom::RowBound`](crate::Bloom::RowBound). Again with a new count, or the same one
+    /// after the data changed, rebinds the rows on screen.
+    fn virtualize(&mut self, view: Leaf, list: VirtualList) {
+        self.push(Op::Virtualize { leaf: view, list });
+    }
     /// Names an element for later lookup.
     fn name(&mut self, leaf: Leaf, name: impl Into<String>) {
         self.push(Op::Name {
@@ -257,6 +315,32 @@ pub trait Grows: Queues {
     fn line_constraint(&mut self, leaf: Leaf, constraint: LineConstraint) {
         self.push(Op::LineConstraint { leaf, constraint });
     }
+    /// Reports [`Bloom::Shortcut`](crate::Bloom::Shortcut) with `id` whenever `keys` are
+    /// pressed, wherever focus is. Binding an `id` again replaces what it was bound to.
+    ///
+    /// Stands aside while a focused text input has a use for the key -- a letter it types, a
+    /// binding it edits with -- so a shortcut never fires on what was meant as typing.
+    fn shortcut(&mut self, keys: impl Into<Chord>, id: impl Into<String>) {
+        self.push(Op::Shortcut {
+            keys: keys.into(),
+            id: id.into(),
+            scope: None,
+        });
+    }
+    /// The same, live only while focus is on `scope` or inside it -- a dialog's own keys,
+    /// gone once it closes. Where it shares keys with a wider shortcut it wins, and the wider
+    /// one does not fire.
+    fn scoped_shortcut(&mut self, scope: Leaf, keys: impl Into<Chord>, id: impl Into<String>) {
+        self.push(Op::Shortcut {
+            keys: keys.into(),
+            id: id.into(),
+            scope: Some(scope),
+        });
+    }
+    /// Removes the shortcut registered under `id`, scoped or not.
+    fn unbind_shortcut(&mut self, id: impl Into<String>) {
+        self.push(Op::UnbindShortcut(id.into()));
+    }
 }
 
 impl<T: Queues> Grows for T {}
@@ -13,7 +13,11 @@ use crate::coordinate::position::{CReprPosition, Position};
 use crate::coordinate::{
     CoordinateContext, CoordinateUnit, Coordinates, Logical, Numerical, Physical,
 };
-use crate::{Anchor, AnchorDeps, Children, Location, Resolve, Resolved};
+use crate::grid::flow::Reflow;
+use crate::grid::hug::Remeasure;
+use crate::{
+    Anchor, AnchorDeps, Children, Flow, Layout, Location, Parent, Resolve, Resolved, Short,
+};
 
 #[derive(Copy, Clone, Default, Component, PartialEq, PartialOrd)]
 #[component(on_insert = Section::<Logical>::on_insert)]
@@ -307,6 +311,19 @@ pub struct LayoutSection(pub Section<Logical>);
 impl LayoutSection {
     fn on_insert(mut world: DeferredWorld, ctx: HookContext) {
         let this = ctx.entity;
+        // Up as well as down: a parent that places its children, or is sized by them, takes
+        // this box now, in the same pass, rather than a frame behind it.
+        if let Some(parent) = world.get::<Parent>(this).and_then(|p| p.id) {
+            if world.get::<Flow>(parent).is_some() {
+                world.tree().send_to(Reflow::new(), parent);
+            }
+            if let Some(location) = world.get::<Location>(parent)
+                && location.hug_axes(*world.resource::<Layout>(), *world.resource::<Short>())
+                    != (false, false)
+            {
+                world.tree().send_to(Remeasure::new(), parent);
+            }
+        }
         let mut deps = world.get::<Children>(this).unwrap().ids.clone();
         for d in deps.clone().iter() {
             if let Some(stack) = world.get::<Anchor>(*d) {
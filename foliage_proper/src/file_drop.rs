@@ -0,0 +1,293 @@
+use std::path::{Path, PathBuf};
+
+use crate::asset::AssetFetch;
+use crate::boundary::bloom::Emissions;
+use crate::boundary::leaf::Grown;
+use crate::foliage::MainMarkers;
+use crate::ginkgo::viewport::ViewportHandle;
+use crate::interaction::MouseAdapter;
+use crate::interaction::drop_target::DropTargets;
+use crate::{
+    Asset, AssetKey, AssetLoader, Attachment, Bloom, Foliage, Logical, Moment, Parent, Position,
+    Resource,
+};
+use bevy_ecs::prelude::IntoScheduleConfigs;
+use bevy_ecs::system::{Query, Res, ResMut};
+
+/// Files dragged into the window from outside it -- the desktop, a file manager, another
+/// browser tab.
+///
+/// The platform reports these with no idea what is under them, so they are queued here and
+/// resolved once a frame against the same drop targets an in-app drag lands on. An upload area
+/// marked [`drop_target`](crate::Sprout::drop_target) takes a card and a photo alike, and there
+/// is one hit test deciding both.
+#[derive(Resource, Default)]
+pub(crate) struct FileDrops {
+    pending: Vec<(FileDrop, Position<Logical>)>,
+    /// Whether the pointer has gone unreported since a file came over the window. Most
+    /// platforms stop sending `CursorMoved` for as long as the pointer carries a file, and
+    /// until it moves again `MouseAdapter` only knows where it was before the drag began.
+    stale: bool,
+    /// Files let go while `stale`, waiting for the pointer to say where that was.
+    held: Vec<(PathBuf, AssetKey, Moment)>,
+    /// Dropped files reported but not yet read, to announce as they land.
+    reading: Vec<AssetKey>,
+}
+/// One platform report, before it has been given a target.
+pub(crate) enum FileDrop {
+    Hovered(PathBuf),
+    Cancelled,
+    Dropped(PathBuf, AssetKey),
+}
+impl FileDrops {
+    /// How long a drop waits for the pointer to move before it is placed where the pointer was
+    /// last seen. A hand that lets go and holds perfectly still is the only thing that waits
+    /// this long; everything else moves within a frame or two.
+    const PATIENCE_MS: u128 = 500;
+    /// Queues `drop` at `at`, in the same window-relative logical space `MouseAdapter` keeps
+    /// its cursor in.
+    pub(crate) fn queue(&mut self, drop: FileDrop, at: Position<Logical>) {
+        self.pending.push((drop, at));
+    }
+    /// A file came over the window, with the pointer last seen at `at`.
+    pub(crate) fn hovered(&mut self, path: PathBuf, at: Position<Logical>) {
+        self.stale = true;
+        self.queue(FileDrop::Hovered(path), at);
+    }
+    /// A file was let go, with the pointer last seen at `at` -- which is where it was let go
+    /// only if the pointer has been reported since the drag came in. Held for the next report
+    /// otherwise.
+    pub(crate) fn dropped(&mut self, path: PathBuf, key: AssetKey, at: Position<Logical>) {
+        if self.stale {
+            self.held.push((path, key, Moment::now()));
+        } else {
+            self.queue(FileDrop::Dropped(path, key), at);
+        }
+    }
+    /// The pointer was reported at `at`: anything let go while it was out of sight was let go
+    /// here.
+    pub(crate) fn moved(&mut self, at: Position<Logical>) {
+        self.stale = false;
+        for (path, key, _) in self.held.drain(..) {
+            self.pending.push((FileDrop::Dropped(path, key), at));
+        }
+    }
+}
+/// What a browser drop handed over, collected by the DOM listeners until the next frame drains
+/// it. `NonSend` for the same reason as `VirtualInputQueue`: wasm32 in the browser is single
+/// threaded, and the closures only need to share it with one system.
+#[cfg(target_family = "wasm")]
+#[derive(Clone, Default)]
+pub(crate) struct WebFileQueue(
+    std::rc::Rc<std::cell::RefCell<Vec<(FileDrop, Position<Logical>, Option<AssetFetch>)>>>,
+);
+pub(crate) struct FileDropAdapter;
+impl Attachment for FileDropAdapter {
+    fn attach(foliage: &mut Foliage) {
+        foliage.world.insert_resource(FileDrops::default());
+        #[cfg(target_family = "wasm")]
+        {
+            let queue = WebFileQueue::default();
+            FileDropAdapter::create_hook(queue.clone());
+            foliage.world.insert_non_send(queue);
+            foliage
+                .main
+                .add_systems(FileDropAdapter::drain_web.in_set(MainMarkers::External));
+        }
+        foliage
+            .main
+            .add_systems(FileDropAdapter::resolve.in_set(MainMarkers::Process));
+    }
+}
+impl FileDropAdapter {
+    /// Reports every queued file against the topmost drop target beneath it, then every dropped
+    /// file whose bytes have since landed.
+    ///
+    /// Pushed straight into `Emissions` rather than through an observer in the funnel, the same
+    /// exception `ScrollRefused` takes: a file is not an entity and has no event target, and a
+    /// file dropped on nothing in particular is still reported -- with no `Leaf` -- because a
+    /// window-wide drop is a perfectly good thing for an app to accept. Its `AssetLoaded` is
+    /// announced here too, since no entity is waiting on the key to have it sent.
+    #[allow(clippy::too_many_arguments)]
+    fn resolve(
+        mut drops: ResMut<FileDrops>,
+        targets: DropTargets,
+        viewport: Res<ViewportHandle>,
+        mouse: Res<MouseAdapter>,
+        loader: Res<AssetLoader>,
+        grown: Query<&Grown>,
+        parents: Query<&Parent>,
+        mut emissions: ResMut<Emissions>,
+    ) {
+        let drops = &mut *drops;
+        let (expired, held) = drops.held.drain(..).partition::<Vec<_>, _>(|(.., since)| {
+            since.elapsed().as_millis() >= FileDrops::PATIENCE_MS
+        });
+        drops.held = held;
+        for (path, key, _) in expired {
+            drops.queue(FileDrop::Dropped(path, key), mouse.cursor);
+        }
+        let offset = viewport.section().position;
+        for (drop, at) in drops.pending.drain(..) {
+            let leaf = targets
+                .beneath(offset + at, None)
+                .and_then(|target| crate::boundary::funnel::attribute(target, &grown, &parents));
+            emissions.push(match drop {
+                FileDrop::Hovered(path) => Bloom::FileHovered { leaf, path },
+                FileDrop::Cancelled => Bloom::FileHoverCancelled { leaf },
+                FileDrop::Dropped(path, key) => {
+                    drops.reading.push(key);
+                    Bloom::FileDropped { leaf, path, key }
+                }
+            });
+        }
+        // A read that failed never lands, and is forgotten rather than waited on.
+        drops.reading.retain(|key| {
+            if loader.assets.contains_key(key) {
+                emissions.push(Bloom::AssetLoaded { key: *key });
+                false
+            } else {
+                loader.awaits(*key)
+            }
+        });
+    }
+    /// Starts reading a file the platform reported dropped, and hands back the key its bytes
+    /// will land under.
+    ///
+    /// The native half of what `AssetSource::Url` does: the fetch is queued with `AssetLoader`
+    /// and the read runs on a thread of its own, so a dropped video is not a frozen window.
+    /// Never reached on web, where winit reports no drops and `create_hook` reads them instead.
+    pub(crate) fn read(path: &Path, loader: &mut AssetLoader) -> AssetKey {
+        let key = AssetLoader::generate_key();
+        let (fetch, sender) = AssetFetch::new(key);
+        loader.queue_fetch(fetch);
+        let path = path.to_path_buf();
+        std::thread::spawn(move || match std::fs::read(&path) {
+            Ok(bytes) => {
+                sender.send(Asset::new(bytes)).ok();
+            }
+            // A directory, a permission: nothing anyone can act on. Dropping the sender ends
+            // the fetch.
+            Err(error) => {
+                tracing::warn!(?path, %error, "file_drop: dropped file unreadable");
+            }
+        });
+        key
+    }
+    /// Moves what the DOM listeners collected into the frame, handing each file's read to
+    /// `AssetLoader` as an ordinary fetch so its bytes land the way a `Url` asset's do.
+    #[cfg(target_family = "wasm")]
+    fn drain_web(
+        queue: bevy_ecs::system::NonSend<WebFileQueue>,
+        mut drops: ResMut<FileDrops>,
+        mut loader: ResMut<AssetLoader>,
+    ) {
+        for (drop, at, fetch) in queue.0.borrow_mut().drain(..) {
+            if let Some(fetch) = fetch {
+                loader.queue_fetch(fetch);
+            }
+            drops.queue(drop, at);
+        }
+    }
+    /// The browser's half of `HoveredFile`/`DroppedFile`: `dragenter`, `dragleave` and `drop`,
+    /// with the files read out of the `DataTransfer`.
+    ///
+    /// Listened for on the document rather than on the canvas, because the canvas does not
+    /// exist yet when this attaches -- and it fills the page, so a drop on it bubbles up to here
+    /// regardless. `dragover` has to be answered with `preventDefault` or the browser refuses
+    /// the drop and navigates to the file instead.
+    ///
+    /// A browser hands over no path, only a name, so that is what `path` carries on web.
+    #[cfg(target_family = "wasm")]
+    fn create_hook(queue: WebFileQueue) {
+        use wasm_bindgen::JsCast;
+        use wasm_bindgen::closure::Closure;
+
+        let document = web_sys::window().unwrap().document().unwrap();
+        let at =
+            |e: &web_sys::DragEvent| Position::logical((e.client_x() as f32, e.client_y() as f32));
+
+        let enter_queue = queue.clone();
+        let on_enter = Closure::wrap(Box::new(move |e: web_sys::DragEvent| {
+            e.prevent_default();
+            // Entering the page, not moving between elements on it -- the same test `dragleave`
+            // makes below, for the same reason.
+            if e.related_target().is_some() {
+                return;
+            }
+            // A browser keeps a dragged file's name to itself until it is dropped, and says only
+            // that a file is coming. So a hover on web reports one empty path per file.
+            let Some(items) = e.data_transfer().map(|d| d.items()) else {
+                return;
+            };
+            for i in 0..items.length() {
+                if items.get(i).is_some_and(|item| item.kind() == "file") {
+                    enter_queue.0.borrow_mut().push((
+                        FileDrop::Hovered(PathBuf::new()),
+                        at(&e),
+                        None,
+                    ));
+                }
+            }
+        }) as Box<dyn FnMut(_)>);
+        document
+            .add_event_listener_with_callback("dragenter", on_enter.as_ref().unchecked_ref())
+            .unwrap();
+        on_enter.forget();
+
+        let on_over = Closure::wrap(Box::new(move |e: web_sys::DragEvent| {
+            e.prevent_default();
+        }) as Box<dyn FnMut(_)>);
+        document
+            .add_event_listener_with_callback("dragover", on_over.as_ref().unchecked_ref())
+            .unwrap();
+        on_over.forget();
+
+        let leave_queue = queue.clone();
+        let on_leave = Closure::wrap(Box::new(move |e: web_sys::DragEvent| {
+            // `dragleave` also fires moving between elements *inside* the page; only leaving
+            // the page altogether has no element on the other side.
+            if e.related_target().is_none() {
+                leave_queue
+                    .0
+                    .borrow_mut()
+                    .push((FileDrop::Cancelled, at(&e), None));
+            }
+        }) as Box<dyn FnMut(_)>);
+        document
+            .add_event_listener_with_callback("dragleave", on_leave.as_ref().unchecked_ref())
+            .unwrap();
+        on_leave.forget();
+
+        let drop_queue = queue;
+        let on_drop = Closure::wrap(Box::new(move |e: web_sys::DragEvent| {
+            e.prevent_default();
+            let Some(files) = e.data_transfer().and_then(|d| d.files()) else {
+                return;
+            };
+            for i in 0..files.length() {
+                let Some(file) = files.get(i) else {
+                    continue;
+                };
+                let key = AssetLoader::generate_key();
+                let (fetch, sender) = AssetFetch::new(key);
+                let read = file.array_buffer();
+                wasm_bindgen_futures::spawn_local(async move {
+                    if let Ok(buffer) = wasm_bindgen_futures::JsFuture::from(read).await {
+                        let bytes = js_sys::Uint8Array::new(&buffer).to_vec();
+                        sender.send(Asset::new(bytes)).ok();
+                    }
+                });
+                drop_queue.0.borrow_mut().push((
+                    FileDrop::Dropped(PathBuf::from(file.name()), key),
+                    at(&e),
+                    Some(fetch),
+                ));
+            }
+        }) as Box<dyn FnMut(_)>);
+        document
+            .add_event_listener_with_callback("drop", on_drop.as_ref().unchecked_ref())
+            .unwrap();
+        on_drop.forget();
+    }
+}
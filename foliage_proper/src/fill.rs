@@ -0,0 +1,204 @@
+use crate::anim::interpolation::Interpolations;
+use crate::{Animate, Attachment, Color, Component, Coordinates, Foliage};
+
+/// What a [`Panel`](crate::Panel) or [`Polygon`](crate::Polygon) is painted with: its
+/// [`Color`], or a gradient laid across its box.
+///
+/// Evaluated per pixel in the shader, so a gradient header or button stays smooth at any
+/// size, and follows the shape's rounding, outline and [`Transform`](crate::Transform) like
+/// a solid fill does. [`Opacity`](crate::Opacity) fades every stop alike.
+///
+/// Animatable as [`Motion::Fill`](crate::Motion::Fill). Two gradients of the same kind tween
+/// angle, centre and every stop; a tween to another kind -- or from `Solid` -- cuts to it
+/// as it starts, since there is nothing between a line and a circle to pass through. A
+/// stop list shorter than the other is padded by repeating its last stop, so a two-stop
+/// gradient can grow a third.
+#[derive(Component, Copy, Clone, Debug, PartialEq, Default)]
+pub enum Fill {
+    /// The element's own [`Color`].
+    #[default]
+    Solid,
+    /// Bands perpendicular to `angle` -- radians, clockwise on screen from left-to-right,
+    /// so `0.0` runs across and `FRAC_PI_2` runs down. The first and last stops land on the
+    /// box's corners, whatever its shape.
+    Linear { angle: f32, stops: Stops },
+    /// Rings about `center`, as fractions of the box -- `(0.5, 0.5)` its middle. The last
+    /// stop lands on the farthest corner.
+    Radial { center: Coordinates, stops: Stops },
+}
+impl Attachment for Fill {
+    fn attach(foliage: &mut Foliage) {
+        foliage.enable_animation::<Self>();
+    }
+}
+impl Fill {
+    /// Which variant, as the shader reads it.
+    fn kind(&self) -> f32 {
+        match self {
+            Fill::Solid => 0.0,
+            Fill::Linear { .. } => 1.0,
+            Fill::Radial { .. } => 2.0,
+        }
+    }
+    /// The angle, or the centre, as two numbers.
+    fn geometry(&self) -> (f32, f32) {
+        match self {
+            Fill::Solid => (0.0, 0.0),
+            Fill::Linear { angle, .. } => (*angle, 0.0),
+            Fill::Radial { center, .. } => (center.a(), center.b()),
+        }
+    }
+    fn stops(&self) -> Stops {
+        match self {
+            Fill::Solid => Stops::new(),
+            Fill::Linear { stops, .. } | Fill::Radial { stops, .. } => *stops,
+        }
+    }
+    /// Laid out for an instance buffer, every stop faded by `opacity`.
+    pub(crate) fn c_repr(&self, opacity: f32) -> CReprFill {
+        let geometry = match self {
+            Fill::Solid => [0.0; 4],
+            Fill::Linear { angle, .. } => [1.0, angle.cos(), angle.sin(), 0.0],
+            Fill::Radial { center, .. } => [2.0, center.a(), center.b(), 0.0],
+        };
+        let (offsets, colors) = self.stops().padded();
+        CReprFill {
+            geometry,
+            offsets,
+            colors: colors.map(|c| c.with_opacity(opacity).c_repr().value),
+        }
+    }
+}
+impl Animate for Fill {
+    fn interpolations(start: &Self, end: &Self) -> Interpolations {
+        // Across kinds nothing is tweened: every channel starts where it ends.
+        let start = if start.kind() == end.kind() {
+            start
+        } else {
+            end
+        };
+        let ((sa, sb), (ea, eb)) = (start.geometry(), end.geometry());
+        let mut interpolations = Interpolations::new()
+            .with(end.kind(), end.kind())
+            .with(sa, ea)
+            .with(sb, eb);
+        let ((so, sc), (eo, ec)) = (start.stops().padded(), end.stops().padded());
+        for i in 0..Stops::MAX {
+            interpolations = interpolations
+                .with(so[i], eo[i])
+                .with(sc[i].r(), ec[i].r())
+                .with(sc[i].g(), ec[i].g())
+                .with(sc[i].b(), ec[i].b())
+                .with(sc[i].a(), ec[i].a());
+        }
+        interpolations
+    }
+
+    fn apply(&mut self, interpolations: &mut Interpolations) {
+        let kind = interpolations.read(0).unwrap_or(self.kind());
+        let (mut a, mut b) = self.geometry();
+        if let Some(v) = interpolations.read(1) {
+            a = v;
+        }
+        if let Some(v) = interpolations.read(2) {
+            b = v;
+        }
+        let (mut offsets, mut colors) = self.stops().padded();
+        for i in 0..Stops::MAX {
+            let at = 3 + i * 5;
+            if let Some(o) = interpolations.read(at) {
+                offsets[i] = o;
+            }
+            if let Some(r) = interpolations.read(at + 1) {
+                colors[i].set_red(r);
+            }
+            if let Some(g) = interpolations.read(at + 2) {
+                colors[i].set_green(g);
+            }
+            if let Some(b) = interpolations.read(at + 3) {
+                colors[i].set_blue(b);
+            }
+            if let Some(a) = interpolations.read(at + 4) {
+                colors[i].set_alpha(a);
+            }
+        }
+        let stops = Stops {
+            len: Stops::MAX,
+            offsets,
+            colors,
+        };
+        *self = if kind < 0.5 {
+            Fill::Solid
+        } else if kind < 1.5 {
+            Fill::Linear { angle: a, stops }
+        } else {
+            Fill::Radial {
+                center: Coordinates::new(a, b),
+                stops,
+            }
+        };
+    }
+}
+
+/// A gradient's colors, each at an offset along it from `0.0` to `1.0` -- up to
+/// [`Stops::MAX`], kept in order of offset however they were added.
+///
+/// `Stops::new().stop(0.0, Color::sky(400)).stop(1.0, Color::indigo(600))`. Before the first
+/// stop is its color, past the last is the last's; with none, the gradient is transparent.
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub struct Stops {
+    len: usize,
+    offsets: [f32; Stops::MAX],
+    colors: [Color; Stops::MAX],
+}
+impl Stops {
+    /// How many stops a gradient holds -- what fits one instance's attributes. Any past this
+    /// are dropped.
+    pub const MAX: usize = 4;
+    /// No stops yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Adds `color` at `offset` along the gradient, clamped to `0.0..=1.0`.
+    pub fn stop(mut self, offset: f32, color: Color) -> Self {
+        if self.len == Self::MAX {
+            return self;
+        }
+        let offset = offset.clamp(0.0, 1.0);
+        let at = self.offsets[..self.len].partition_point(|o| *o <= offset);
+        self.offsets.copy_within(at..self.len, at + 1);
+        self.colors.copy_within(at..self.len, at + 1);
+        self.offsets[at] = offset;
+        self.colors[at] = color;
+        self.len += 1;
+        self
+    }
+    /// All [`MAX`](Self::MAX) slots filled, the last stop repeated into the empty ones -- how
+    /// the shader reads them, and how a shorter list tweens against a longer one.
+    fn padded(&self) -> ([f32; Self::MAX], [Color; Self::MAX]) {
+        let Some(last) = self.len.checked_sub(1) else {
+            return (
+                [0.0; Self::MAX],
+                [Color::default().with_opacity(0.0); Self::MAX],
+            );
+        };
+        let mut offsets = self.offsets;
+        let mut colors = self.colors;
+        for i in self.len..Self::MAX {
+            offsets[i] = offsets[last];
+            colors[i] = colors[last];
+        }
+        (offsets, colors)
+    }
+}
+
+/// [`Fill`] as the shader reads it: `geometry` is the kind, then the direction's cosine and
+/// sine or the centre; `offsets` and `colors` the stops, padded to [`Stops::MAX`]. A zeroed
+/// slot is `Solid`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, bytemuck::Pod, bytemuck::Zeroable)]
+pub(crate) struct CReprFill {
+    geometry: [f32; 4],
+    offsets: [f32; 4],
+    colors: [[f32; 4]; 4],
+}
@@ -0,0 +1,49 @@
+// `Fill`, prepended to every shader that paints one (see the `format!` in each pipeline's
+// `renderer`). `geometry.x` is the kind -- 0 solid, 1 linear, 2 radial -- and `.yz` the
+// direction's cosine and sine, or the centre as fractions of the box. The four stops are
+// padded by repeating the last, so every stop after it is a segment of no length.
+
+// How far `t` is through the segment from `a` to `b`; a step where the two coincide.
+fn fill_segment(t: f32, a: f32, b: f32) -> f32 {
+    return select(step(a, t), clamp((t - a) / (b - a), 0.0, 1.0), b > a);
+}
+fn premultiplied(c: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(c.rgb * c.a, c.a);
+}
+// The color at `p`, with `section` (origin, size) the box it is laid across in the same
+// space. A solid fill is `solid` as it is.
+fn filled(
+    solid: vec4<f32>,
+    p: vec2<f32>,
+    section: vec4<f32>,
+    geometry: vec4<f32>,
+    offsets: vec4<f32>,
+    c0: vec4<f32>,
+    c1: vec4<f32>,
+    c2: vec4<f32>,
+    c3: vec4<f32>
+) -> vec4<f32> {
+    if geometry.x < 0.5 {
+        return solid;
+    }
+    let half = section.zw * 0.5;
+    var t = 0.0;
+    if geometry.x < 1.5 {
+        // The box projected onto the direction, so the end stops meet its far corners.
+        let reach = abs(geometry.y) * half.x + abs(geometry.z) * half.y;
+        t = dot(p - (section.xy + half), geometry.yz) / max(2.0 * reach, 0.0001) + 0.5;
+    } else {
+        let center = section.xy + geometry.yz * section.zw;
+        let far = max(abs(section.xy - center), abs(section.xy + section.zw - center));
+        t = distance(p, center) / max(length(far), 0.0001);
+    }
+    // Mixed premultiplied, so a stop fading to transparent does not darken on the way.
+    var c = premultiplied(c0);
+    c = mix(c, premultiplied(c1), fill_segment(t, offsets.x, offsets.y));
+    c = mix(c, premultiplied(c2), fill_segment(t, offsets.y, offsets.z));
+    c = mix(c, premultiplied(c3), fill_segment(t, offsets.z, offsets.w));
+    if c.a <= 0.0 {
+        return vec4<f32>(0.0);
+    }
+    return vec4<f32>(c.rgb / c.a, c.a);
+}
@@ -12,9 +12,9 @@ use crate::time::Time;
 use crate::virtual_keyboard::VirtualKeyboardAdapter;
 use crate::willow::Willow;
 use crate::{
-    AndroidConnection, Animate, Area, Attachment, Color, Disable, Elevation, Enable, Grid,
-    Icon, Image, Interaction, Line, Location, Named, Opacity, Panel, Physical, Polygon, Resource,
-    SystemSet, Text, TextInput, Visibility,
+    AndroidConnection, Animate, Area, Attachment, Color, Disable, Elevation, Enable, Fill, Grid,
+    Icon, Image, Interaction, Line, Location, Named, Opacity, Panel, Path, Physical, Polygon,
+    Resource, Shader, Shadow, SystemSet, Text, TextInput, Transform, Visibility,
 };
 use crate::{Canopy, Sprig};
 use bevy_ecs::component::Component;
@@ -100,6 +100,9 @@ impl Foliage {
     /// Scroll direction multiplier where the *viewport* follows the gesture -- push up,
     /// you move down the page. The wheel convention.
     pub const VIEW_SCROLLING: f32 = 1.0;
+    /// How far Ctrl with the wheel zooms a [`Zoom`](crate::Zoom) view, per logical pixel the
+    /// wheel would have scrolled -- one notch is a tenth.
+    pub const ZOOM_SENSITIVITY: f32 = 0.0025;
     /// Every other platform's entry point owns nothing external -- this is the one place
     /// there's a real handle (the `AndroidApp` Android hands you at process start) that has
     /// to exist *before* a `Foliage` is meaningful, so unlike every other platform there's no
@@ -174,6 +177,8 @@ impl Foliage {
         Panel::attach(&mut foliage);
         Line::attach(&mut foliage);
         Polygon::attach(&mut foliage);
+        Path::attach(&mut foliage);
+        Shader::attach(&mut foliage);
         Grid::attach(&mut foliage);
         Interaction::attach(&mut foliage);
         Icon::attach(&mut foliage);
@@ -183,8 +188,11 @@ impl Foliage {
         Time::attach(&mut foliage);
         Remove::attach(&mut foliage);
         Opacity::attach(&mut foliage);
+        Transform::attach(&mut foliage);
         Elevation::attach(&mut foliage);
         Color::attach(&mut foliage);
+        Fill::attach(&mut foliage);
+        Shadow::attach(&mut foliage);
         Image::attach(&mut foliage);
         Visibility::attach(&mut foliage);
         Location::attach(&mut foliage);
@@ -192,6 +200,8 @@ impl Foliage {
         TextInput::attach(&mut foliage);
         VirtualKeyboardAdapter::attach(&mut foliage);
         crate::Clipboard::attach(&mut foliage);
+        crate::file_drop::FileDropAdapter::attach(&mut foliage);
+        crate::shortcut::Shortcuts::attach(&mut foliage);
         foliage
     }
     /// A [`Tree`](crate::Tree) over this instance's world. Internal: an app builds its tree
@@ -367,6 +377,13 @@ impl Foliage {
     pub fn icon(&mut self, memory: crate::IconMemory) {
         self.world.spawn(memory);
     }
+    /// Registers an app-wide shortcut before the loop starts -- see
+    /// [`Grows::shortcut`](crate::Grows::shortcut), which does the same at runtime.
+    pub fn shortcut(&mut self, keys: impl Into<crate::Chord>, id: impl Into<String>) {
+        self.world
+            .resource_mut::<crate::shortcut::Shortcuts>()
+            .bind(keys.into(), id.into(), None);
+    }
     /// Sets a startup resource an app is allowed to tune: scroll momentum, the text input's
     /// key bindings, and anything else the engine reads once and never writes.
     pub fn tune<R: crate::Tuning>(&mut self, value: R) {
@@ -392,6 +409,23 @@ impl Foliage {
             .expect("fonts")
             .add(font)
     }
+    /// Registers a fragment for [`Shader`] entities and hands back the
+    /// [`ShaderId`](crate::ShaderId) naming it. `fragment` is WGSL defining
+    /// `fn shade(shading: Shading) -> vec4<f32>` -- see [`Shader`] for what it is given.
+    ///
+    /// **Panics if `fragment` does not validate** against what foliage puts around it, with
+    /// the WGSL error. Checked here rather than left to the GPU, so the mistake is reported
+    /// at startup against the app's own source instead of as a failed pipeline mid-frame.
+    ///
+    /// Startup-only, like [`font`](Self::font).
+    pub fn shader(&mut self, fragment: &str) -> crate::ShaderId {
+        if let Err(error) = Shader::check(fragment) {
+            panic!("shader fragment does not validate:\n{error}");
+        }
+        let mut shaders = self.world.resource_mut::<crate::shader::Shaders>();
+        shaders.fragments.push(fragment.to_string());
+        crate::ShaderId(shaders.fragments.len() as u32 - 1)
+    }
     /// Declares where this app's assets are served from -- the path segment between the
     /// page origin and an asset's own relative path. Set it once at startup and
     /// [`bundled_asset!`](crate::bundled_asset) resolves every wasm URL through it, so no
@@ -252,10 +252,12 @@ impl Ginkgo {
             resource: self.viewport().uniform.buffer.as_entire_binding(),
         }
     }
+    /// The surface to draw to, cleared to `clear_color` -- or, given none, kept as an
+    /// earlier pass of the frame left it.
     pub(crate) fn color_attachment<'a>(
         &'a self,
         surface_view: &'a TextureView,
-        clear_color: Color,
+        clear_color: Option<Color>,
     ) -> [Option<RenderPassColorAttachment<'a>>; 1] {
         let (view, resolve_target) = match self.configuration().msaa.view.as_ref() {
             None => (surface_view, None),
@@ -266,20 +268,32 @@ impl Ginkgo {
             depth_slice: None,
             resolve_target,
             ops: Operations {
-                load: LoadOp::Clear(clear_color.into()),
+                load: clear_color.map_or(LoadOp::Load, |c| LoadOp::Clear(c.into())),
                 store: self.configuration().msaa.color_attachment_store_op(),
             },
         })]
     }
-    pub(crate) fn depth_stencil_attachment(&self) -> Option<RenderPassDepthStencilAttachment<'_>> {
+    /// Depth for the frame, `clear`ed at its start and kept across any pass it resumes in.
+    pub(crate) fn depth_stencil_attachment(
+        &self,
+        clear: bool,
+    ) -> Option<RenderPassDepthStencilAttachment<'_>> {
         Some(RenderPassDepthStencilAttachment {
             view: &self.configuration().depth.as_ref().unwrap().view,
             depth_ops: Some(Operations {
-                load: LoadOp::Clear(1.0),
+                load: if clear {
+                    LoadOp::Clear(1.0)
+                } else {
+                    LoadOp::Load
+                },
                 store: StoreOp::Store,
             }),
             stencil_ops: Some(Operations {
-                load: LoadOp::Clear(0u32),
+                load: if clear {
+                    LoadOp::Clear(0u32)
+                } else {
+                    LoadOp::Load
+                },
                 store: StoreOp::Store,
             }),
         })
@@ -419,8 +433,17 @@ impl Ginkgo {
         let area = willow.actual_area().max(Area::physical((1, 1)));
         let msaa = Msaa::new(self.context(), 1, area);
         let depth = Depth::new(self.context(), &msaa, area);
+        // Copied from too where the platform allows, for a `BackdropBlur` to read back.
+        let readable = self
+            .context()
+            .surface
+            .as_ref()
+            .unwrap()
+            .get_capabilities(&self.context().adapter)
+            .usages
+            & TextureUsages::COPY_SRC;
         let config = SurfaceConfiguration {
-            usage: TextureUsages::RENDER_ATTACHMENT,
+            usage: TextureUsages::RENDER_ATTACHMENT | readable,
             format: self.context().surface_format,
             width: area.width() as u32,
             height: area.height() as u32,
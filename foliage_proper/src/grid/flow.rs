@@ -0,0 +1,481 @@
+use crate::grid::{Gap, Layout, Short};
+use crate::node::GrowOrder;
+use crate::{
+    Children, Component, CoordinateUnit, Grid, LayoutSection, Location, Logical, Parent, Resolve,
+    ResolvedVisibility, Resource, Section, Tree, Trigger,
+};
+use bevy_ecs::entity::Entity;
+use bevy_ecs::query::{Changed, Or, With};
+use bevy_ecs::system::{Query, Res, ResMut};
+use std::collections::{HashMap, HashSet};
+
+/// Lays a parent's children out one after another by their own sizes, rather than each
+/// against a fixed [`Grid`] -- a row of chips, a column of list items, a run of tags that
+/// wraps at the parent's edge.
+///
+/// A child's [`Location`] still says how big it is; the flow says where it goes. Its width
+/// and height resolve exactly as they would anywhere else -- px, pct, letters,
+/// [`text_content()`](crate::text_content) -- and its left and top are ignored, so write them
+/// as anything (`0.px()` reads best). Children are taken in [`FlowItem::order`], then in the
+/// order they were grown.
+///
+/// ```ignore
+/// Panel::new().flow(Flow::row().gap(8).wrap())
+/// ```
+///
+/// Placed in the same pass as the measure, rather than by a solver: a child is resolved
+/// first, its box landing has the flow place its children again, and a child whose place
+/// changed is re-resolved into it before anything is drawn. Anything that changes a child's
+/// size -- its text, its font, the parent's width it wraps against -- settles in the pass it
+/// lands in.
+///
+/// Requires [`Grid`], which every child's `Location` resolves against regardless: a flow
+/// places children, it does not stand in for the coordinate system their sizes are stated in.
+#[derive(Component, Copy, Clone, Debug, PartialEq)]
+#[require(Grid)]
+pub struct Flow {
+    pub direction: FlowDirection,
+    /// Space between neighbours along a line.
+    pub gap: Gap,
+    /// Space between one wrapped line and the next.
+    pub line_gap: Gap,
+    /// Whether a child that would run past the parent's edge starts a new line. Off, a line
+    /// that does not fit shrinks its children instead -- see [`FlowItem::shrink`].
+    pub wrap: bool,
+    /// How a line uses whatever room its children leave along it.
+    pub justify: FlowJustify,
+    /// Where a child sits across its line, when it is not the line's tallest (or widest).
+    pub align: FlowAlign,
+}
+impl Flow {
+    /// Children left to right.
+    pub fn row() -> Self {
+        Self {
+            direction: FlowDirection::Row,
+            gap: Gap::default(),
+            line_gap: Gap::default(),
+            wrap: false,
+            justify: FlowJustify::default(),
+            align: FlowAlign::default(),
+        }
+    }
+    /// Children top to bottom.
+    pub fn column() -> Self {
+        Self {
+            direction: FlowDirection::Column,
+            ..Self::row()
+        }
+    }
+    /// Space between neighbours, and between wrapped lines unless
+    /// [`line_gap`](Self::line_gap) says otherwise.
+    pub fn gap<G: Into<Gap>>(mut self, g: G) -> Self {
+        self.gap = g.into();
+        self.line_gap = self.gap;
+        self
+    }
+    /// Space between wrapped lines alone.
+    pub fn line_gap<G: Into<Gap>>(mut self, g: G) -> Self {
+        self.line_gap = g.into();
+        self
+    }
+    /// Starts a new line at the parent's edge rather than shrinking.
+    pub fn wrap(mut self) -> Self {
+        self.wrap = true;
+        self
+    }
+    pub fn justify(mut self, justify: FlowJustify) -> Self {
+        self.justify = justify;
+        self
+    }
+    pub fn align(mut self, align: FlowAlign) -> Self {
+        self.align = align;
+        self
+    }
+}
+/// Which way a [`Flow`] runs. Wrapped lines stack on the other axis.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FlowDirection {
+    #[default]
+    Row,
+    Column,
+}
+/// Where a [`Flow`]'s line puts the room its children leave along it. None of these does
+/// anything on a line a growing child has already filled.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FlowJustify {
+    /// Children against the leading edge, room after them.
+    #[default]
+    Near,
+    Center,
+    /// Children against the trailing edge.
+    Far,
+    /// The room shared out between neighbours; the first and last child on the edges. A lone
+    /// child sits at the leading edge.
+    SpaceBetween,
+}
+/// Where a child sits across its [`Flow`] line.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FlowAlign {
+    #[default]
+    Near,
+    Center,
+    Far,
+    /// Grown to the line's full extent. Not applied to an axis the child sizes by
+    /// [`text_content()`](crate::text_content) -- that extent is its glyphs', and the measure
+    /// would write it straight back.
+    Stretch,
+}
+/// How a child of a [`Flow`] gives and takes room along its line.
+///
+/// Both weights are shares, CSS's `flex-grow` and `flex-shrink`: leftover room goes to each
+/// growing child in proportion to `grow`, and a line that overruns takes the excess from each
+/// child in proportion to `shrink` times its own size, so a large child gives up more than a
+/// small one. The flow's own direction is the only axis either applies to, and, as with
+/// [`FlowAlign::Stretch`], never one sized by `text_content()`.
+#[derive(Component, Copy, Clone, Debug, PartialEq)]
+pub struct FlowItem {
+    /// Share of a line's leftover room. `0` keeps the child at its own size.
+    pub grow: f32,
+    /// Share of a line's overrun. `0` never shrinks.
+    pub shrink: f32,
+    /// Place in the flow, ahead of grow order -- lower first. Worth setting on anything
+    /// inserted into a flow after the fact: grow order is only which entity is older, and an
+    /// entity id freed by a withered child is handed to the next one grown.
+    pub order: i32,
+}
+impl Default for FlowItem {
+    fn default() -> Self {
+        Self {
+            grow: 0.0,
+            shrink: 1.0,
+            order: 0,
+        }
+    }
+}
+impl FlowItem {
+    /// A child that takes `grow` shares of the room its line leaves.
+    pub fn grow(grow: f32) -> Self {
+        Self {
+            grow,
+            ..Default::default()
+        }
+    }
+    pub fn shrink(mut self, shrink: f32) -> Self {
+        self.shrink = shrink;
+        self
+    }
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = order;
+        self
+    }
+}
+
+/// A flow child's own size and the place its flow gave it, kept on the child.
+///
+/// `basis` is written by `Location::update` before the slot is applied -- the size the
+/// child's `Location` asks for -- so a slot that grew or stretched the child is never read
+/// back as what the child wanted. `within` is the flow that placed it: a child re-stemmed
+/// elsewhere keeps the component and must stop taking slots from a flow it has left.
+#[derive(Component, Copy, Clone, Debug)]
+pub(crate) struct Flowed {
+    pub(crate) within: Entity,
+    /// Offset from the flow's own box, and the size to take there.
+    pub(crate) slot: Section<Logical>,
+    pub(crate) basis: Option<crate::Area<Logical>>,
+}
+
+struct Entry {
+    entity: Entity,
+    /// `FlowItem::order`, then grow order, then the id for anything the tree did not grow.
+    order: (i32, u64, Entity),
+    main: CoordinateUnit,
+    cross: CoordinateUnit,
+    grow: f32,
+    shrink: f32,
+    fixed_main: bool,
+    fixed_cross: bool,
+}
+
+/// A child of this flow has just been placed: place its children again. Sent from
+/// [`LayoutSection`]'s insert, only to a parent with a [`Flow`].
+#[foliage_macros::targeted_event]
+#[derive(Copy)]
+pub(crate) struct Reflow {}
+
+/// How many times each flow has placed its children again this frame.
+///
+/// Placing re-resolves the children it moved, whose boxes landing ask again. A settled flow
+/// compares equal and ends that at once; one whose children resize with their slot -- text
+/// wrapping to a narrower one, say -- goes around until they agree. Past
+/// [`Reflowed::LIMIT`] a flow leaves the rest to the next frame's [`arrange`].
+#[derive(Resource, Default)]
+pub(crate) struct Reflowed(HashMap<Entity, u32>);
+impl Reflowed {
+    const LIMIT: u32 = 8;
+}
+
+#[allow(clippy::type_complexity)]
+type Items<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static Location,
+        &'static LayoutSection,
+        Option<&'static FlowItem>,
+        Option<&'static mut Flowed>,
+        Option<&'static ResolvedVisibility>,
+    ),
+>;
+
+impl Reflow {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn obs(
+        trigger: Trigger<Self>,
+        flows: Query<(Entity, &Flow, &Children, &LayoutSection)>,
+        mut items: Items,
+        grow_orders: Query<&GrowOrder>,
+        layout: Res<Layout>,
+        short: Res<Short>,
+        mut reflowed: ResMut<Reflowed>,
+        mut tree: Tree,
+    ) {
+        let Ok((this, flow, children, context)) = flows.get(trigger.event_target()) else {
+            return;
+        };
+        let count = reflowed.0.entry(this).or_default();
+        if *count >= Reflowed::LIMIT {
+            return;
+        }
+        if place(
+            (this, flow, children, context),
+            &mut items,
+            &grow_orders,
+            *layout,
+            *short,
+            &mut tree,
+        ) {
+            *count += 1;
+        }
+    }
+}
+
+/// Places the children of every [`Flow`] something changed under since the last frame.
+///
+/// A child's box landing places its flow in the same pass, through [`Reflow`]; this is for
+/// what lands no box. A child hidden, withered or given a new [`FlowItem`] changes the flow
+/// without resolving anything, and a flow held back by [`Reflowed::LIMIT`] picks up here --
+/// its children's boxes changed, which is what this filters on. A flow nothing changed under
+/// is not looked at.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub(crate) fn arrange(
+    flows: Query<(Entity, &Flow, &Children, &LayoutSection)>,
+    reshaped: Query<
+        Entity,
+        (
+            With<Flow>,
+            Or<(Changed<Flow>, Changed<Children>, Changed<LayoutSection>)>,
+        ),
+    >,
+    changed: Query<
+        &Parent,
+        Or<(
+            Changed<LayoutSection>,
+            Changed<FlowItem>,
+            Changed<ResolvedVisibility>,
+        )>,
+    >,
+    mut items: Items,
+    grow_orders: Query<&GrowOrder>,
+    layout: Res<Layout>,
+    short: Res<Short>,
+    mut reflowed: ResMut<Reflowed>,
+    mut tree: Tree,
+) {
+    reflowed.0.clear();
+    let touched = changed
+        .iter()
+        .filter_map(|p| p.id)
+        .chain(reshaped.iter())
+        .collect::<HashSet<_>>();
+    for flow in touched {
+        let Ok(flow) = flows.get(flow) else {
+            continue;
+        };
+        place(flow, &mut items, &grow_orders, *layout, *short, &mut tree);
+    }
+}
+
+/// Places one flow's children from the sizes they last resolved to, re-resolving each whose
+/// place changed. Whether any did.
+fn place(
+    (this, flow, children, context): (Entity, &Flow, &Children, &LayoutSection),
+    items: &mut Items,
+    grow_orders: &Query<&GrowOrder>,
+    layout: Layout,
+    short: Short,
+    tree: &mut Tree,
+) -> bool {
+    const EPSILON: CoordinateUnit = 0.01;
+    let mut moved = false;
+    let row = flow.direction == FlowDirection::Row;
+    let extent = if row {
+        context.0.width()
+    } else {
+        context.0.height()
+    };
+    let mut entries = Vec::new();
+    for child in children.ids.iter().copied() {
+        let Ok((location, placed, item, flowed, visibility)) = items.get(child) else {
+            continue;
+        };
+        if !location.boxed(layout, short) || visibility.is_some_and(|v| !v.visible()) {
+            continue;
+        }
+        // What the child asked for; the placed box stands in only until a resolve has
+        // recorded that, which it does from the first one after the child joins.
+        let mut size = flowed
+            .filter(|f| f.within == this)
+            .and_then(|f| f.basis)
+            .unwrap_or(placed.0.area);
+        // A measured axis is only ever known from the box the measure wrote.
+        let (content_w, content_h) = location.content_axes(layout, short);
+        if content_w {
+            size.set_width(placed.0.width());
+        }
+        if content_h {
+            size.set_height(placed.0.height());
+        }
+        let item = item.copied().unwrap_or_default();
+        let (main, cross, fixed_main, fixed_cross) = if row {
+            (size.width(), size.height(), content_w, content_h)
+        } else {
+            (size.height(), size.width(), content_h, content_w)
+        };
+        entries.push(Entry {
+            entity: child,
+            order: (
+                item.order,
+                grow_orders.get(child).map(|o| o.0).unwrap_or(u64::MAX),
+                child,
+            ),
+            main,
+            cross,
+            grow: item.grow.max(0.0),
+            shrink: item.shrink.max(0.0),
+            fixed_main,
+            fixed_cross,
+        });
+    }
+    entries.sort_by_key(|e| e.order);
+    // Break into lines first, each a run of `entries`.
+    let mut lines = Vec::new();
+    let mut start = 0;
+    let mut used = 0.0;
+    for (i, entry) in entries.iter().enumerate() {
+        let needed = if i == start {
+            entry.main
+        } else {
+            used + flow.gap.amount + entry.main
+        };
+        if flow.wrap && i > start && needed > extent + EPSILON {
+            lines.push(start..i);
+            start = i;
+            used = entry.main;
+        } else {
+            used = needed;
+        }
+    }
+    if start < entries.len() {
+        lines.push(start..entries.len());
+    }
+    let mut line_start = 0.0;
+    for line in lines {
+        let line = &mut entries[line];
+        let count = line.len() as CoordinateUnit;
+        let total = |line: &[Entry]| {
+            line.iter().map(|e| e.main).sum::<CoordinateUnit>() + flow.gap.amount * (count - 1.0)
+        };
+        let mut free = extent - total(line);
+        if free > 0.0 {
+            let shares = line
+                .iter()
+                .filter(|e| !e.fixed_main)
+                .map(|e| e.grow)
+                .sum::<f32>();
+            if shares > 0.0 {
+                for e in line.iter_mut().filter(|e| !e.fixed_main) {
+                    e.main += free * e.grow / shares;
+                }
+                free = 0.0;
+            }
+        } else if free < 0.0 {
+            let weighted = line
+                .iter()
+                .filter(|e| !e.fixed_main)
+                .map(|e| e.shrink * e.main)
+                .sum::<f32>();
+            if weighted > 0.0 {
+                for e in line.iter_mut().filter(|e| !e.fixed_main) {
+                    e.main = (e.main + free * e.shrink * e.main / weighted).max(0.0);
+                }
+            }
+            free = (extent - total(line)).max(0.0);
+        }
+        let (mut along, spacing) = match flow.justify {
+            FlowJustify::Near => (0.0, 0.0),
+            FlowJustify::Center => (free / 2.0, 0.0),
+            FlowJustify::Far => (free, 0.0),
+            FlowJustify::SpaceBetween if count > 1.0 => (0.0, free / (count - 1.0)),
+            FlowJustify::SpaceBetween => (0.0, 0.0),
+        };
+        let thickness = line.iter().map(|e| e.cross).fold(0.0, f32::max);
+        for e in line.iter_mut() {
+            let mut cross = e.cross;
+            let across = match flow.align {
+                FlowAlign::Near => 0.0,
+                FlowAlign::Center => (thickness - cross) / 2.0,
+                FlowAlign::Far => thickness - cross,
+                FlowAlign::Stretch => {
+                    if !e.fixed_cross {
+                        cross = thickness;
+                    }
+                    0.0
+                }
+            };
+            let slot = if row {
+                Section::new((along, line_start + across), (e.main, cross))
+            } else {
+                Section::new((line_start + across, along), (cross, e.main))
+            };
+            along += e.main + flow.gap.amount + spacing;
+            match items.get_mut(e.entity).ok().and_then(|(.., f, _)| f) {
+                Some(mut flowed) if flowed.within == this => {
+                    let current = flowed.slot;
+                    let same = (current.left() - slot.left()).abs() < EPSILON
+                        && (current.top() - slot.top()).abs() < EPSILON
+                        && (current.width() - slot.width()).abs() < EPSILON
+                        && (current.height() - slot.height()).abs() < EPSILON;
+                    if same {
+                        continue;
+                    }
+                    flowed.slot = slot;
+                }
+                _ => {
+                    let (_, placed, ..) = items.get(e.entity).unwrap();
+                    tree.write_to(
+                        e.entity,
+                        Flowed {
+                            within: this,
+                            slot,
+                            basis: Some(placed.0.area),
+                        },
+                    );
+                }
+            }
+            tree.send_to(Resolve::<Location>::new(), e.entity);
+            moved = true;
+        }
+        line_start += thickness + flow.line_gap.amount;
+    }
+    moved
+}
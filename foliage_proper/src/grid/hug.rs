@@ -0,0 +1,180 @@
+use crate::grid::{Layout, Short};
+use crate::{
+    Children, Component, CoordinateUnit, Coordinates, Grid, LayoutSection, Location, Resolve,
+    ResolvedVisibility, Resource, Tree, Trigger,
+};
+use bevy_ecs::entity::Entity;
+use bevy_ecs::system::{Query, Res, ResMut};
+use std::collections::HashMap;
+
+/// The extent of an entity's children, as last measured, for a
+/// [`children_content()`](crate::children_content) axis to resolve to. Width then height,
+/// each from the entity's own near edge: `far` to the furthest child's far edge, `near` to
+/// the nearest child's near edge. Padding is the `Location`'s, added at resolve.
+#[derive(Component, Copy, Clone, Default, Debug, PartialEq)]
+pub(crate) struct Hugged {
+    pub(crate) near: Coordinates,
+    pub(crate) far: Coordinates,
+}
+
+/// A child of this entity has just been placed: measure its children again. Sent from
+/// [`LayoutSection`]'s insert, only to a parent that sizes an axis by its children.
+#[foliage_macros::targeted_event]
+#[derive(Copy)]
+pub(crate) struct Remeasure {}
+
+/// How many times each container has re-measured this frame.
+///
+/// A child placed partway across the box it sizes -- centred in it, say -- moves each time the
+/// box does, and the two close on their answer by that fraction per re-measure rather than
+/// all at once. Past [`Remeasured::LIMIT`] a container leaves the rest to the next frame's
+/// [`hug`], so no pass spends itself on the last fraction of a pixel. A child that would never
+/// close at all is not measured in the first place -- see [`rehug`].
+#[derive(Resource, Default)]
+pub(crate) struct Remeasured(HashMap<Entity, u32>);
+impl Remeasured {
+    const LIMIT: u32 = 8;
+}
+
+#[allow(clippy::type_complexity)]
+type Containers<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Location,
+        &'static LayoutSection,
+        Option<&'static Children>,
+        Option<&'static Hugged>,
+        Option<&'static Grid>,
+    ),
+>;
+type Contents<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static Location,
+        &'static LayoutSection,
+        Option<&'static ResolvedVisibility>,
+    ),
+>;
+
+impl Remeasure {
+    pub(crate) fn obs(
+        trigger: Trigger<Self>,
+        containers: Containers,
+        children: Contents,
+        layout: Res<Layout>,
+        short: Res<Short>,
+        mut remeasured: ResMut<Remeasured>,
+        mut tree: Tree,
+    ) {
+        let Ok(container) = containers.get(trigger.event_target()) else {
+            return;
+        };
+        let count = remeasured.0.entry(container.0).or_default();
+        if *count >= Remeasured::LIMIT {
+            return;
+        }
+        if rehug(container, &children, *layout, *short, &mut tree) {
+            *count += 1;
+        }
+    }
+}
+
+/// Measures the children of every entity that sizes an axis by them, and re-resolves the ones
+/// whose children's extent moved.
+///
+/// A child's box landing re-measures its parent in the same pass, through [`Remeasure`]; this
+/// is for what lands no box. A child hidden or withered is gone from the extent without
+/// resolving anything, and a container held back by [`Remeasured::LIMIT`] picks up here.
+pub(crate) fn hug(
+    containers: Containers,
+    children: Contents,
+    layout: Res<Layout>,
+    short: Res<Short>,
+    mut remeasured: ResMut<Remeasured>,
+    mut tree: Tree,
+) {
+    remeasured.0.clear();
+    for container in containers.iter() {
+        rehug(container, &children, *layout, *short, &mut tree);
+    }
+}
+
+/// Measures one container's children, and re-resolves it if their extent moved. Whether it
+/// did.
+///
+/// In layout space on both sides, so a scroll inside the container changes nothing here: the
+/// children are wherever the layout put them, which is what the container is being sized to
+/// hold. A hidden child takes no room, and neither does one with no `Location` of its own --
+/// it sits at the origin by default, which says nothing about the content.
+///
+/// Nor does a child whose edges keep pace with the container on an axis it sizes, by
+/// [`Location::reach`]: its far edge is wherever the container's is, so measured it would
+/// hand back the container's own extent plus the padding, and grow it by that much on every
+/// measure there ever was.
+fn rehug(
+    (this, location, section, kids, hugged, grid): (
+        Entity,
+        &Location,
+        &LayoutSection,
+        Option<&Children>,
+        Option<&Hugged>,
+        Option<&Grid>,
+    ),
+    children: &Contents,
+    layout: Layout,
+    short: Short,
+    tree: &mut Tree,
+) -> bool {
+    const EPSILON: CoordinateUnit = 0.01;
+    let (width, height) = location.hug_axes(layout, short);
+    if !width && !height {
+        return false;
+    }
+    let grid = grid.copied().unwrap_or_default().config(layout);
+    let (mirror_width, mirror_height) = location.hug_mirrored(layout, short);
+    // How much the measure moves per pixel the container grows: at one or more, it never stops.
+    let chases = |(near, far): (f32, f32), mirrored: bool| {
+        far + if mirrored { near } else { 0.0 } >= 1.0 - EPSILON
+    };
+    let (mut left, mut top) = (CoordinateUnit::INFINITY, CoordinateUnit::INFINITY);
+    let (mut right, mut bottom) = (0.0 as CoordinateUnit, 0.0 as CoordinateUnit);
+    for kid in kids.iter().flat_map(|k| k.ids.iter()) {
+        let Ok((kid_location, kid_section, visibility)) = children.get(*kid) else {
+            continue;
+        };
+        if kid_location.config(layout, short).is_none() {
+            continue;
+        }
+        if visibility.is_some_and(|v| !v.visible()) {
+            continue;
+        }
+        let [horizontal, vertical] = kid_location.reach(layout, short, grid);
+        if !(width && chases(horizontal, mirror_width)) {
+            left = left.min(kid_section.0.left() - section.0.left());
+            right = right.max(kid_section.0.right() - section.0.left());
+        }
+        if !(height && chases(vertical, mirror_height)) {
+            top = top.min(kid_section.0.top() - section.0.top());
+            bottom = bottom.max(kid_section.0.bottom() - section.0.top());
+        }
+    }
+    let extent = Hugged {
+        near: Coordinates::new(left.clamp(0.0, right), top.clamp(0.0, bottom)),
+        far: Coordinates::new(right, bottom),
+    };
+    let close = |a: Coordinates, b: Coordinates| {
+        (a.a() - b.a()).abs() < EPSILON && (a.b() - b.b()).abs() < EPSILON
+    };
+    if let Some(last) = hugged
+        && close(last.near, extent.near)
+        && close(last.far, extent.far)
+    {
+        return false;
+    }
+    tree.write_to(this, extent);
+    tree.send_to(Resolve::<Location>::new(), this);
+    true
+}
@@ -4,7 +4,7 @@ use crate::{CoordinateUnit, Logical, Parent, Resolve, Resolved, Section, Tree};
 use bevy_ecs::entity::Entity;
 use bevy_ecs::query::With;
 use bevy_ecs::resource::Resource;
-use bevy_ecs::system::{Query, ResMut};
+use bevy_ecs::system::{Query, Res, ResMut};
 
 /// The current breakpoint, from the viewport's width -- a `Resource`, so there is one
 /// answer for the whole app at any moment.
@@ -14,7 +14,8 @@ use bevy_ecs::system::{Query, ResMut};
 /// [`FontSize`](crate::FontSize)'s per-breakpoint sizes. Each falls back to the nearest
 /// smaller breakpoint that was given, so only `xs` is ever required.
 ///
-/// Recomputed on resize; a change re-resolves the layout from the roots down.
+/// Recomputed on resize; a change re-resolves the layout from the roots down. Where each
+/// breakpoint starts is [`Breakpoints`]'s, tunable per app.
 #[derive(Resource, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub enum Layout {
     Xs,
@@ -24,29 +25,134 @@ pub enum Layout {
     Xl,
 }
 impl Layout {
-    /// Lower bound of `Sm`, in logical pixels. Below this is `Xs`.
+    /// Default lower bound of `Sm`, in logical pixels. Below this is `Xs`.
     pub const SM: CoordinateUnit = 420.0;
-    /// Lower bound of `Md`.
+    /// Default lower bound of `Md`.
     pub const MD: CoordinateUnit = 600.0;
-    /// Lower bound of `Lg`.
+    /// Default lower bound of `Lg`.
     pub const LG: CoordinateUnit = 840.0;
-    /// Lower bound of `Xl`.
+    /// Default lower bound of `Xl`.
     pub const XL: CoordinateUnit = 1200.0;
-    /// The breakpoint `section`'s width falls in.
+    /// The breakpoint `section`'s width falls in, at the default thresholds.
     pub fn new(section: Section<Logical>) -> Self {
-        if section.width() >= Self::XL {
+        Self::with_breakpoints(section, &Breakpoints::default())
+    }
+    /// The breakpoint `section`'s width falls in, at `breakpoints`' thresholds.
+    pub fn with_breakpoints(section: Section<Logical>, breakpoints: &Breakpoints) -> Self {
+        if section.width() >= breakpoints.xl {
             Self::Xl
-        } else if section.width() >= Self::LG {
+        } else if section.width() >= breakpoints.lg {
             Self::Lg
-        } else if section.width() >= Self::MD {
+        } else if section.width() >= breakpoints.md {
             Self::Md
-        } else if section.width() >= Self::SM {
+        } else if section.width() >= breakpoints.sm {
             Self::Sm
         } else {
             Self::Xs
         }
     }
 }
+/// Where each [`Layout`] breakpoint starts, and where the viewport turns [`Short`] and back.
+///
+/// Tuned like [`GestureThresholds`](crate::GestureThresholds) --
+/// `foliage.tune(Breakpoints { md: 768.0, lg: 1024.0, ..Default::default() })` before
+/// `photosynthesize` -- so an app whose design system draws its lines elsewhere can put them
+/// there. The defaults are [`Layout::SM`] and friends, [`Short::ENTER`] and [`Short::EXIT`].
+/// A set is checked when it is tuned, with [`validate`](Self::validate), so thresholds out of
+/// order stop the app at startup rather than at its first resize.
+///
+/// The thresholds move; the tiers do not. There are five, because every responsive type --
+/// `Location`, `Grid`, `FontSize`, `AspectRatio` -- stores one value per tier and falls back
+/// through them in order, and an app-named sixth would be a tier none of them has anywhere to
+/// keep. A design system with fewer lines sets the ones it does not use equal to a neighbour,
+/// which leaves that tier unreachable and its values falling through as usual.
+#[derive(Resource, Copy, Clone, Debug, PartialEq)]
+pub struct Breakpoints {
+    /// Lower bound of [`Layout::Sm`], in logical px.
+    pub sm: CoordinateUnit,
+    /// Lower bound of [`Layout::Md`].
+    pub md: CoordinateUnit,
+    /// Lower bound of [`Layout::Lg`].
+    pub lg: CoordinateUnit,
+    /// Lower bound of [`Layout::Xl`].
+    pub xl: CoordinateUnit,
+    /// Height below which the viewport becomes [`Short::Yes`].
+    pub short_enter: CoordinateUnit,
+    /// Height at or above which it returns to [`Short::No`]. Kept above `short_enter` -- the
+    /// gap is the deadband [`Short::EXIT`] explains.
+    pub short_exit: CoordinateUnit,
+}
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self {
+            sm: Layout::SM,
+            md: Layout::MD,
+            lg: Layout::LG,
+            xl: Layout::XL,
+            short_enter: Short::ENTER,
+            short_exit: Short::EXIT,
+        }
+    }
+}
+impl Breakpoints {
+    /// Whether these thresholds can be used: widths ascending, and `short_enter` at or below
+    /// `short_exit`. An error rather than a quiet sort -- thresholds out of order describe a
+    /// tier that starts above the next one, and whichever way that were repaired would be a
+    /// layout nobody asked for.
+    ///
+    /// [`Foliage::tune`](crate::Foliage::tune) asks this itself; call it first to handle a set
+    /// read from configuration rather than written in code.
+    pub fn validate(&self) -> Result<(), BreakpointsError> {
+        if !(self.sm <= self.md && self.md <= self.lg && self.lg <= self.xl) {
+            return Err(BreakpointsError::Unordered {
+                sm: self.sm,
+                md: self.md,
+                lg: self.lg,
+                xl: self.xl,
+            });
+        }
+        if self.short_enter > self.short_exit {
+            return Err(BreakpointsError::ShortInverted {
+                enter: self.short_enter,
+                exit: self.short_exit,
+            });
+        }
+        Ok(())
+    }
+}
+/// Why a [`Breakpoints`] cannot be used -- see [`Breakpoints::validate`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum BreakpointsError {
+    /// The width thresholds do not ascend.
+    Unordered {
+        sm: CoordinateUnit,
+        md: CoordinateUnit,
+        lg: CoordinateUnit,
+        xl: CoordinateUnit,
+    },
+    /// `short_enter` is above `short_exit`, so a height between them would be both short and
+    /// not.
+    ShortInverted {
+        enter: CoordinateUnit,
+        exit: CoordinateUnit,
+    },
+}
+impl std::fmt::Display for BreakpointsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unordered { sm, md, lg, xl } => write!(
+                f,
+                "`Breakpoints` must ascend: sm {sm} <= md {md} <= lg {lg} <= xl {xl}"
+            ),
+            Self::ShortInverted { enter, exit } => write!(
+                f,
+                "`Breakpoints::short_enter` ({enter}) is above `short_exit` ({exit}) -- a \
+                height between them would be both short and not"
+            ),
+        }
+    }
+}
+impl std::error::Error for BreakpointsError {}
 
 /// Whether the viewport is vertically cramped -- a phone held landscape, a short desktop
 /// window, a mobile browser whose address bar is eating the difference. A `Resource`, like
@@ -68,12 +174,12 @@ pub enum Short {
     No,
 }
 impl Short {
-    /// Below this height (logical px) the viewport becomes [`Short::Yes`]. Sized to catch
+    /// Default height (logical px) below which the viewport becomes [`Short::Yes`]. Sized to catch
     /// phones in landscape (~330-390 after browser chrome) and little else -- at 480 it also
     /// claimed ordinary short desktop windows, which have plenty of room and only want the
     /// normal layout.
     pub const ENTER: CoordinateUnit = 400.0;
-    /// At or above this height it returns to [`Short::No`]. The gap between the two is a
+    /// Default height at or above which it returns to [`Short::No`]. The gap between the two is a
     /// deliberate deadband, and it is asymmetric on purpose -- *sticky toward short*.
     ///
     /// A single threshold thrashes: on mobile web the address bar hides and shows as the
@@ -87,10 +193,15 @@ impl Short {
     /// The next state, given the current one -- the hysteresis. Between [`ENTER`](Self::ENTER)
     /// and [`EXIT`](Self::EXIT) the answer is whatever it already was.
     pub fn next(self, section: Section<Logical>) -> Self {
+        self.next_with(section, &Breakpoints::default())
+    }
+    /// [`next`](Self::next) between `breakpoints`'
+    /// [`short_enter`](Breakpoints::short_enter) and [`short_exit`](Breakpoints::short_exit).
+    pub fn next_with(self, section: Section<Logical>, breakpoints: &Breakpoints) -> Self {
         let height = section.height();
         match self {
-            Self::No if height < Self::ENTER => Self::Yes,
-            Self::Yes if height >= Self::EXIT => Self::No,
+            Self::No if height < breakpoints.short_enter => Self::Yes,
+            Self::Yes if height >= breakpoints.short_exit => Self::No,
             current => current,
         }
     }
@@ -104,14 +215,15 @@ pub(crate) fn viewport_changed(
     locations: Query<(Entity, &Parent), With<Location>>,
     mut layout: ResMut<Layout>,
     mut short: ResMut<Short>,
+    breakpoints: Res<Breakpoints>,
     mut tree: Tree,
 ) {
     if vh.window_forced_resize() {
-        let new = Layout::new(vh.section());
+        let new = Layout::with_breakpoints(vh.section(), &breakpoints);
         // `Short` rides the same `Resolved::<Layout>` notification rather than carrying its
         // own: both are inputs to the same resolve, and an author reacting to one wants to
         // re-read both anyway.
-        let new_short = short.next(vh.section());
+        let new_short = short.next_with(vh.section(), &breakpoints);
         if new != *layout || new_short != *short {
             tree.send(Resolved::<Layout>::new());
             *layout = new;
@@ -4,14 +4,18 @@ use crate::anim::interpolation::Interpolations;
 use crate::disable::AutoDisable;
 use crate::enable::AutoEnable;
 use crate::ginkgo::viewport::ViewportHandle;
+use crate::grid::flow::Flowed;
+use crate::grid::hug::Hugged;
+use crate::grid::template::Templated;
+use crate::grid::transition::{Transition, Transitioning};
 use crate::grid::{Gap, GridAxisDescriptor, GridConfiguration, Short};
 use crate::node::SpawnedAt;
 use crate::text::monospaced::FontContext;
 use crate::visibility::AutoVisibility;
 use crate::{
     Animate, AspectRatio, Attachment, Component, CoordinateUnit, Coordinates, Foliage, Grid,
-    Layout, LayoutSection, Line, Logical, Parent, Points, Position, Resolve, Resolved,
-    ResolvedVisibility, Section, Tree, View, Visibility,
+    Layout, LayoutSection, Line, Logical, Parent, Points, Resolve, Resolved, ResolvedVisibility,
+    Section, Tree, View, Visibility,
 };
 use bevy_ecs::change_detection::Res;
 use bevy_ecs::entity::Entity;
@@ -192,7 +196,7 @@ impl Location {
             && self.xl.is_none()
             && self.short.is_none()
     }
-    fn config(&self, layout: Layout, short: Short) -> Option<LocationDescriptor> {
+    pub(crate) fn config(&self, layout: Layout, short: Short) -> Option<LocationDescriptor> {
         // One extra link on the front of the same chain, not a second dimension: `short`
         // wins when it is both relevant and set, and otherwise this is the width lookup
         // untouched.
@@ -236,6 +240,20 @@ impl Location {
         .iter()
         .any(|d| matches!(d.value, LocationValue::Letters(_)))
     }
+    /// Whether this resolves to a box at all under `layout` -- set, and not built from
+    /// `as_x`/`as_y`. A [`Flow`](crate::Flow) places boxes; points have no size to flow by.
+    pub(crate) fn boxed(&self, layout: Layout, short: Short) -> bool {
+        self.config(layout, short).is_some_and(|config| {
+            ![
+                config.horizontal.a.designator,
+                config.horizontal.b.designator,
+                config.vertical.a.designator,
+                config.vertical.b.designator,
+            ]
+            .iter()
+            .any(|d| matches!(d, Designator::X | Designator::Y))
+        })
+    }
     /// Which axes take their extent from the entity's own measured text -- a
     /// [`text_content()`](text_content) value standing as this box's width or height.
     /// Returns `(width, height)`.
@@ -259,6 +277,116 @@ impl Location {
                 || sized_by_content(config.vertical.b, Designator::Height),
         )
     }
+    /// Which axes take their extent from the entity's own children -- a
+    /// [`children_content()`](children_content) value standing as this box's width or height.
+    /// Returns `(width, height)`.
+    pub(crate) fn hug_axes(&self, layout: Layout, short: Short) -> (bool, bool) {
+        let Some(config) = self.config(layout, short) else {
+            return (false, false);
+        };
+        let hugs = |d: ValueDescriptor, axis: Designator| {
+            matches!(d.value, LocationValue::ChildrenContent { .. }) && d.designator == axis
+        };
+        (
+            hugs(config.horizontal.a, Designator::Width)
+                || hugs(config.horizontal.b, Designator::Width),
+            hugs(config.vertical.a, Designator::Height)
+                || hugs(config.vertical.b, Designator::Height),
+        )
+    }
+    /// Which [`hug_axes`](Self::hug_axes) also repeat the gap the children leave at the near
+    /// side -- see [`mirrored`](LocationValue::mirrored). Returns `(width, height)`.
+    pub(crate) fn hug_mirrored(&self, layout: Layout, short: Short) -> (bool, bool) {
+        let Some(config) = self.config(layout, short) else {
+            return (false, false);
+        };
+        let mirrors = |d: ValueDescriptor| {
+            matches!(
+                d.value,
+                LocationValue::ChildrenContent { mirrored: true, .. }
+            )
+        };
+        (
+            mirrors(config.horizontal.a) || mirrors(config.horizontal.b),
+            mirrors(config.vertical.a) || mirrors(config.vertical.b),
+        )
+    }
+    /// How far each edge of this box moves for every pixel its parent grows, under the
+    /// parent's `grid`: `(near, far)` for the horizontal axis, then the vertical. A pct moves
+    /// by its fraction, a `col`/`row` by its share of a divided grid, and everything else --
+    /// px, letters, an anchor, a measure -- not at all. A box of points answers zero: it is
+    /// drawn to, not fitted into.
+    ///
+    /// Read off the same terms `calc` adds up, so a
+    /// [`children_content()`](children_content) parent can tell which children it may measure
+    /// before it measures them.
+    pub(crate) fn reach(
+        &self,
+        layout: Layout,
+        short: Short,
+        grid: GridConfiguration,
+    ) -> [(f32, f32); 2] {
+        let Some(config) = self.config(layout, short) else {
+            return [(0.0, 0.0); 2];
+        };
+        let rate = |d: ValueDescriptor| match d.value {
+            LocationValue::Percent(pct) => pct,
+            LocationValue::Column(line) | LocationValue::Row(line) => {
+                let (tracks, inclusive) = if let LocationValue::Column(_) = d.value {
+                    (
+                        grid.columns.value,
+                        matches!(d.designator, Designator::Right | Designator::Width),
+                    )
+                } else {
+                    (
+                        grid.rows.value,
+                        matches!(d.designator, Designator::Bottom | Designator::Height),
+                    )
+                };
+                let centred = matches!(
+                    d.designator,
+                    Designator::X | Designator::CenterX | Designator::Y | Designator::CenterY
+                );
+                match tracks {
+                    LocationValue::Column(n) | LocationValue::Row(n) if n > 0 => {
+                        (line as f32 - f32::from(!inclusive) + if centred { 0.5 } else { 0.0 })
+                            / n as f32
+                    }
+                    _ => 0.0,
+                }
+            }
+            _ => 0.0,
+        };
+        // Ordered by designator, the way `resolve` orders its own pair.
+        let edges =
+            |a: ValueDescriptor, b: ValueDescriptor| {
+                let (a, b) = if a.designator > b.designator {
+                    (b, a)
+                } else {
+                    (a, b)
+                };
+                let (ra, rb) = (rate(a), rate(b));
+                match (a.designator, b.designator) {
+                    (Designator::Left, Designator::Width)
+                    | (Designator::Top, Designator::Height) => (ra, ra + rb),
+                    (Designator::Left, Designator::Right)
+                    | (Designator::Top, Designator::Bottom) => (ra, rb),
+                    (Designator::Left, Designator::CenterX)
+                    | (Designator::Top, Designator::CenterY) => (ra, 2.0 * rb - ra),
+                    (Designator::Width, Designator::Right)
+                    | (Designator::Height, Designator::Bottom) => (rb - ra, rb),
+                    (Designator::Width, Designator::CenterX)
+                    | (Designator::Height, Designator::CenterY) => (rb - ra / 2.0, rb + ra / 2.0),
+                    (Designator::Right, Designator::CenterX)
+                    | (Designator::Bottom, Designator::CenterY) => (2.0 * rb - ra, ra),
+                    _ => (0.0, 0.0),
+                }
+            };
+        [
+            edges(config.horizontal.a, config.horizontal.b),
+            edges(config.vertical.a, config.vertical.b),
+        ]
+    }
     /// Which edge a content-sized axis is pinned by: `(right, bottom)`, where `false` means
     /// the near edge or a centre. An axis that is not content-sized answers `false`, which
     /// is the same answer and costs nothing.
@@ -330,6 +458,20 @@ impl Location {
             config.vertical.a,
             config.vertical.b,
         ] {
+            if let LocationValue::ChildrenContent { .. } = descriptor.value
+                && !matches!(
+                    descriptor.designator,
+                    Designator::Width | Designator::Height
+                )
+            {
+                panic!(
+                    "a `Location` uses `children_content()` as an edge, but it describes an \
+                    extent -- the children say how big this box is, not where it starts.{}\n  \
+                    fix: `children_content().as_width()` or `.as_height()`, and place the other \
+                    edge with px/pct/letters/anchor().\n  (entity {this:?})",
+                    at()
+                );
+            }
             if descriptor.value != LocationValue::TextContent {
                 continue;
             }
@@ -394,7 +536,15 @@ impl Location {
         trigger: Trigger<Resolve<Location>>,
         mut tree: Tree,
         layout: Res<Layout>,
-        locations: Query<(&Location, Option<&SpawnedAt>)>,
+        mut locations: Query<(
+            &Location,
+            Option<&SpawnedAt>,
+            Option<&mut Flowed>,
+            Option<&Hugged>,
+            Option<&mut Templated>,
+            Option<&crate::Sticky>,
+            Option<(&Transition, Option<&Transitioning>)>,
+        )>,
         texts: Query<(), With<crate::Text>>,
         sections: Query<&Section<Logical>>,
         layout_sections: Query<&LayoutSection>,
@@ -420,15 +570,21 @@ impl Location {
             .get(this)
             .ok()
             .and_then(|s| s.id)
-            .and_then(|p| grids.p0().get(p).ok().map(|(_, v)| v.accumulated_offset))
+            .and_then(|p| grids.p0().get(p).ok().map(|(_, v)| *v))
+            .unwrap_or_default();
+        let origin = layout_sections
+            .get(this)
+            .map(|l| l.0.position)
             .unwrap_or_default();
         if let Ok(mut view) = grids.p1().get_mut(this) {
             // `snapped_offset`, matching `propagate_offsets` -- the two paths write the same
             // entity's `Section`, so a resolve landing mid-scroll has to agree with the walk
             // on where the subtree sits, down to the pixel.
-            view.accumulated_offset = inherited + view.snapped_offset;
+            view.accumulate(&inherited, origin);
         }
-        if let Ok((location, spawned_at)) = locations.get(this) {
+        if let Ok((location, spawned_at, flowed, hugged, templated, sticky, transition)) =
+            locations.get_mut(this)
+        {
             if location.unset() {
                 // never configured -- not a positional element (a coordinator root, say);
                 // nothing to resolve, so leave AutoVisibility at its default true instead of
@@ -438,8 +594,8 @@ impl Location {
             let (_, auto_vis) = visibilities.get(this).unwrap();
             tracing::trace!(entity = ?this, visible = auto_vis.visible, "location: resolve start");
             let stem = stems.get(this).unwrap();
-            // `accumulated` is what this entity subtracts to go from layout space to screen
-            // space: every scroll offset between it and the root, which is exactly what its
+            // `accumulated` is what takes this entity from layout space to screen space: every
+            // scroll offset and zoom between it and the root, which is exactly what its
             // parent's `View` already carries for its children.
             let (grid, accumulated, context, stem_letters) =
                 if let Some(id) = stem.id {
@@ -466,16 +622,11 @@ impl Location {
                     // the stem's own font/size -- `.letters()` measures against the cell the
                     // parent lays out in
                     let stem_letter_dims = fonts.character_block(id, *layout).unwrap_or_default();
-                    (
-                        val.0.config(*layout),
-                        val.1.accumulated_offset,
-                        context,
-                        stem_letter_dims,
-                    )
+                    (val.0.config(*layout), val.1, context, stem_letter_dims)
                 } else {
                     (
                         Grid::default().config(*layout),
-                        Position::default(),
+                        View::default(),
                         viewport.section(),
                         Coordinates::default(),
                     )
@@ -495,13 +646,25 @@ impl Location {
                     if visibilities.get(id).unwrap().0.visible() {
                         // An anchor target is read in screen space, since it can sit
                         // anywhere in the tree -- under a different view, or under none.
-                        // Adding back what this entity subtracts states the target in *this*
-                        // entity's own layout space, which is the only space the resolve
+                        // Undoing the placement this entity goes through states the target in
+                        // *this* entity's own layout space, which is the only space the resolve
                         // below works in. Two entities in the same view cancel out exactly;
-                        // across a scroll boundary this is the real distance between them.
-                        let mut target = *sections.get(id).unwrap();
-                        target.position += accumulated;
-                        stack.replace(target);
+                        // across a scroll or zoom boundary this is the real distance between
+                        // them.
+                        let target = *sections.get(id).unwrap();
+                        let target = stems
+                            .get(id)
+                            .ok()
+                            .and_then(|s| s.id)
+                            .and_then(|p| {
+                                grids
+                                    .p0()
+                                    .get(p)
+                                    .ok()
+                                    .map(|(_, v)| v.child_on_screen(target))
+                            })
+                            .unwrap_or(target);
+                        stack.replace(accumulated.unplace(accumulated.child_from_screen(target)));
                     } else {
                         tracing::trace!(entity = ?this, target = ?id, "location: anchor target not visible, ignoring");
                     }
@@ -511,6 +674,11 @@ impl Location {
             };
             let current = layout_sections.get(this).unwrap().0;
             let letter_dims = fonts.character_block(this, *layout).unwrap_or_default();
+            // A template child whose area the template in force lacks resolves to nothing,
+            // and auto-hides with it, until a template that has the area brings it back.
+            let unplaced = templated
+                .as_ref()
+                .is_some_and(|t| stem.id == Some(t.within) && t.slot.is_none());
             if let Some(mut resolution) = resolve(
                 *layout,
                 // the same `Short` `FontContext` already carries, rather than a second copy
@@ -525,7 +693,33 @@ impl Location {
                 letter_dims,
                 aspect_ratio,
                 stem_letters,
-            ) {
+                hugged.copied().unwrap_or_default(),
+            )
+            .filter(|_| !unplaced)
+            {
+                // A flow or a template places this box when its parent is the one that holds
+                // it; each keeps its own record, since a child can move from one to the other.
+                let content = location.content_axes(*layout, *fonts.short);
+                if let Some(mut flowed) = flowed
+                    && stem.id == Some(flowed.within)
+                    && !resolution.from_points
+                {
+                    let slot = flowed.slot;
+                    slot_into(&mut resolution, &mut flowed.basis, slot, context, content);
+                }
+                if let Some(mut templated) = templated
+                    && stem.id == Some(templated.within)
+                    && let Some(slot) = templated.slot
+                    && !resolution.from_points
+                {
+                    slot_into(
+                        &mut resolution,
+                        &mut templated.basis,
+                        slot,
+                        context,
+                        content,
+                    );
+                }
                 if !auto_vis.visible {
                     tracing::trace!(entity = ?this, "location: resolved, re-enabling");
                     tree.write_to(this, AutoVisibility::new(true));
@@ -548,8 +742,56 @@ impl Location {
                     };
                     let anim_diff = diff * location.animation_percent;
                     resolution.section += anim_diff;
-                    let mut screen = resolution.section;
-                    screen.position -= accumulated;
+                    if let Some((transition, moving)) = transition {
+                        // `last` is the box as drawn, mid-move included, so a target that
+                        // changes again turns the move from wherever it has got to. A box never
+                        // placed before, or coming back from being auto-hidden, has nothing on
+                        // screen to move from and lands where it belongs.
+                        let target = resolution.section;
+                        let placed = auto_vis.visible && last.section.area != Default::default();
+                        let flight = match moving {
+                            Some(m) if m.to == target => Some(*m),
+                            _ if placed && moving.map_or(last.section, |m| m.to) != target => {
+                                let m = Transitioning::new(last.section, target);
+                                tree.write_to(this, m);
+                                Some(m)
+                            }
+                            Some(_) => {
+                                tree.strip::<Transitioning>(this);
+                                None
+                            }
+                            None => None,
+                        };
+                        if let Some(m) = flight {
+                            resolution.section = transition.at(&m);
+                        }
+                    }
+                    let mut screen = accumulated.place(resolution.section);
+                    // The box this view's own children are placed from has just moved, and
+                    // under a zoom that moves where they land.
+                    if let Ok(mut view) = grids.p1().get_mut(this) {
+                        view.accumulate(&inherited, resolution.section.position);
+                    }
+                    // Held by `Sticky` on the same pass as the scroll is taken off, so a resolve
+                    // mid-scroll lands where `propagate_offsets` would have put it -- and this
+                    // entity's children, which read `accumulated_offset` off it, ride along.
+                    if let Some(sticky) = sticky {
+                        let shift = crate::grid::view::stuck(
+                            this,
+                            accumulated.child_on_screen(screen),
+                            *sticky,
+                            |e| stems.get(e).ok().and_then(|s| s.id),
+                            |e| {
+                                let view = *grids.p0().get(e).ok()?.1;
+                                Some((view, view.on_screen(*sections.get(e).ok()?)))
+                            },
+                        );
+                        screen.position += shift / accumulated.accumulated_zoom;
+                        if let Ok(mut view) = grids.p1().get_mut(this) {
+                            view.hold(shift);
+                        }
+                        tree.write_to(this, crate::grid::view::Stuck(shift));
+                    }
                     tracing::trace!(
                         entity = ?this,
                         accumulated = ?accumulated,
@@ -593,11 +835,10 @@ impl Location {
                         bbox.set_height(h);
                     }
                     resolution.section = bbox;
-                    let mut screen = resolution.section;
-                    screen.position -= accumulated;
+                    let screen = accumulated.place(resolution.section);
                     let mut screen_points = resolution.points;
                     for pt in screen_points.data.iter_mut() {
-                        *pt -= accumulated;
+                        *pt = accumulated.place_point(*pt);
                     }
                     tree.write_to(
                         this,
@@ -618,8 +859,28 @@ impl Location {
     }
 }
 /// Resolves a `Location` entirely in layout space -- no scroll offset appears anywhere in
-/// here. What an ancestor's scroll does to the result is a translation applied afterwards,
-/// by the one caller that knows the accumulated total.
+/// here. What an ancestor's scroll or zoom does to the result is applied afterwards, by the
+/// one caller that knows the accumulated total.
+/// Moves a resolved box into the `slot` its flow or template gave it, offset from the
+/// `context` that holds it. The size asked for is recorded as `basis` first, so the placer
+/// never mistakes a grown or stretched box for a wanted one; a content-sized axis keeps the
+/// measure it resolved to.
+fn slot_into(
+    resolution: &mut Resolution,
+    basis: &mut Option<crate::Area<Logical>>,
+    slot: Section<Logical>,
+    context: Section<Logical>,
+    (content_w, content_h): (bool, bool),
+) {
+    *basis = Some(resolution.section.area);
+    resolution.section.position = context.position + slot.position;
+    if !content_w {
+        resolution.section.set_width(slot.width());
+    }
+    if !content_h {
+        resolution.section.set_height(slot.height());
+    }
+}
 fn resolve(
     layout: Layout,
     short: Short,
@@ -631,6 +892,7 @@ fn resolve(
     letter_dims: Coordinates,
     aspect_ratio: Option<AspectRatio>,
     stem_letters: Coordinates,
+    hugged: Hugged,
 ) -> Option<Resolution> {
     if let Some(config) = location.config(layout, short) {
         let mut resolution = Resolution::default();
@@ -642,6 +904,7 @@ fn resolve(
             current,
             letter_dims,
             stem_letters,
+            hugged,
         )?;
         let b = calc(
             config.horizontal.b,
@@ -651,6 +914,7 @@ fn resolve(
             current,
             letter_dims,
             stem_letters,
+            hugged,
         )?;
         let (pair, data) = if config.horizontal.a.designator > config.horizontal.b.designator {
             (
@@ -719,6 +983,7 @@ fn resolve(
             current,
             letter_dims,
             stem_letters,
+            hugged,
         )?;
         let d = calc(
             config.vertical.b,
@@ -728,6 +993,7 @@ fn resolve(
             current,
             letter_dims,
             stem_letters,
+            hugged,
         )?;
         let (pair, data) = if config.vertical.a.designator > config.vertical.b.designator {
             (
@@ -864,6 +1130,7 @@ fn calc(
     current: Section<Logical>,
     letter_dims: Coordinates,
     stem_letters: Coordinates,
+    hugged: Hugged,
 ) -> Option<CoordinateUnit> {
     let calculated = match desc.value {
         LocationValue::Percent(pct) => {
@@ -961,6 +1228,18 @@ fn calc(
             Designator::Width => Some(current.width()),
             _ => None,
         },
+        LocationValue::ChildrenContent { padding, mirrored } => {
+            let near = if mirrored {
+                hugged.near
+            } else {
+                Coordinates::default()
+            };
+            match desc.designator {
+                Designator::Height => Some(hugged.far.b() + near.b() + padding),
+                Designator::Width => Some(hugged.far.a() + near.a() + padding),
+                _ => None,
+            }
+        }
         LocationValue::Letters(l) => match desc.designator {
             Designator::Left
             | Designator::Right
@@ -1129,6 +1408,13 @@ pub enum LocationValue {
     Anchor(Designator, f32),
     /// The entity's own measured text extent -- see [`text_content()`](text_content).
     TextContent,
+    /// The extent of the entity's children, plus far-side padding -- see
+    /// [`children_content()`](children_content).
+    ChildrenContent {
+        padding: CoordinateUnit,
+        /// Whether the far side also repeats the gap the children leave at the near side.
+        mirrored: bool,
+    },
     /// Multiple of one character's advance at this entity's own font size.
     Letters(i32),
 }
@@ -1181,6 +1467,39 @@ impl LocationValue {
     pub fn as_y(self) -> ValueDescriptor {
         ValueDescriptor::new(Designator::Y, self)
     }
+    /// Adds `padding` logical pixels past the furthest child to a
+    /// [`children_content()`](children_content) extent. The far side only, on purpose: the
+    /// near side is wherever the children's own `Location`s start them, which a container
+    /// sized by them has no say over. A card whose children sit `16.px()` in pads by the same
+    /// 16 to match -- or says so once with [`mirrored`](Self::mirrored).
+    pub fn padded(self, padding: CoordinateUnit) -> LocationValue {
+        debug_assert!(
+            matches!(self, LocationValue::ChildrenContent { .. }),
+            "LocationValue::{self:?} has no padding -- only `children_content()` takes one"
+        );
+        match self {
+            LocationValue::ChildrenContent { mirrored, .. } => {
+                LocationValue::ChildrenContent { padding, mirrored }
+            }
+            other => other,
+        }
+    }
+    /// Pads a [`children_content()`](children_content) extent's far side by the gap its
+    /// children leave at the near side, so both sides match wherever the children are put.
+    /// Any [`padded`](Self::padded) amount goes on top.
+    pub fn mirrored(self) -> LocationValue {
+        debug_assert!(
+            matches!(self, LocationValue::ChildrenContent { .. }),
+            "LocationValue::{self:?} cannot mirror -- only `children_content()` can"
+        );
+        match self {
+            LocationValue::ChildrenContent { padding, .. } => LocationValue::ChildrenContent {
+                padding,
+                mirrored: true,
+            },
+            other => other,
+        }
+    }
     /// Turns this value into a [`Grid`] axis with `g` logical pixels between tracks.
     pub fn gap<G: Into<Gap>>(self, g: G) -> GridAxisDescriptor {
         debug_assert!(match self {
@@ -1290,12 +1609,30 @@ pub fn anchor() -> AnchorDescriptor {
 }
 /// Sizes this dimension to the entity's own text content, measured via font shaping
 /// (`fontdue`) rather than resolved against the parent -- `Text`/`TextInput`'s own
-/// mechanism specifically, not a general "size to fit children" primitive (no such thing
-/// exists in this framework: layout is single-pass and top-down, with no path for a
-/// child's computed size to flow back into a parent's).
+/// mechanism specifically. Sizing a box to the children inside it is
+/// [`children_content()`](children_content).
 pub fn text_content() -> LocationValue {
     LocationValue::TextContent
 }
+/// Sizes this dimension to the entity's own children: from its near edge to the furthest far
+/// edge among its visible, positioned children, plus any [`padded`](LocationValue::padded) or
+/// [`mirrored`](LocationValue::mirrored) amount -- a card as tall as whatever text it was
+/// given, at every breakpoint, with no height stated for any of them.
+///
+/// Measured as the children resolve, in the same pass: each child's box landing re-measures
+/// this one, and a changed extent re-resolves it before the frame draws. The children's own
+/// layout decides the answer, so a child that takes its size from the answer is not asked: one
+/// whose edges move as fast as this axis grows (`100.pct()`, the last `col`/`row`, a pct width
+/// that reaches the far edge) is placed by this box, like a background filling a card, and
+/// left out of the measure. Counted, it would push the far edge out by the padding on every
+/// measure, without end. One partway across -- centred at `50.pct()`, say -- is measured, and
+/// settles within a few re-measures.
+pub fn children_content() -> LocationValue {
+    LocationValue::ChildrenContent {
+        padding: 0.0,
+        mirrored: false,
+    }
+}
 #[derive(Copy, Clone)]
 pub(crate) struct LocationDescriptor {
     pub(crate) horizontal: ConfigurationDescriptor,
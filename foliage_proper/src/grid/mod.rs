@@ -1,37 +1,74 @@
 mod aspect_ratio;
+pub(crate) mod flow;
+pub(crate) mod hug;
 mod layout;
 pub(crate) mod location;
+pub(crate) mod reveal;
+pub(crate) mod scrollbar;
+pub(crate) mod template;
+pub(crate) mod transition;
 pub(crate) mod view;
+pub(crate) mod virtual_list;
+pub(crate) mod zoom;
 
 use crate::foliage::{DiffMarkers, Foliage, MainMarkers};
 pub(crate) use crate::grid::layout::viewport_changed;
 pub use crate::grid::location::{
     Adjust, AnchorDescriptor, ConfigurationDescriptor, Justify, ValueDescriptor, anchor,
-    text_content,
+    children_content, text_content,
 };
 pub use crate::grid::location::{GridExt, LocationValue};
-use crate::grid::view::{ScrolledViews, coast, extent_check, propagate_offsets};
+use crate::grid::view::{ScrolledViews, coast, extent_check, propagate_offsets, snap};
 use crate::{Attachment, Component, CoordinateUnit};
 pub use aspect_ratio::AspectRatio;
 use bevy_ecs::prelude::IntoScheduleConfigs;
-pub use layout::{Layout, Short};
+pub use flow::{Flow, FlowAlign, FlowDirection, FlowItem, FlowJustify};
+pub use layout::{Breakpoints, BreakpointsError, Layout, Short};
 pub use location::Anchor;
 pub use location::AnchorDeps;
 pub use location::Location;
+pub use reveal::Align;
+pub use scrollbar::ScrollbarStyle;
+pub use template::{Breadth, GridItem, GridPlace, GridTemplate, Template, Track};
 pub use view::{ScrollMomentum, ScrollProgress, ScrollTo, View};
+pub use virtual_list::VirtualList;
+pub use zoom::{Zoom, ZoomTo};
 
 impl Attachment for Grid {
     fn attach(foliage: &mut Foliage) {
         foliage.world.insert_resource(Layout::Xs);
         foliage.world.insert_resource(Short::No);
+        foliage.world.insert_resource(Breakpoints::default());
         foliage.world.insert_resource(ScrollMomentum::default());
         foliage.world.insert_resource(ScrolledViews::default());
+        foliage.world.insert_resource(hug::Remeasured::default());
+        foliage.define(hug::Remeasure::obs);
+        foliage.world.insert_resource(flow::Reflowed::default());
+        foliage.define(flow::Reflow::obs);
+        foliage.enable_queued_event::<zoom::ZoomInput>();
         foliage
             .main
             .add_systems(viewport_changed.in_set(MainMarkers::External));
-        foliage.main.add_systems(coast.in_set(MainMarkers::Process));
+        foliage
+            .main
+            .add_systems(transition::transition.in_set(MainMarkers::Animation));
+        foliage.main.add_systems(
+            (coast, snap, reveal::reveal)
+                .chain()
+                .in_set(MainMarkers::Process),
+        );
+        foliage.main.add_systems(
+            (
+                template::place,
+                flow::arrange,
+                hug::hug,
+                virtual_list::recycle,
+            )
+                .chain()
+                .in_set(MainMarkers::Process),
+        );
         foliage.diff.add_systems(
-            (extent_check, propagate_offsets)
+            (zoom::zoom, extent_check, propagate_offsets, scrollbar::sync)
                 .chain()
                 .in_set(DiffMarkers::Prepare),
         );
@@ -190,7 +227,7 @@ impl From<LocationValue> for GridAxisDescriptor {
 }
 /// Space between adjacent tracks, in logical pixels. Applies only between tracks, so an
 /// n-track axis has n-1 gaps and no outer margin.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Gap {
     pub amount: CoordinateUnit,
 }
@@ -0,0 +1,183 @@
+use crate::grid::view::{ScrollMomentum, ScrollTo};
+use crate::interaction::CurrentInteraction;
+use crate::{Component, CoordinateUnit, Ease, Easement, Logical, Parent, Section, Tree, View};
+use bevy_ecs::entity::Entity;
+use bevy_ecs::system::{Query, Res};
+
+/// Where a revealed element ends up within each view scrolled to show it -- see
+/// [`Grows::scroll_into_view`](crate::Grows::scroll_into_view).
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum Align {
+    /// Moves only if some of it is out of sight, and then only as far as it takes: its near
+    /// edge to the view's when it lies before, its far edge when it lies after. Something
+    /// larger than the view lines up at the start.
+    #[default]
+    Nearest,
+    /// Its start edge on the view's.
+    Start,
+    /// Its middle on the view's.
+    Center,
+    /// Its end edge on the view's.
+    End,
+}
+
+/// One-shot request, written on the element to be revealed and consumed by [`reveal`] the
+/// frame it lands -- the same "request, not raw state" shape as [`ScrollTo`].
+#[derive(Component, Copy, Clone, Debug)]
+pub(crate) struct ScrollIntoView {
+    pub(crate) align: Align,
+    pub(crate) animated: bool,
+}
+
+/// A view on its way to a revealed element, in px of offset per axis -- `None` on an axis it
+/// is not moving. Carried to the end as pixels rather than as a fraction of the range, which
+/// a range changing mid-flight would turn into a different place.
+#[derive(Component, Copy, Clone, Debug)]
+pub(crate) struct Revealing {
+    from: (CoordinateUnit, CoordinateUnit),
+    to: (Option<CoordinateUnit>, Option<CoordinateUnit>),
+    elapsed_ms: f32,
+}
+
+/// How far one axis of a view moves for `span` (already shifted by the views inside this one)
+/// to sit at `align` within `window`, given the view is at `at` of `range` -- its
+/// [`View::range`] on that axis.
+fn delta(
+    span: (CoordinateUnit, CoordinateUnit),
+    window: (CoordinateUnit, CoordinateUnit),
+    align: Align,
+    at: CoordinateUnit,
+    range: (CoordinateUnit, CoordinateUnit),
+) -> CoordinateUnit {
+    let (near, far) = span;
+    let (start, end) = window;
+    let wanted = match align {
+        Align::Start => near - start,
+        Align::End => far - end,
+        Align::Center => (near + far - start - end) / 2.0,
+        Align::Nearest => {
+            if near < start || far - near > end - start {
+                near - start
+            } else if far > end {
+                far - end
+            } else {
+                0.0
+            }
+        }
+    };
+    (at + wanted).clamp(range.0, range.1) - at
+}
+
+/// Answers each [`ScrollIntoView`], and moves every view already revealing a step further.
+///
+/// Walks up from the element through every view above it. Each one moves just enough to show
+/// the element as the views inside it have left it, clamped into its own range -- so a view
+/// that cannot scroll, or has run out of room, passes what it could not do to the next one
+/// out. Every move is a [`ScrollTo`], which is what a program moving a view already writes:
+/// it is not gated by [`ScrollAxes`](crate::ScrollAxes), and it stops a coast or snap under
+/// way. An animated reveal eases over [`ScrollMomentum::reveal_ms`] and stops at a press.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn reveal(
+    requests: Query<(Entity, &ScrollIntoView)>,
+    mut revealing: Query<(Entity, &mut Revealing)>,
+    views: Query<(&View, &Section<Logical>)>,
+    sections: Query<&Section<Logical>>,
+    stems: Query<&Parent>,
+    momentum: Res<ScrollMomentum>,
+    current: Res<CurrentInteraction>,
+    time: Res<crate::Time>,
+    mut tree: Tree,
+) {
+    let elapsed_ms = time.frame_diff().as_secs_f32() * 1000.0;
+    // Of the same range `ScrollTo` is applied across.
+    let fraction = |to: Option<CoordinateUnit>, (min, max): (CoordinateUnit, CoordinateUnit)| {
+        to.filter(|_| max > min).map(|to| (to - min) / (max - min))
+    };
+    for (entity, mut r) in revealing.iter_mut() {
+        if current.pressed {
+            tree.strip::<Revealing>(entity);
+            continue;
+        }
+        let Ok((view, section)) = views.get(entity) else {
+            continue;
+        };
+        r.elapsed_ms += elapsed_ms;
+        let done = r.elapsed_ms >= momentum.reveal_ms;
+        let t = if done {
+            1.0
+        } else {
+            Easement::new(Ease::DECELERATE).percent_changed(r.elapsed_ms / momentum.reveal_ms)
+        };
+        let [range_x, range_y] = view.range(*section);
+        let x = fraction(r.to.0.map(|to| r.from.0 + (to - r.from.0) * t), range_x);
+        let y = fraction(r.to.1.map(|to| r.from.1 + (to - r.from.1) * t), range_y);
+        tree.write_to(entity, ScrollTo { x, y });
+        if done {
+            tree.strip::<Revealing>(entity);
+        }
+    }
+    for (entity, request) in requests.iter() {
+        tree.strip::<ScrollIntoView>(entity);
+        let Ok(section) = sections.get(entity) else {
+            continue;
+        };
+        let mut at = stems.get(entity).ok().and_then(|p| p.id);
+        // Compared on screen, where the offsets it moves are measured.
+        let section = at
+            .and_then(|p| views.get(p).ok())
+            .map(|(view, _)| view.child_on_screen(*section))
+            .unwrap_or(*section);
+        // How far the views already walked have moved the element, per axis.
+        let mut shift = (0.0, 0.0);
+        while let Some(id) = at {
+            at = stems.get(id).ok().and_then(|p| p.id);
+            let Ok((view, window)) = views.get(id) else {
+                continue;
+            };
+            let [range_x, range_y] = view.range(*window);
+            let window = view.on_screen(*window);
+            let offset = view.offset();
+            let dx = delta(
+                (section.left() - shift.0, section.right() - shift.0),
+                (window.left(), window.right()),
+                request.align,
+                offset.left(),
+                range_x,
+            );
+            let dy = delta(
+                (section.top() - shift.1, section.bottom() - shift.1),
+                (window.top(), window.bottom()),
+                request.align,
+                offset.top(),
+                range_y,
+            );
+            shift = (shift.0 + dx, shift.1 + dy);
+            let to = (
+                (dx != 0.0).then_some(offset.left() + dx),
+                (dy != 0.0).then_some(offset.top() + dy),
+            );
+            if to.0.is_none() && to.1.is_none() {
+                continue;
+            }
+            if request.animated && momentum.reveal_ms > 0.0 {
+                tree.write_to(
+                    id,
+                    Revealing {
+                        from: (offset.left(), offset.top()),
+                        to,
+                        elapsed_ms: 0.0,
+                    },
+                );
+            } else {
+                tree.strip::<Revealing>(id);
+                tree.write_to(
+                    id,
+                    ScrollTo {
+                        x: fraction(to.0, range_x),
+                        y: fraction(to.1, range_y),
+                    },
+                );
+            }
+        }
+    }
+}
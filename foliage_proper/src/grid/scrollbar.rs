@@ -0,0 +1,451 @@
+use crate::grid::view::ScrollTo;
+use crate::interaction::CurrentInteraction;
+use crate::remove::Remove;
+use crate::{
+    AsTree, Author, Color, Component, CoordinateUnit, Dragged, Elevation, Engaged, GridExt,
+    InteractionMethod, LayoutSection, Leaf, Location, Logical, OnClick, Opacity, Panel, Parent,
+    Rounding, ScrollProgress, Section, Sprout, Tree, View, Visibility, anchor,
+};
+use bevy_ecs::change_detection::DetectChanges;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::lifecycle::HookContext;
+use bevy_ecs::system::{Query, Res};
+use bevy_ecs::world::{DeferredWorld, Ref, World};
+
+/// A track and thumb drawn over a view's edge, showing how much of its content is in sight
+/// and where -- given with [`Author::scrollbar`](crate::Sprout::scrollbar).
+///
+/// One bar per axis, each shown only while the view has room to scroll that way. The thumb
+/// drags, moving the view as far through its content as the thumb moves through the track;
+/// a click on the track either side of the thumb pages the view one viewport that way. Both
+/// arrive as a [`ScrollTo`], so a snap or coast under way gives way to them.
+///
+/// After touch input the bars fade out once the view has sat still for
+/// [`idle_ms`](Self::idle_ms), and back in the moment it moves. After a mouse or wheel they
+/// stay: a pointer has nothing else to tell it that a region scrolls, and a bar that is not
+/// there cannot be grabbed.
+///
+/// The bars are siblings of the view rather than children, anchored to its box, so they stay
+/// put while its content scrolls and take no part in its extent. A view with no parent -- the
+/// root -- has nowhere to hang them, and shows none. Being the parent's, they are withered
+/// by hand: along with the view, or on their own when the style is taken off it.
+#[derive(Component, Copy, Clone, Debug, PartialEq)]
+#[component(on_add = Self::on_add)]
+#[component(on_remove = Self::on_remove)]
+pub struct ScrollbarStyle {
+    pub track: Color,
+    pub thumb: Color,
+    /// Logical px across the bar.
+    pub thickness: i32,
+    /// Logical px between the bar and the view's edges.
+    pub inset: i32,
+    /// The shortest the thumb gets, however long the content, so it stays something to grab.
+    pub min_thumb: CoordinateUnit,
+    /// ms a view scrolled by touch sits still before its bars fade.
+    pub idle_ms: f32,
+}
+impl ScrollbarStyle {
+    /// A 6px bar, inset 2px, with a thumb no shorter than 24px that fades after 1.2s idle.
+    pub fn new(track: Color, thumb: Color) -> Self {
+        Self {
+            track,
+            thumb,
+            thickness: 6,
+            inset: 2,
+            min_thumb: 24.0,
+            idle_ms: 1200.0,
+        }
+    }
+    pub fn thickness(mut self, px: i32) -> Self {
+        self.thickness = px;
+        self
+    }
+    pub fn inset(mut self, px: i32) -> Self {
+        self.inset = px;
+        self
+    }
+    pub fn min_thumb(mut self, px: CoordinateUnit) -> Self {
+        self.min_thumb = px;
+        self
+    }
+    pub fn idle_ms(mut self, ms: f32) -> Self {
+        self.idle_ms = ms;
+        self
+    }
+    fn on_add(mut world: DeferredWorld, ctx: HookContext) {
+        world.tree().subscribe(ctx.entity, Scrollbars::wither);
+    }
+    fn on_remove(mut world: DeferredWorld, ctx: HookContext) {
+        let this = ctx.entity;
+        // A despawn reaches this hook too, and by then `Scrollbars::wither` has the bars in
+        // hand; only a view still standing once this lands has had its style taken off.
+        world.commands().queue(move |world: &mut World| {
+            let Some(bars) = world
+                .get_entity_mut(this)
+                .ok()
+                .and_then(|mut view| view.take::<Scrollbars>())
+            else {
+                return;
+            };
+            world.tree().remove(bars.tracks());
+        });
+    }
+}
+
+/// The bars a [`ScrollbarStyle`] grew, across then down, and how long the view has been idle.
+#[derive(Component)]
+pub(crate) struct Scrollbars {
+    bars: [Bar; 2],
+    idle_ms: f32,
+    shown: f32,
+}
+impl Scrollbars {
+    /// Each bar's track, which holds its thumb.
+    fn tracks(&self) -> [Entity; 2] {
+        self.bars.map(|b| b.track)
+    }
+    /// The view is withering: its bars hang from its parent, out of reach of the cascade.
+    fn wither(trigger: crate::Trigger<Remove>, bars: Query<&Scrollbars>, mut tree: Tree) {
+        if let Ok(bars) = bars.get(trigger.event_target()) {
+            tree.remove(bars.tracks());
+        }
+    }
+}
+#[derive(Copy, Clone)]
+struct Bar {
+    track: Entity,
+    thumb: Entity,
+    /// The thumb's offset along the track and its length, as last written.
+    placed: Option<(CoordinateUnit, CoordinateUnit)>,
+    visible: bool,
+}
+/// On a track and its thumb: the view they drive, along which axis, and -- on a thumb -- the
+/// view's progress when the thumb was grabbed, which a drag is measured from.
+#[derive(Component, Copy, Clone)]
+pub(crate) struct ScrollbarPart {
+    view: Entity,
+    across: bool,
+    grabbed: f32,
+}
+
+/// The bar's box: along the view's far edge on the other axis, inset on every side.
+fn track(style: &ScrollbarStyle, across: bool) -> Location {
+    let (inset, thickness) = (style.inset, style.thickness);
+    if across {
+        Location::new().xs(
+            anchor().left().as_left().adjust(inset).with(
+                anchor()
+                    .right()
+                    .as_right()
+                    .adjust(-(inset + thickness + inset)),
+            ),
+            anchor()
+                .bottom()
+                .as_bottom()
+                .adjust(-inset)
+                .with(thickness.px().as_height()),
+        )
+    } else {
+        Location::new().xs(
+            anchor()
+                .right()
+                .as_right()
+                .adjust(-inset)
+                .with(thickness.px().as_width()),
+            anchor().top().as_top().adjust(inset).with(
+                anchor()
+                    .bottom()
+                    .as_bottom()
+                    .adjust(-(inset + thickness + inset)),
+            ),
+        )
+    }
+}
+
+/// The thumb's box within its track: `at` px along it, `length` px long.
+fn thumb(at: CoordinateUnit, length: CoordinateUnit, across: bool) -> Location {
+    if across {
+        Location::new().xs(
+            at.px().as_left().with(length.px().as_width()),
+            0.pct().as_top().with(100.pct().as_bottom()),
+        )
+    } else {
+        Location::new().xs(
+            0.pct().as_left().with(100.pct().as_right()),
+            at.px().as_top().with(length.px().as_height()),
+        )
+    }
+}
+
+/// How far a view can scroll along one axis, and how much of it shows at once.
+fn range(
+    view: &View,
+    section: &Section<Logical>,
+    across: bool,
+) -> (CoordinateUnit, CoordinateUnit) {
+    let section = view.on_screen(*section);
+    if across {
+        (
+            (view.extent().width() - section.right()).max(0.0),
+            section.width(),
+        )
+    } else {
+        (
+            (view.extent().height() - section.bottom()).max(0.0),
+            section.height(),
+        )
+    }
+}
+
+/// Grows each [`ScrollbarStyle`] view's bars on first sight, then keeps every thumb sized
+/// and placed to the view's scroll, and the bars faded in or out.
+///
+/// Runs straight after `propagate_offsets`, on the progress `extent_check` has just
+/// published, so a thumb moves on the same frame as the content it stands for.
+#[allow(clippy::type_complexity)]
+pub(crate) fn sync(
+    mut views: Query<(
+        Entity,
+        &ScrollbarStyle,
+        Option<&mut Scrollbars>,
+        &View,
+        &Section<Logical>,
+        Ref<ScrollProgress>,
+        Option<&Parent>,
+        Option<&Elevation>,
+    )>,
+    layouts: Query<&LayoutSection>,
+    current: Res<CurrentInteraction>,
+    time: Res<crate::Time>,
+    mut tree: Tree,
+) {
+    const FADE_MS: f32 = 150.0;
+    const EPSILON: CoordinateUnit = 0.5;
+    let elapsed_ms = time.frame_diff().as_secs_f32() * 1000.0;
+    for (this, style, bars, view, section, progress, stem, elevation) in views.iter_mut() {
+        let Some(mut bars) = bars else {
+            let Some(parent) = stem.and_then(|s| s.id) else {
+                continue;
+            };
+            // One step in front of the view, as its sibling, which is in front of everything
+            // the view holds however deep it goes.
+            let in_front = elevation
+                .map(|e| Elevation {
+                    amount: e.amount - 1.0,
+                    ..*e
+                })
+                .unwrap_or(Elevation::up(1));
+            let grow = |tree: &mut Tree, across: bool| {
+                let track = tree.branch(
+                    parent,
+                    Panel::new()
+                        .color(style.track)
+                        .rounding(Rounding::Full)
+                        .anchored(Leaf(this))
+                        .at(track(style, across))
+                        .elevate(in_front)
+                        .grid(crate::Grid::default())
+                        .interactive()
+                        .holds_drag()
+                        .with(Visibility::new(false)),
+                );
+                let thumb = tree.branch(
+                    track,
+                    Panel::new()
+                        .color(style.thumb)
+                        .rounding(Rounding::Full)
+                        .at(self::thumb(0.0, 0.0, across))
+                        .elevate(Elevation::up(1))
+                        .interactive()
+                        .holds_drag(),
+                );
+                let part = ScrollbarPart {
+                    view: this,
+                    across,
+                    grabbed: 0.0,
+                };
+                tree.write_to(track, part);
+                tree.write_to(thumb, part);
+                tree.subscribe(track, page);
+                tree.subscribe(thumb, grab);
+                tree.subscribe(thumb, drag);
+                Bar {
+                    track,
+                    thumb,
+                    placed: None,
+                    visible: false,
+                }
+            };
+            let bars = [grow(&mut tree, true), grow(&mut tree, false)];
+            tree.write_to(
+                this,
+                Scrollbars {
+                    bars,
+                    idle_ms: 0.0,
+                    shown: 1.0,
+                },
+            );
+            continue;
+        };
+        let held = current.pressed
+            && current
+                .primary
+                .is_some_and(|p| bars.bars.iter().any(|b| b.thumb == p || b.track == p));
+        if progress.is_changed() || held {
+            bars.idle_ms = 0.0;
+        } else {
+            bars.idle_ms += elapsed_ms;
+        }
+        // Only once there has been a gesture to judge by: before the first, nothing says this is
+        // a touch screen, and a desktop should not open on bars that have already faded.
+        let touch = current.primary.is_some() && current.method == InteractionMethod::TouchScreen;
+        let target = if touch && bars.idle_ms > style.idle_ms {
+            0.0
+        } else {
+            1.0
+        };
+        let step = elapsed_ms / FADE_MS;
+        let shown = if bars.shown < target {
+            (bars.shown + step).min(target)
+        } else {
+            (bars.shown - step).max(target)
+        };
+        let faded = shown != bars.shown;
+        bars.shown = shown;
+        for (across, bar) in [true, false].into_iter().zip(bars.bars.iter_mut()) {
+            let (max, visible) = range(view, section, across);
+            let show = max > EPSILON;
+            if show != bar.visible {
+                bar.visible = show;
+                tree.write_to(bar.track, Visibility::new(show));
+            }
+            if faded {
+                tree.write_to(bar.track, Opacity::new(shown));
+            }
+            if !show {
+                continue;
+            }
+            let Ok(length) = layouts
+                .get(bar.track)
+                .map(|l| if across { l.0.width() } else { l.0.height() })
+            else {
+                continue;
+            };
+            let size = (length * visible / (visible + max))
+                .max(style.min_thumb)
+                .min(length);
+            let at = (length - size) * if across { progress.x() } else { progress.y() };
+            let moved = bar
+                .placed
+                .is_none_or(|(a, s)| (a - at).abs() > 0.01 || (s - size).abs() > 0.01);
+            if moved {
+                bar.placed = Some((at, size));
+                tree.write_to(bar.thumb, thumb(at, size, across));
+            }
+        }
+    }
+}
+
+/// A thumb taken hold of: remembers where the view was, for the drag to measure from.
+fn grab(
+    trigger: crate::Trigger<Engaged>,
+    mut parts: Query<&mut ScrollbarPart>,
+    progresses: Query<&ScrollProgress>,
+) {
+    let Ok(mut part) = parts.get_mut(trigger.event_target()) else {
+        return;
+    };
+    let Ok(progress) = progresses.get(part.view) else {
+        return;
+    };
+    part.grabbed = if part.across {
+        progress.x()
+    } else {
+        progress.y()
+    };
+}
+
+/// A thumb dragged: the view moves through its range as far as the pointer has moved the
+/// thumb through the room the track leaves it.
+fn drag(
+    trigger: crate::Trigger<Dragged>,
+    parts: Query<&ScrollbarPart>,
+    stems: Query<&Parent>,
+    layouts: Query<&LayoutSection>,
+    current: Res<CurrentInteraction>,
+    mut tree: Tree,
+) {
+    let this = trigger.event_target();
+    let Ok(part) = parts.get(this) else {
+        return;
+    };
+    let Some(track) = stems.get(this).ok().and_then(|s| s.id) else {
+        return;
+    };
+    let (Ok(track), Ok(thumb)) = (layouts.get(track), layouts.get(this)) else {
+        return;
+    };
+    let moved = current.click.current - current.click.start;
+    let (room, moved) = if part.across {
+        (track.0.width() - thumb.0.width(), moved.left())
+    } else {
+        (track.0.height() - thumb.0.height(), moved.top())
+    };
+    if room <= 0.0 {
+        return;
+    }
+    let to = part.grabbed + moved / room;
+    let request = if part.across {
+        ScrollTo::x(to)
+    } else {
+        ScrollTo::y(to)
+    };
+    tree.write_to(part.view, request);
+}
+
+/// The track clicked beside its thumb: the view pages one viewport toward the click.
+fn page(
+    trigger: crate::Trigger<OnClick>,
+    parts: Query<&ScrollbarPart>,
+    views: Query<(&View, &Section<Logical>, &ScrollProgress)>,
+    sections: Query<&Section<Logical>>,
+    scrollbars: Query<&Scrollbars>,
+    current: Res<CurrentInteraction>,
+    mut tree: Tree,
+) {
+    let this = trigger.event_target();
+    let Ok(part) = parts.get(this) else {
+        return;
+    };
+    let Ok((view, section, progress)) = views.get(part.view) else {
+        return;
+    };
+    let Some(thumb) = scrollbars
+        .get(part.view)
+        .ok()
+        .and_then(|s| s.bars.iter().find(|b| b.track == this))
+        .and_then(|b| sections.get(b.thumb).ok())
+    else {
+        return;
+    };
+    // The bars are the view's siblings, drawn under whatever zooms the view's own box is.
+    let thumb = view.on_screen(*thumb);
+    let (max, visible) = range(view, section, part.across);
+    if max <= 0.0 {
+        return;
+    }
+    let at = current.click.end.unwrap_or(current.click.current);
+    let (before, from) = if part.across {
+        (at.left() < thumb.left(), progress.x())
+    } else {
+        (at.top() < thumb.top(), progress.y())
+    };
+    let step = visible / max;
+    let to = if before { from - step } else { from + step };
+    let request = if part.across {
+        ScrollTo::x(to)
+    } else {
+        ScrollTo::y(to)
+    };
+    tree.write_to(part.view, request);
+}
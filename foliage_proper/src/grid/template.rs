@@ -0,0 +1,653 @@
+use crate::grid::{Gap, Layout, Short};
+use crate::node::GrowOrder;
+use crate::{
+    Children, Component, CoordinateUnit, Grid, LayoutSection, Location, Logical, Resolve, Section,
+    Tree, Visibility,
+};
+use bevy_ecs::entity::Entity;
+use bevy_ecs::system::{Query, Res};
+
+/// Lays a parent's children into named areas and auto-placed cells over tracks of differing
+/// sizes, rather than each child naming its own column and row lines against a [`Grid`]'s
+/// equal divisions -- the shell of a page, a form of label/field pairs, a gallery of tiles.
+///
+/// One [`Template`] per breakpoint, falling back down the breakpoints exactly as `Grid`'s
+/// configurations do, so a shell that stacks on a phone and splits on a desktop is two
+/// templates rather than every child restating its lines at each size:
+///
+/// ```ignore
+/// Bare::new().template(
+///     GridTemplate::new(Template::areas(["header", "main", "nav"]))
+///         .md(Template::areas(["header header", "nav main"])
+///             .columns([Track::px(240.0), Track::fr(1.0)])),
+/// )
+/// ```
+///
+/// Only children carrying a [`GridItem`] are placed. Each still needs a [`Location`], for the
+/// same reason a [`Flow`](crate::Flow)'s children do: it states the size the child asks for,
+/// which is what an [`auto`](Track::auto) track is sized to hold. Its position is ignored,
+/// and so is its size on any axis whose track is not the child's to size -- the cell is the
+/// box. An axis sized by [`text_content()`](crate::text_content) keeps its measured extent,
+/// at the cell's near edge.
+///
+/// One pass behind the measure, like the flow: children resolve, the template reads what
+/// they asked for, and a child whose cell moved is re-resolved into it.
+///
+/// Requires [`Grid`] -- the coordinate system a child's `Location` resolves against before
+/// the template places it. The grid's own column and row counts play no part here.
+#[derive(Component, Clone, Debug, PartialEq)]
+#[require(Grid)]
+pub struct GridTemplate {
+    pub xs: Template,
+    pub sm: Option<Template>,
+    pub md: Option<Template>,
+    pub lg: Option<Template>,
+    pub xl: Option<Template>,
+}
+impl GridTemplate {
+    /// A template used at every breakpoint. Add exceptions with
+    /// [`sm`](Self::sm)/[`md`](Self::md)/[`lg`](Self::lg)/[`xl`](Self::xl).
+    pub fn new(xs: Template) -> Self {
+        Self {
+            xs,
+            sm: None,
+            md: None,
+            lg: None,
+            xl: None,
+        }
+    }
+    /// Overrides the template from the `sm` breakpoint up.
+    pub fn sm(mut self, t: Template) -> Self {
+        self.sm.replace(t);
+        self
+    }
+    /// Overrides the template from the `md` breakpoint up.
+    pub fn md(mut self, t: Template) -> Self {
+        self.md.replace(t);
+        self
+    }
+    /// Overrides the template from the `lg` breakpoint up.
+    pub fn lg(mut self, t: Template) -> Self {
+        self.lg.replace(t);
+        self
+    }
+    /// Overrides the template from the `xl` breakpoint up.
+    pub fn xl(mut self, t: Template) -> Self {
+        self.xl.replace(t);
+        self
+    }
+    /// The template in force at `layout`, falling back down the breakpoints to `xs`.
+    pub fn config(&self, layout: Layout) -> &Template {
+        let chain = [&self.xl, &self.lg, &self.md, &self.sm];
+        let skip = match layout {
+            Layout::Xl => 0,
+            Layout::Lg => 1,
+            Layout::Md => 2,
+            Layout::Sm => 3,
+            Layout::Xs => 4,
+        };
+        chain
+            .into_iter()
+            .skip(skip)
+            .find_map(Option::as_ref)
+            .unwrap_or(&self.xs)
+    }
+}
+/// One breakpoint's worth of [`GridTemplate`]: the tracks on each axis, the gaps between
+/// them, and which cells make up each named area.
+///
+/// Columns are fixed by the template. Rows run on past its last one as auto-placed children
+/// need them, each sized as [`auto_rows`](Self::auto_rows) says.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Template {
+    pub(crate) columns: Vec<Track>,
+    pub(crate) rows: Vec<Track>,
+    pub(crate) auto_rows: Track,
+    pub(crate) column_gap: Gap,
+    pub(crate) row_gap: Gap,
+    pub(crate) areas: Vec<NamedArea>,
+}
+impl Template {
+    /// Explicit tracks: `Template::new([Track::px(200.0), Track::fr(1.0)], [Track::auto()])`.
+    pub fn new(
+        columns: impl IntoIterator<Item = Track>,
+        rows: impl IntoIterator<Item = Track>,
+    ) -> Self {
+        let columns = columns.into_iter().collect::<Vec<_>>();
+        assert!(
+            !columns.is_empty(),
+            "a `Template` needs at least one column -- rows can be left to auto-placement, \
+            columns cannot"
+        );
+        Self {
+            columns,
+            rows: rows.into_iter().collect(),
+            auto_rows: Track::auto(),
+            column_gap: Gap::default(),
+            row_gap: Gap::default(),
+            areas: Vec::new(),
+        }
+    }
+    /// Named areas, one string per row and one whitespace-separated name per cell:
+    /// `["header header", "nav main"]`. A `.` leaves a cell out of every area.
+    ///
+    /// Every area has to be a rectangle, and every row the same number of cells -- either
+    /// mistake is a panic here rather than a child landing somewhere nobody drew. Columns
+    /// start as equal [`fr`](Track::fr) shares and rows as [`auto`](Track::auto); replace
+    /// either with [`columns`](Self::columns)/[`rows`](Self::rows), keeping the counts.
+    pub fn areas(rows: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        let cells = rows
+            .into_iter()
+            .map(|row| {
+                row.as_ref()
+                    .split_whitespace()
+                    .map(str::to_string)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let width = cells.first().map(Vec::len).unwrap_or_default();
+        assert!(
+            width > 0 && cells.iter().all(|r| r.len() == width),
+            "every row of `Template::areas` must name the same, non-zero number of cells: \
+            {cells:?}"
+        );
+        let mut areas: Vec<NamedArea> = Vec::new();
+        for (r, row) in cells.iter().enumerate() {
+            for (c, name) in row.iter().enumerate() {
+                if name == "." {
+                    continue;
+                }
+                match areas.iter_mut().find(|a| &a.name == name) {
+                    Some(area) => {
+                        area.columns = (area.columns.0.min(c), area.columns.1.max(c + 1));
+                        area.rows = (area.rows.0.min(r), area.rows.1.max(r + 1));
+                    }
+                    None => areas.push(NamedArea {
+                        name: name.clone(),
+                        columns: (c, c + 1),
+                        rows: (r, r + 1),
+                    }),
+                }
+            }
+        }
+        for area in areas.iter() {
+            let filled = (area.rows.0..area.rows.1)
+                .all(|r| (area.columns.0..area.columns.1).all(|c| cells[r][c] == area.name));
+            assert!(
+                filled,
+                "area `{}` in `Template::areas` is not a rectangle: {cells:?}",
+                area.name
+            );
+        }
+        Self {
+            columns: vec![Track::fr(1.0); width],
+            rows: vec![Track::auto(); cells.len()],
+            auto_rows: Track::auto(),
+            column_gap: Gap::default(),
+            row_gap: Gap::default(),
+            areas,
+        }
+    }
+    /// Replaces the column tracks. On a template with [`areas`](Self::areas), as many as the
+    /// areas have columns.
+    pub fn columns(mut self, columns: impl IntoIterator<Item = Track>) -> Self {
+        let columns = columns.into_iter().collect::<Vec<_>>();
+        assert!(
+            !columns.is_empty() && (self.areas.is_empty() || columns.len() == self.columns.len()),
+            "`Template::columns` given {} tracks where the template has {}",
+            columns.len(),
+            self.columns.len()
+        );
+        self.columns = columns;
+        self
+    }
+    /// Replaces the row tracks. On a template with [`areas`](Self::areas), as many as the
+    /// areas have rows.
+    pub fn rows(mut self, rows: impl IntoIterator<Item = Track>) -> Self {
+        let rows = rows.into_iter().collect::<Vec<_>>();
+        assert!(
+            self.areas.is_empty() || rows.len() == self.rows.len(),
+            "`Template::rows` given {} tracks where the template has {}",
+            rows.len(),
+            self.rows.len()
+        );
+        self.rows = rows;
+        self
+    }
+    /// How each row past the template's own is sized. [`auto`](Track::auto) unless set.
+    pub fn auto_rows(mut self, track: Track) -> Self {
+        self.auto_rows = track;
+        self
+    }
+    /// Space between tracks on both axes, in logical pixels.
+    pub fn gap<G: Into<Gap>>(mut self, g: G) -> Self {
+        self.column_gap = g.into();
+        self.row_gap = self.column_gap;
+        self
+    }
+    pub fn column_gap<G: Into<Gap>>(mut self, g: G) -> Self {
+        self.column_gap = g.into();
+        self
+    }
+    pub fn row_gap<G: Into<Gap>>(mut self, g: G) -> Self {
+        self.row_gap = g.into();
+        self
+    }
+    fn area(&self, name: &str) -> Option<&NamedArea> {
+        self.areas.iter().find(|a| a.name == name)
+    }
+}
+/// A named area's cells: 0-based, end-exclusive, on each axis.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct NamedArea {
+    name: String,
+    columns: (usize, usize),
+    rows: (usize, usize),
+}
+/// One track's size, as CSS's `minmax(min, max)`: never smaller than `min`, grown toward
+/// `max` by whatever room the template has left.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Track {
+    pub min: Breadth,
+    pub max: Breadth,
+}
+impl Track {
+    /// Exactly `px` logical pixels.
+    pub fn px(px: CoordinateUnit) -> Self {
+        Self {
+            min: Breadth::Px(px),
+            max: Breadth::Px(px),
+        }
+    }
+    /// A `fr` share of the room the other tracks leave, but never narrower than what is in it.
+    pub fn fr(fr: f32) -> Self {
+        Self {
+            min: Breadth::Auto,
+            max: Breadth::Fr(fr),
+        }
+    }
+    /// As large as the largest child placed in this track alone, and stretched to share out
+    /// the template's leftover room when no track takes it as a [`fr`](Self::fr).
+    pub fn auto() -> Self {
+        Self {
+            min: Breadth::Auto,
+            max: Breadth::Auto,
+        }
+    }
+    /// No smaller than `min`, no larger than `max`. A `Fr` minimum has no meaning -- a share
+    /// of the leftover cannot be something the leftover is computed after -- and is a panic.
+    pub fn minmax(min: Breadth, max: Breadth) -> Self {
+        assert!(
+            !matches!(min, Breadth::Fr(_)),
+            "`Track::minmax` takes a `Px` or `Auto` minimum, not a `Fr`"
+        );
+        Self { min, max }
+    }
+}
+/// One bound of a [`Track`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Breadth {
+    /// A fixed number of logical pixels.
+    Px(CoordinateUnit),
+    /// A share of the room left once every other track has its size. Only as a maximum.
+    Fr(f32),
+    /// The size the track's own children ask for. Only a child confined to this one track
+    /// counts -- one spanning several is sized by them, not the other way round.
+    Auto,
+}
+/// How a child of a [`GridTemplate`] is placed.
+///
+/// [`auto`](Self::auto) children take the next free cell, row by row, in the order they
+/// were grown, after every [`area`](Self::area) and [`at`](Self::at) child has claimed its
+/// cells. A [`span`](Self::span) wider than the template's columns is cut to fit.
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct GridItem {
+    pub place: GridPlace,
+    /// Columns, then rows, the child covers. Ignored for an area, whose cells are its own.
+    pub span: (usize, usize),
+}
+impl GridItem {
+    /// The next free cell.
+    pub fn auto() -> Self {
+        Self {
+            place: GridPlace::Auto,
+            span: (1, 1),
+        }
+    }
+    /// The named area of the template in force. At a breakpoint whose template has no such
+    /// area the child is hidden, so an area dropped from a small screen's template takes its
+    /// child with it.
+    pub fn area(name: impl Into<String>) -> Self {
+        Self {
+            place: GridPlace::Area(name.into()),
+            span: (1, 1),
+        }
+    }
+    /// A 1-based column and row, as [`col`](crate::GridExt::col)/[`row`](crate::GridExt::row)
+    /// count them.
+    pub fn at(column: usize, row: usize) -> Self {
+        Self {
+            place: GridPlace::At(column.max(1), row.max(1)),
+            span: (1, 1),
+        }
+    }
+    pub fn span(mut self, columns: usize, rows: usize) -> Self {
+        self.span = (columns.max(1), rows.max(1));
+        self
+    }
+}
+/// Where a [`GridItem`] asks to go.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GridPlace {
+    Auto,
+    Area(String),
+    At(usize, usize),
+}
+
+/// A template child's own size and the cell its template gave it, kept on the child.
+///
+/// Written the way [`Flowed`](crate::grid::flow::Flowed) is, for the same reasons: `basis` is
+/// recorded by `Location::update` before the cell is applied, and `within` stops a child
+/// re-stemmed elsewhere from taking cells from a template it has left. A `slot` of `None` is
+/// a child whose area the template in force does not have, which resolves to nothing.
+#[derive(Component, Copy, Clone, Debug)]
+pub(crate) struct Templated {
+    pub(crate) within: Entity,
+    /// Offset from the template's own box, and the size to take there.
+    pub(crate) slot: Option<Section<Logical>>,
+    pub(crate) basis: Option<crate::Area<Logical>>,
+}
+
+struct Entry {
+    entity: Entity,
+    size: crate::Area<Logical>,
+    /// Column range then row range, 0-based and end-exclusive, once placed.
+    cells: Option<((usize, usize), (usize, usize))>,
+}
+
+/// Places every [`GridTemplate`]'s children and sizes its tracks, from the sizes the children
+/// last resolved to.
+///
+/// Asked every frame rather than on change: a child's size moves for reasons that reach no
+/// single component of the template's -- a resolve, a glyph measure, a parent resize. A
+/// settled template compares equal and sends nothing.
+#[allow(clippy::type_complexity)]
+pub(crate) fn place(
+    templates: Query<(Entity, &GridTemplate, &Children, &LayoutSection)>,
+    mut items: Query<(
+        &Location,
+        &LayoutSection,
+        &GridItem,
+        Option<&mut Templated>,
+        Option<&Visibility>,
+    )>,
+    grow_orders: Query<&GrowOrder>,
+    layout: Res<Layout>,
+    short: Res<Short>,
+    mut tree: Tree,
+) {
+    const EPSILON: CoordinateUnit = 0.01;
+    for (this, template, children, context) in templates.iter() {
+        let template = template.config(*layout);
+        let width = template.columns.len();
+        let mut entries = Vec::new();
+        let mut taken: Vec<Vec<bool>> = Vec::new();
+        let take = |taken: &mut Vec<Vec<bool>>, (c, r): ((usize, usize), (usize, usize))| {
+            if taken.len() < r.1 {
+                taken.resize(r.1, vec![false; width]);
+            }
+            for row in taken[r.0..r.1].iter_mut() {
+                row[c.0..c.1].fill(true);
+            }
+        };
+        let mut waiting = Vec::new();
+        // `Children` is a set; auto-placement goes in the order the children were grown.
+        let mut kids = children.ids.iter().copied().collect::<Vec<_>>();
+        kids.sort_by_key(|kid| (grow_orders.get(*kid).map(|o| o.0).unwrap_or(u64::MAX), *kid));
+        for child in kids {
+            let Ok((location, placed, item, templated, visibility)) = items.get(child) else {
+                continue;
+            };
+            // The child's own visibility, not the resolved one: a child the template hid for
+            // want of its area resolves invisible, and has to stay in the running to come back.
+            if !location.boxed(*layout, *short) || visibility.is_some_and(|v| !v.visible()) {
+                continue;
+            }
+            let mut size = templated
+                .filter(|t| t.within == this)
+                .and_then(|t| t.basis)
+                .unwrap_or(placed.0.area);
+            let (content_w, content_h) = location.content_axes(*layout, *short);
+            if content_w {
+                size.set_width(placed.0.width());
+            }
+            if content_h {
+                size.set_height(placed.0.height());
+            }
+            let span = (item.span.0.max(1), item.span.1.max(1));
+            let cells = match &item.place {
+                GridPlace::Auto => {
+                    waiting.push((entries.len(), span));
+                    None
+                }
+                GridPlace::Area(name) => template.area(name).map(|area| (area.columns, area.rows)),
+                GridPlace::At(c, r) => {
+                    let c = c.saturating_sub(1).min(width - 1);
+                    let r = r.saturating_sub(1);
+                    Some(((c, (c + span.0).min(width)), (r, r + span.1)))
+                }
+            };
+            if let Some(cells) = cells {
+                take(&mut taken, cells);
+            }
+            entries.push(Entry {
+                entity: child,
+                size,
+                cells,
+            });
+        }
+        // Sparse, as CSS's default `grid-auto-flow: row`: the cursor only moves forward, so a
+        // hole left behind a wide child stays a hole rather than being back-filled out of
+        // order.
+        let mut cursor = (0, 0);
+        for (at, (columns, rows)) in waiting {
+            let columns = columns.min(width);
+            let (mut c, mut r) = cursor;
+            loop {
+                if c + columns > width {
+                    c = 0;
+                    r += 1;
+                    continue;
+                }
+                let free = (r..r + rows).all(|r| {
+                    taken
+                        .get(r)
+                        .is_none_or(|row| row[c..c + columns].iter().all(|t| !t))
+                });
+                if free {
+                    break;
+                }
+                c += 1;
+            }
+            let cells = ((c, c + columns), (r, r + rows));
+            take(&mut taken, cells);
+            entries[at].cells = Some(cells);
+            cursor = (c + columns, r);
+        }
+        let mut rows = template.rows.clone();
+        if rows.len() < taken.len() {
+            rows.resize(taken.len(), template.auto_rows);
+        }
+        let mut wanted_columns = vec![0.0 as CoordinateUnit; width];
+        let mut wanted_rows = vec![0.0 as CoordinateUnit; rows.len()];
+        for entry in entries.iter() {
+            let Some((c, r)) = entry.cells else {
+                continue;
+            };
+            if c.1 - c.0 == 1 {
+                wanted_columns[c.0] = wanted_columns[c.0].max(entry.size.width());
+            }
+            if r.1 - r.0 == 1 {
+                wanted_rows[r.0] = wanted_rows[r.0].max(entry.size.height());
+            }
+        }
+        let columns = lines(
+            &template.columns,
+            &wanted_columns,
+            context.0.width(),
+            template.column_gap.amount,
+        );
+        let rows = lines(
+            &rows,
+            &wanted_rows,
+            context.0.height(),
+            template.row_gap.amount,
+        );
+        for entry in entries {
+            let slot = entry.cells.map(|(c, r)| {
+                Section::new(
+                    (columns[c.0].0, rows[r.0].0),
+                    (
+                        columns[c.1 - 1].1 - columns[c.0].0,
+                        rows[r.1 - 1].1 - rows[r.0].0,
+                    ),
+                )
+            });
+            match items.get_mut(entry.entity).ok().and_then(|(.., t, _)| t) {
+                Some(mut templated) if templated.within == this => {
+                    let same = match (templated.slot, slot) {
+                        (Some(current), Some(slot)) => {
+                            (current.left() - slot.left()).abs() < EPSILON
+                                && (current.top() - slot.top()).abs() < EPSILON
+                                && (current.width() - slot.width()).abs() < EPSILON
+                                && (current.height() - slot.height()).abs() < EPSILON
+                        }
+                        (None, None) => true,
+                        _ => false,
+                    };
+                    if same {
+                        continue;
+                    }
+                    templated.slot = slot;
+                }
+                _ => {
+                    tree.write_to(
+                        entry.entity,
+                        Templated {
+                            within: this,
+                            slot,
+                            basis: Some(entry.size),
+                        },
+                    );
+                }
+            }
+            tree.send_to(Resolve::<Location>::new(), entry.entity);
+        }
+    }
+}
+
+/// Sizes one axis's tracks into `available`, and returns each track's near and far edge.
+///
+/// CSS's track sizing, cut down to what a [`Track`] can say: every track starts at its
+/// minimum, the ones with a fixed or content maximum grow toward it while room lasts, and
+/// then the `fr` tracks share what is left -- any whose share would fall below its own
+/// minimum keeps the minimum and drops out of the sharing. With no `fr` track, leftover room
+/// stretches the `auto` ones instead, so a template of nothing but `auto` rows fills its box.
+fn lines(
+    tracks: &[Track],
+    wanted: &[CoordinateUnit],
+    available: CoordinateUnit,
+    gap: CoordinateUnit,
+) -> Vec<(CoordinateUnit, CoordinateUnit)> {
+    const EPSILON: CoordinateUnit = 0.01;
+    let room = (available - gap * tracks.len().saturating_sub(1) as CoordinateUnit).max(0.0);
+    let base = tracks
+        .iter()
+        .zip(wanted)
+        .map(|(t, w)| match t.min {
+            Breadth::Px(px) => px,
+            Breadth::Auto | Breadth::Fr(_) => *w,
+        })
+        .collect::<Vec<_>>();
+    let limit = tracks
+        .iter()
+        .zip(wanted)
+        .zip(base.iter())
+        .map(|((t, w), b)| match t.max {
+            Breadth::Px(px) => px.max(*b),
+            Breadth::Auto => w.max(*b),
+            Breadth::Fr(_) => CoordinateUnit::INFINITY,
+        })
+        .collect::<Vec<_>>();
+    let flexible = |i: usize| matches!(tracks[i].max, Breadth::Fr(_));
+    let mut sizes = base.clone();
+    let mut free = room - sizes.iter().sum::<CoordinateUnit>();
+    loop {
+        let growing = (0..tracks.len())
+            .filter(|&i| !flexible(i) && sizes[i] + EPSILON < limit[i])
+            .collect::<Vec<_>>();
+        if growing.is_empty() || free < EPSILON {
+            break;
+        }
+        let share = free / growing.len() as CoordinateUnit;
+        for i in growing {
+            let add = share.min(limit[i] - sizes[i]);
+            sizes[i] += add;
+            free -= add;
+        }
+    }
+    let mut sharing = (0..tracks.len())
+        .filter(|&i| flexible(i))
+        .collect::<Vec<_>>();
+    if !sharing.is_empty() {
+        loop {
+            let left = room
+                - (0..tracks.len())
+                    .filter(|i| !sharing.contains(i))
+                    .map(|i| sizes[i])
+                    .sum::<CoordinateUnit>();
+            let shares = sharing
+                .iter()
+                .map(|&i| match tracks[i].max {
+                    Breadth::Fr(fr) => fr.max(0.0),
+                    _ => 0.0,
+                })
+                .collect::<Vec<_>>();
+            let total = shares.iter().sum::<f32>();
+            let unit = if total > 0.0 {
+                (left / total).max(0.0)
+            } else {
+                0.0
+            };
+            let under = sharing
+                .iter()
+                .zip(shares.iter())
+                .filter(|&(&i, share)| base[i] > unit * share)
+                .map(|(&i, _)| i)
+                .collect::<Vec<_>>();
+            if under.is_empty() {
+                for (&i, share) in sharing.iter().zip(shares) {
+                    sizes[i] = unit * share;
+                }
+                break;
+            }
+            sharing.retain(|i| !under.contains(i));
+        }
+    } else if free > EPSILON {
+        let stretching = (0..tracks.len())
+            .filter(|&i| tracks[i].max == Breadth::Auto)
+            .collect::<Vec<_>>();
+        for &i in stretching.iter() {
+            sizes[i] += free / stretching.len() as CoordinateUnit;
+        }
+    }
+    let mut at = 0.0;
+    sizes
+        .into_iter()
+        .map(|size| {
+            let edges = (at, at + size);
+            at += size + gap;
+            edges
+        })
+        .collect()
+}
@@ -0,0 +1,79 @@
+use crate::{Component, Easement, Location, Logical, Resolve, Section, Timing, Tree};
+use bevy_ecs::entity::Entity;
+use bevy_ecs::system::{Query, Res};
+
+/// Eases an element from where it was drawn to wherever its `Location` next resolves --
+/// given with [`Author::transition`](crate::Sprout::transition).
+///
+/// Watches the resolve itself rather than any one way of asking for a move, so a new
+/// `Location`, a breakpoint flip, a parent resizing, or a flow or template handing it another
+/// slot all glide the same way. The first placement, and a return from being auto-hidden,
+/// land where they are without one. Children are placed from the box as it moves, so a
+/// whole subtree travels with it.
+#[derive(Component, Clone)]
+pub(crate) struct Transition(pub(crate) Timing);
+
+/// A move under way, in layout space: where the box was drawn when its target last changed,
+/// and that target. A target that moves again starts a new one from wherever the box is
+/// drawn by then, so an interrupted move turns rather than jumping.
+#[derive(Component, Copy, Clone, Debug)]
+pub(crate) struct Transitioning {
+    pub(crate) from: Section<Logical>,
+    pub(crate) to: Section<Logical>,
+    elapsed_ms: f32,
+}
+
+impl Transitioning {
+    pub(crate) fn new(from: Section<Logical>, to: Section<Logical>) -> Self {
+        Self {
+            from,
+            to,
+            elapsed_ms: 0.0,
+        }
+    }
+}
+
+impl Transition {
+    /// Where `moving` draws the box at the time it has reached.
+    pub(crate) fn at(&self, moving: &Transitioning) -> Section<Logical> {
+        let span = self.0.finish.saturating_sub(self.0.start) as f32;
+        let linear = if span > 0.0 {
+            ((moving.elapsed_ms - self.0.start as f32) / span).clamp(0.0, 1.0)
+        } else if moving.elapsed_ms >= self.0.start as f32 {
+            1.0
+        } else {
+            0.0
+        };
+        let t = Easement::new(self.0.ease.clone()).percent_changed(linear);
+        let (from, to) = (moving.from, moving.to);
+        Section::logical(
+            (
+                from.left() + (to.left() - from.left()) * t,
+                from.top() + (to.top() - from.top()) * t,
+            ),
+            (
+                from.width() + (to.width() - from.width()) * t,
+                from.height() + (to.height() - from.height()) * t,
+            ),
+        )
+    }
+}
+
+/// Steps every move under way on foliage's clock and resolves its element again, which is
+/// what draws the box at the new time. A finished move is stripped only after that last
+/// resolve has drawn it on its target: stripped first, the resolve would find the box short
+/// of its target with nothing moving it, and start a move there all over again.
+pub(crate) fn transition(
+    mut moving: Query<(Entity, &mut Transitioning, &Transition)>,
+    time: Res<crate::Time>,
+    mut tree: Tree,
+) {
+    let elapsed_ms = time.frame_diff().as_secs_f32() * 1000.0;
+    for (entity, mut m, transition) in moving.iter_mut() {
+        m.elapsed_ms += elapsed_ms;
+        tree.send_to(Resolve::<Location>::new(), entity);
+        if m.elapsed_ms >= transition.0.finish as f32 {
+            tree.strip::<Transitioning>(entity);
+        }
+    }
+}
@@ -3,11 +3,11 @@ use crate::ginkgo::viewport::ViewportHandle;
 use crate::grid::location::Resolution;
 use crate::interaction::CurrentInteraction;
 use crate::{
-    AnchorDeps, Children, Component, LayoutSection, Location, Logical, Parent, Points, Position,
-    Resolve, Section, Tree,
+    AnchorDeps, Children, Component, CoordinateUnit, LayoutSection, Location, Logical, Parent,
+    Points, Position, Resolve, Section, Tree,
 };
 use bevy_ecs::entity::Entity;
-use bevy_ecs::prelude::{Changed, DetectChanges, Query, Ref, Res, ResMut, Resource};
+use bevy_ecs::prelude::{Changed, DetectChanges, Query, Ref, Res, ResMut, Resource, Without};
 use std::collections::HashSet;
 
 /// A scroll to apply, and what kind of input asked for it.
@@ -182,6 +182,16 @@ pub struct ScrollMomentum {
     /// time (`0.998.powf(2000)` is still only ~98% gone). Real touch-scroll implementations
     /// use a similarly short recency window for exactly this reason, not a slow decay.
     pub stillness_cutoff_ms: f32,
+    /// Fraction of the distance left to a [`ScrollSnap`] point retained per elapsed ms while
+    /// settling onto it -- the same frame-rate independent `powf` shape as `decay`, applied to
+    /// distance rather than velocity, so the settle eases out instead of stopping on a wall.
+    pub snap_decay: f32,
+    /// ms a [`ScrollSnap`] view waits after the last wheel notch before settling. A wheel has
+    /// no release to settle on, so quiet stands in for one; shorter than a spinning wheel's
+    /// gap between notches and the view snaps back under every one of them.
+    pub wheel_settle_ms: f32,
+    /// ms an animated [`scroll_into_view`](crate::Grows::scroll_into_view) takes to arrive.
+    pub reveal_ms: f32,
 }
 impl Default for ScrollMomentum {
     fn default() -> Self {
@@ -190,6 +200,9 @@ impl Default for ScrollMomentum {
             decay: 0.997,
             stop_epsilon: 0.05,
             stillness_cutoff_ms: 150.0,
+            snap_decay: 0.985,
+            wheel_settle_ms: 150.0,
+            reveal_ms: 300.0,
         }
     }
 }
@@ -262,9 +275,293 @@ pub(crate) fn coast(
         c.velocity = c.velocity * decayed;
         if c.velocity.left().hypot(c.velocity.top()) < momentum.stop_epsilon {
             tree.strip::<Coasting>(entity);
+            // A coast running out is the gesture's real end, so it is where a snap starts --
+            // not the release that handed over to it, which would fight the coast for the
+            // offset all the way down.
+            tree.write_to(entity, Settle::now(c.method));
         }
     }
 }
+/// Where a view comes to rest once a gesture lets go of it, per axis -- a carousel on its
+/// next card, a pager on a whole page, a picker on a row.
+///
+/// Applied when the gesture ends: at release for a drag too slow to coast, when the coast
+/// runs out otherwise, and once a wheel has gone quiet for
+/// [`ScrollMomentum::wheel_settle_ms`]. The view then eases onto the nearest point at
+/// [`ScrollMomentum::snap_decay`] and reports it with
+/// [`Bloom::SnappedTo`](crate::Bloom::SnappedTo). Input is never resisted on the way: the
+/// reader drags freely, and the snap is only where the view goes once nobody is holding it.
+///
+/// Found from whatever the gesture grabbed by walking up to the first view carrying one, the
+/// same walk the overscroll chain takes, so a card dragged inside a carousel settles the
+/// carousel. An axis that does not take input -- [`ScrollAxes`] off, or held off by a
+/// [`DirectionalLock`] the gesture committed away from -- is left where it is.
+///
+/// [`ScrollTo`] is not snapped. It states exactly where the view belongs, and rounding that to
+/// a snap point would be second-guessing the one caller that already knows.
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq)]
+pub struct ScrollSnap {
+    pub x: Option<Snap>,
+    pub y: Option<Snap>,
+}
+impl ScrollSnap {
+    /// Snaps across, leaving down free.
+    pub fn x(snap: Snap) -> Self {
+        Self {
+            x: Some(snap),
+            y: None,
+        }
+    }
+    /// Snaps down, leaving across free.
+    pub fn y(snap: Snap) -> Self {
+        Self {
+            x: None,
+            y: Some(snap),
+        }
+    }
+    /// Snaps both axes.
+    pub fn xy(x: Snap, y: Snap) -> Self {
+        Self {
+            x: Some(x),
+            y: Some(y),
+        }
+    }
+}
+/// The points one axis of a [`ScrollSnap`] comes to rest on. Each is clamped into the view's
+/// scrollable range, so the last page of content that does not divide evenly still ends flush.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Snap {
+    /// A child's near edge lined up with the view's. Children hidden by the app, and
+    /// [`clip_to_viewport`](crate::Sprout::clip_to_viewport) overlays, are not snapped to --
+    /// the same children the extent leaves out. The reported index counts the rest, in the
+    /// order they were grown.
+    Children,
+    /// Every so many logical pixels from the start of the range.
+    Interval(CoordinateUnit),
+    /// Every view's width (or height) -- an interval that follows the view as it resizes.
+    Page,
+}
+/// A gesture on this entity has ended, and the nearest [`ScrollSnap`] view above it should
+/// settle once `after_ms` has run down -- at once for a drag or coast, after
+/// [`ScrollMomentum::wheel_settle_ms`] for a wheel. Written again by every notch, which is
+/// what restarts the wait.
+#[derive(Component, Copy, Clone, Debug)]
+pub(crate) struct Settle {
+    pub(crate) after_ms: f32,
+    pub(crate) method: crate::InteractionMethod,
+}
+impl Settle {
+    pub(crate) fn now(method: crate::InteractionMethod) -> Self {
+        Self {
+            after_ms: 0.0,
+            method,
+        }
+    }
+}
+/// A view easing onto its snap point. `remaining` is kept here rather than re-read from
+/// `View::offset`, which trails the adjustments written into it by a frame, so the last step
+/// lands on the point instead of overshooting it.
+#[derive(Component, Copy, Clone, Debug)]
+pub(crate) struct Snapping {
+    remaining: Position<Logical>,
+    /// The point on each axis that snapped, reported once the view arrives.
+    index: (Option<usize>, Option<usize>),
+    method: crate::InteractionMethod,
+}
+/// Starts each ended gesture's snap, and eases every view already snapping a step closer.
+///
+/// Steps are written as [`ViewAdjustment`]s, so a snap is clamped and published by
+/// `extent_check` like any other movement -- and stopped the same way a coast is, by a press.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub(crate) fn snap(
+    mut settling: Query<(Entity, &mut Settle)>,
+    mut snapping: Query<(Entity, &mut Snapping)>,
+    views: Query<(
+        &View,
+        &Section<Logical>,
+        &LayoutSection,
+        &ScrollAxes,
+        &DirectionalLock,
+        Option<&ScrollSnap>,
+    )>,
+    stems: Query<&Parent>,
+    branches: Query<&Children>,
+    contents: Query<(&LayoutSection, &crate::ResolvedVisibility), Without<ClipToViewport>>,
+    momentum: Res<ScrollMomentum>,
+    current: Res<CurrentInteraction>,
+    time: Res<crate::Time>,
+    grown: Query<&crate::boundary::leaf::Grown>,
+    mut emissions: ResMut<crate::boundary::bloom::Emissions>,
+    mut tree: Tree,
+) {
+    const ARRIVED: CoordinateUnit = 0.5;
+    let elapsed_ms = time.frame_diff().as_secs_f32() * 1000.0;
+    let mut halted = HashSet::new();
+    for (entity, mut settle) in settling.iter_mut() {
+        if current.pressed {
+            tree.strip::<Settle>(entity);
+            continue;
+        }
+        let mut at = Some(entity);
+        let mut found = None;
+        while let Some(id) = at {
+            if let Ok((.., Some(snap))) = views.get(id)
+                && (snap.x.is_some() || snap.y.is_some())
+            {
+                found = Some((id, *snap));
+                break;
+            }
+            at = stems.get(id).ok().and_then(|p| p.id);
+        }
+        let Some((id, snap)) = found else {
+            tree.strip::<Settle>(entity);
+            continue;
+        };
+        if settle.after_ms > 0.0 {
+            // The wheel is still turning this view, so a snap already under way is stale.
+            settle.after_ms -= elapsed_ms;
+            if snapping.contains(id) {
+                halted.insert(id);
+                tree.strip::<Snapping>(id);
+            }
+            continue;
+        }
+        tree.strip::<Settle>(entity);
+        let (view, section, layout, axes, lock, _) = views.get(id).unwrap();
+        let [range_x, range_y] = view.range(*section);
+        let section = view.on_screen(*section);
+        let committed = current.axis.filter(|_| lock.0);
+        let edges = |across: bool| {
+            branches
+                .get(id)
+                .map(|b| {
+                    b.ids
+                        .iter()
+                        .filter_map(|c| contents.get(*c).ok())
+                        .filter(|(_, v)| v.user_visible())
+                        .map(|(child, _)| {
+                            let edge = if across {
+                                child.0.left() - layout.0.left()
+                            } else {
+                                child.0.top() - layout.0.top()
+                            };
+                            edge * view.accumulated_zoom
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default()
+        };
+        let x = snap
+            .x
+            .filter(|_| axes.x && committed != Some(crate::GestureAxis::Down))
+            .and_then(|s| {
+                rest(
+                    s,
+                    view.offset.left(),
+                    range_x,
+                    section.width(),
+                    &edges(true),
+                )
+            });
+        let y = snap
+            .y
+            .filter(|_| axes.y && committed != Some(crate::GestureAxis::Across))
+            .and_then(|s| {
+                rest(
+                    s,
+                    view.offset.top(),
+                    range_y,
+                    section.height(),
+                    &edges(false),
+                )
+            });
+        if x.is_none() && y.is_none() {
+            continue;
+        }
+        let remaining = Position::new((
+            x.map(|(p, _)| p - view.offset.left()).unwrap_or_default(),
+            y.map(|(p, _)| p - view.offset.top()).unwrap_or_default(),
+        ));
+        tree.write_to(
+            id,
+            Snapping {
+                remaining,
+                index: (x.map(|(_, i)| i), y.map(|(_, i)| i)),
+                method: settle.method,
+            },
+        );
+        // Replaces any snap already running here, which the loop below must not step on top
+        // of the fresh one.
+        halted.insert(id);
+    }
+    for (entity, mut snapping) in snapping.iter_mut() {
+        if halted.contains(&entity) {
+            continue;
+        }
+        if current.pressed {
+            tree.strip::<Snapping>(entity);
+            continue;
+        }
+        let mut step = snapping.remaining * (1.0 - momentum.snap_decay.powf(elapsed_ms));
+        let left = snapping.remaining - step;
+        let arrived = left.left().hypot(left.top()) < ARRIVED;
+        if arrived {
+            step = snapping.remaining;
+        }
+        snapping.remaining -= step;
+        tree.write_to(entity, ViewAdjustment(step, snapping.method));
+        if arrived {
+            tree.strip::<Snapping>(entity);
+            // Only for views the app grew, as `Bloom::ScrollRefused` is.
+            if grown.contains(entity) {
+                let landed = [
+                    (crate::GestureAxis::Across, snapping.index.0),
+                    (crate::GestureAxis::Down, snapping.index.1),
+                ];
+                for (axis, index) in landed {
+                    if let Some(index) = index {
+                        emissions.push(crate::Bloom::SnappedTo {
+                            leaf: crate::Leaf(entity),
+                            index,
+                            axis,
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
+/// The point one axis of a snap comes to rest on from `at`, and its index, within `range`.
+/// `None` when the axis has nothing to snap to -- no children, or a step that is not positive.
+fn rest(
+    snap: Snap,
+    at: CoordinateUnit,
+    range: (CoordinateUnit, CoordinateUnit),
+    page: CoordinateUnit,
+    edges: &[CoordinateUnit],
+) -> Option<(CoordinateUnit, usize)> {
+    let (min, max) = range;
+    let step = match snap {
+        Snap::Children => {
+            return edges
+                .iter()
+                .map(|e| e.clamp(min, max))
+                .enumerate()
+                .min_by(|(_, a), (_, b)| (*a - at).abs().total_cmp(&(*b - at).abs()))
+                .map(|(i, p)| (p, i));
+        }
+        Snap::Interval(px) => px,
+        Snap::Page => page,
+    };
+    if step <= 0.0 {
+        return None;
+    }
+    // The last point is the end of the range whether or not it falls on a step, so content
+    // that does not divide evenly still has its far end reachable.
+    let last = ((max - min) / step).ceil();
+    let index = ((at - min) / step).round().clamp(0.0, last);
+    Some(((min + index * step).min(max), index as usize))
+}
 #[derive(Component, Copy, Clone, Debug)]
 #[require(
     ViewAdjustment,
@@ -290,10 +587,10 @@ pub(crate) fn coast(
 ///
 /// Scrolling never re-resolves anything. An offset is a translation of a whole subtree,
 /// which is why it is kept out of the layout entirely: `Location` resolves into
-/// [`LayoutSection`], and `propagate_offsets` subtracts
-/// `accumulated_offset` from it to produce the on-screen [`Section`]. Moving a view rewrites
-/// its descendants' `Section`s and nothing else -- no `Location` is resolved, no glyph is
-/// laid out again.
+/// [`LayoutSection`], and `propagate_offsets` subtracts `accumulated_offset` from it to
+/// produce the on-screen [`Section`] -- scaled as it is drawn, under a [`Zoom`](crate::Zoom).
+/// Moving a view rewrites its descendants' `Section`s and nothing else -- no `Location` is
+/// resolved, no glyph is laid out again.
 ///
 /// The cost that remains is one write per descendant, plus the differential and the instance
 /// buffer behind it. That floor is deliberate: pushing the offset past the differential (a
@@ -338,6 +635,12 @@ pub struct View {
     /// rather than deriving it at each use is what keeps the scale factor out of
     /// `Location::update`, whose parameter list has no room left for it.
     pub(crate) snapped_offset: Position<Logical>,
+    /// How much this view magnifies what it holds -- 1 for any view without a
+    /// [`Zoom`](crate::Zoom), which is nearly all of them.
+    pub(crate) zoom: f32,
+    /// This view's own `zoom` times every ancestor view's: what a child's box is drawn scaled
+    /// by, once `accumulated_offset` is subtracted. See [`View::child_on_screen`].
+    pub(crate) accumulated_zoom: f32,
 }
 impl View {
     /// An unscrolled view. Its extent is computed from its children.
@@ -347,6 +650,8 @@ impl View {
             extent: Default::default(),
             accumulated_offset: Default::default(),
             snapped_offset: Default::default(),
+            zoom: 1.0,
+            accumulated_zoom: 1.0,
         }
     }
     /// Current pan, in px -- raw state, `pub(crate)`-write only (`extent_check`'s clamp
@@ -362,12 +667,90 @@ impl View {
     pub fn extent(&self) -> Section<Logical> {
         self.extent
     }
+    /// How much this view magnifies its content: 1 unless it is a [`Zoom`](crate::Zoom).
+    pub fn zoom(&self) -> f32 {
+        self.zoom
+    }
+    /// Where a box laid out beneath this view is placed: `accumulated_offset` taken off. Under
+    /// a [`Zoom`](crate::Zoom) that is not yet on screen -- the zoom is drawn as a scale, through
+    /// `ResolvedTransform`, so the box keeps its laid-out size and everything drawn in it grows
+    /// with it. [`child_on_screen`](Self::child_on_screen) is where it lands.
+    pub(crate) fn place(&self, mut section: Section<Logical>) -> Section<Logical> {
+        section.position = self.place_point(section.position);
+        section
+    }
+    pub(crate) fn place_point(&self, point: Position<Logical>) -> Position<Logical> {
+        point - self.accumulated_offset
+    }
+    /// Back from where a box beneath this view is placed to layout space -- the inverse of
+    /// [`place`](Self::place).
+    pub(crate) fn unplace(&self, mut section: Section<Logical>) -> Section<Logical> {
+        section.position += self.accumulated_offset;
+        section
+    }
+    /// A box placed beneath this view, as it lands on screen: scaled by `accumulated_zoom`
+    /// about the origin, which is the scale its `ResolvedTransform` draws it through. Without a
+    /// zoom anywhere above, the box as it is.
+    pub(crate) fn child_on_screen(&self, section: Section<Logical>) -> Section<Logical> {
+        magnify(section, self.accumulated_zoom)
+    }
+    /// A box on screen, stated where a box beneath this view is placed -- the inverse of
+    /// [`child_on_screen`](Self::child_on_screen).
+    pub(crate) fn child_from_screen(&self, section: Section<Logical>) -> Section<Logical> {
+        magnify(section, 1.0 / self.accumulated_zoom)
+    }
+    /// This view's own box, as it lands on screen. Only the zooms above it scale it, not its
+    /// own.
+    pub(crate) fn on_screen(&self, section: Section<Logical>) -> Section<Logical> {
+        magnify(section, self.accumulated_zoom / self.zoom)
+    }
+    /// The offsets this view can rest at, `(min, max)` per axis, given its own `section` -- the
+    /// bounds `ovrscrl` clamps to. `min` is below zero where content reaches before the view's
+    /// near edge, and `max` is never less than `min`.
+    pub(crate) fn range(&self, section: Section<Logical>) -> [(CoordinateUnit, CoordinateUnit); 2] {
+        let section = self.on_screen(section);
+        let min_x = self.extent.left() - section.left();
+        let min_y = self.extent.top() - section.top();
+        [
+            (min_x, (self.extent.width() - section.right()).max(min_x)),
+            (min_y, (self.extent.height() - section.bottom()).max(min_y)),
+        ]
+    }
+    /// Rebuilds what this view hands its children from what its parent handed it and where the
+    /// layout put its own box.
+    ///
+    /// A child at `c` lands at `(c - accumulated_offset) * accumulated_zoom`. Solving that for
+    /// a child that should sit at this view's own screen origin, scaled by `zoom` about it and
+    /// moved back by `snapped_offset`, gives the offset below; with `zoom` at 1 it is the
+    /// parent's plus this view's own, as it always was. `snapped_offset` stays in screen px
+    /// whatever is zoomed above, so a drag moves the content exactly as far as the pointer.
+    pub(crate) fn accumulate(&mut self, inherited: &View, origin: Position<Logical>) {
+        let within = inherited.accumulated_zoom;
+        self.accumulated_offset = if self.zoom == 1.0 {
+            inherited.accumulated_offset + self.snapped_offset / within
+        } else {
+            origin
+                - (origin - inherited.accumulated_offset - self.snapped_offset / within) / self.zoom
+        };
+        self.accumulated_zoom = within * self.zoom;
+    }
+    /// Carries a [`Sticky`] shift, which is on screen, down to what this view's children are
+    /// placed from -- taken off before the zoom, so divided by it.
+    pub(crate) fn hold(&mut self, shift: Position<Logical>) {
+        self.accumulated_offset -= shift / self.accumulated_zoom;
+    }
 }
 impl Default for View {
     fn default() -> Self {
         Self::new()
     }
 }
+/// `section` scaled by `by` about the origin, position and size alike.
+fn magnify(mut section: Section<Logical>, by: f32) -> Section<Logical> {
+    section.position = section.position * by;
+    section.area = section.area * by;
+    section
+}
 /// Read-only readout of `View`'s own current scroll position, as a 0..1 fraction of its
 /// scrollable range per axis -- the resolved counterpart to the [`ScrollTo`] request, the
 /// same "author states intent, a resolved value is what everything else reads" split
@@ -391,6 +774,100 @@ impl ScrollProgress {
         self.y
     }
 }
+/// Holds an element at an edge of the nearest view above it that can scroll on that axis,
+/// once scrolling would carry it past -- CSS's `position: sticky`. A section header that stays
+/// in sight while its section does.
+///
+/// Its parent is what it sticks *within*: the element never leaves the parent's box, so when
+/// the parent's far edge scrolls up to it, it goes with the parent and the next section's
+/// header takes its place. Stuck directly under the scrolling view, it stays for good.
+///
+/// Applied where the scroll is, not by moving the `Location`: the layout never sees it, and
+/// the two places that turn a layout box into an on-screen one -- a resolve and
+/// `propagate_offsets` -- each add the shift on the same pass, so it cannot trail the scroll
+/// by a frame. What is under the element moves with it.
+#[derive(Component, Copy, Clone, Debug, PartialEq)]
+#[require(Stuck)]
+pub struct Sticky {
+    pub edge: StickyEdge,
+    /// Logical pixels between the view's edge and where the element holds.
+    pub offset: CoordinateUnit,
+}
+/// Which edge of its scrolling view a [`Sticky`] element holds at.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StickyEdge {
+    Top,
+    Left,
+}
+/// How far a [`Sticky`] element was last moved from where its layout put it -- what its
+/// children subtract on top of the scroll, so they ride along with it.
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq)]
+pub(crate) struct Stuck(pub(crate) Position<Logical>);
+/// How far `this`, whose unstuck box on screen is `screen`, has to move to hold as `sticky`
+/// says.
+///
+/// `stem` names an entity's parent, and `look` a view's state and on-screen box: the two
+/// callers hold them in different queries, and both have already settled every ancestor's
+/// box by the time they ask.
+pub(crate) fn stuck(
+    this: Entity,
+    screen: Section<Logical>,
+    sticky: Sticky,
+    stem: impl Fn(Entity) -> Option<Entity>,
+    mut look: impl FnMut(Entity) -> Option<(View, Section<Logical>)>,
+) -> Position<Logical> {
+    const EPSILON: CoordinateUnit = 0.01;
+    let Some(parent) = stem(this) else {
+        return Position::default();
+    };
+    let Some((_, container)) = look(parent) else {
+        return Position::default();
+    };
+    let top = sticky.edge == StickyEdge::Top;
+    let mut at = Some(parent);
+    let mut scroller = None;
+    while let Some(id) = at {
+        if let Some((view, section)) = look(id) {
+            let scrolls = if top {
+                view.extent.height() > section.bottom() + EPSILON
+                    || view.extent.top() < section.top() - EPSILON
+            } else {
+                view.extent.width() > section.right() + EPSILON
+                    || view.extent.left() < section.left() - EPSILON
+            };
+            if scrolls {
+                scroller = Some(section);
+                break;
+            }
+        }
+        at = stem(id);
+    }
+    let Some(scroller) = scroller else {
+        return Position::default();
+    };
+    let (near, size, hold, limit) = if top {
+        (
+            screen.top(),
+            screen.height(),
+            scroller.top() + sticky.offset,
+            container.bottom(),
+        )
+    } else {
+        (
+            screen.left(),
+            screen.width(),
+            scroller.left() + sticky.offset,
+            container.right(),
+        )
+    };
+    // Never above where the layout put it, and never out past the parent's far edge.
+    let shift = (hold.min(limit - size) - near).max(0.0);
+    if top {
+        Position::new((0.0, shift))
+    } else {
+        Position::new((shift, 0.0))
+    }
+}
 /// Splits a movement into the part `entity` will take and the part it turns away.
 ///
 /// Two filters, and they dispose of what they stop differently. [`DirectionalLock`] runs first and
@@ -475,7 +952,7 @@ fn ovrscrl(
     }
     let mut view = views.get_mut(entity).unwrap();
     view.offset += applied;
-    let section = *sections.get(entity).unwrap().1;
+    let section = view.on_screen(*sections.get(entity).unwrap().1);
     let over_right = section.right() + view.offset.left();
     if over_right > view.extent.width() {
         let val = view.extent.width() - section.right();
@@ -510,6 +987,9 @@ fn ovrscrl(
 }
 /// Recomputes each view's scrollable extent from its children and clamps `offset` to it.
 ///
+/// In screen px, as `offset` is: a view's own box and its children's are each taken through
+/// the zooms they are drawn under before they are compared.
+///
 /// TODO: this walks the whole tree twice every frame regardless of what changed. Both
 /// `contexts.iter()` and `sections.iter()` are full scans, filtered afterwards by
 /// `is_changed()` -- so the cost is paid on an idle frame the same as a busy one, and it
@@ -578,7 +1058,10 @@ pub(crate) fn extent_check(
         return;
     }
     for entity in to_check.iter() {
-        let section = *sections.get(*entity).unwrap().1;
+        let section = views
+            .get(*entity)
+            .unwrap()
+            .on_screen(*sections.get(*entity).unwrap().1);
         let old_extent = views.get(*entity).unwrap().extent;
         let old_offset = views.get(*entity).unwrap().offset;
         views.get_mut(*entity).unwrap().extent =
@@ -618,7 +1101,7 @@ pub(crate) fn extent_check(
                 }
                 if let Ok(mut view) = views.get_mut(id) {
                     if let Ok((_, section)) = sections.get(entity) {
-                        let mut relative = *section;
+                        let mut relative = view.child_on_screen(*section);
                         relative.position += view.offset;
                         if relative.left() < view.extent.left() {
                             view.extent.set_left(relative.left());
@@ -715,14 +1198,12 @@ pub(crate) fn extent_check(
         // content change can't be applied -- then consumed immediately, `ScrollTo` never
         // lingers to fight the next drag/wheel write over which one owns `offset`.
         if let Ok((_, request)) = scroll_requests.get(*entity) {
-            let section = *sections.get(*entity).unwrap().1;
-            let max_x = (view.extent.width() - section.right()).max(0.0);
-            let max_y = (view.extent.height() - section.bottom()).max(0.0);
+            let [(min_x, max_x), (min_y, max_y)] = view.range(*sections.get(*entity).unwrap().1);
             if let Some(x) = request.x {
-                view.offset.set_left(x * max_x);
+                view.offset.set_left(min_x + x * (max_x - min_x));
             }
             if let Some(y) = request.y {
-                view.offset.set_top(y * max_y);
+                view.offset.set_top(min_y + y * (max_y - min_y));
             }
             tracing::trace!(entity = ?entity, request = ?request, after = ?view.offset, "grid::view: applied ScrollTo to offset");
             to_trigger.insert(*entity);
@@ -737,6 +1218,9 @@ pub(crate) fn extent_check(
             // writing its own decaying `ViewAdjustment` into the same `offset` this
             // request just set, once per frame, and the two visibly fight.
             tree.strip::<Coasting>(*entity);
+            // A snap in flight is the same kind of stale authority, easing toward a point this
+            // request has just overruled.
+            tree.strip::<Snapping>(*entity);
         }
     }
     for entity in to_check.iter() {
@@ -822,18 +1306,16 @@ pub(crate) fn extent_check(
         }
     }
     for entity in settled {
-        let section = *sections.get(entity).unwrap().1;
         let view = *views.get(entity).unwrap();
-        let max_x = (view.extent.width() - section.right()).max(0.0);
-        let max_y = (view.extent.height() - section.bottom()).max(0.0);
+        let [(min_x, max_x), (min_y, max_y)] = view.range(*sections.get(entity).unwrap().1);
         let progress = ScrollProgress {
-            x: if max_x > 0.0 {
-                (view.offset.left() / max_x).clamp(0.0, 1.0)
+            x: if max_x > min_x {
+                ((view.offset.left() - min_x) / (max_x - min_x)).clamp(0.0, 1.0)
             } else {
                 0.0
             },
-            y: if max_y > 0.0 {
-                (view.offset.top() / max_y).clamp(0.0, 1.0)
+            y: if max_y > min_y {
+                ((view.offset.top() - min_y) / (max_y - min_y)).clamp(0.0, 1.0)
             } else {
                 0.0
             },
@@ -855,7 +1337,7 @@ pub(crate) struct ScrolledViews(pub(crate) HashSet<Entity>);
 ///
 /// Top-down, because [`View::accumulated_offset`] is built up on the way: each level hands
 /// its children its own accumulated total, and a nested view adds its own `offset` to what
-/// it was handed before passing it on. The subtraction itself is the whole of the work per
+/// it was handed before passing it on. The placement itself is the whole of the work per
 /// entity -- no `Location` is resolved, no `LayoutSection` is written, and nothing here
 /// re-enters the layout solver.
 ///
@@ -875,6 +1357,7 @@ pub(crate) struct ScrolledViews(pub(crate) HashSet<Entity>);
 /// `DiffMarkers::Prepare` without that edge would let it run first and trail a frame.
 /// The third consumer, `Panel::update_from_section`, recomputes corner radii from the box's
 /// *size*, which a scroll never changes -- not firing it is the correct outcome, not a gap.
+/// Nor does a zoom, which scales the box only as it is drawn.
 pub(crate) fn propagate_offsets(
     mut scrolled: ResMut<ScrolledViews>,
     stems: Query<&Parent>,
@@ -885,6 +1368,7 @@ pub(crate) fn propagate_offsets(
     anchor_deps: Query<&AnchorDeps>,
     mut sections: Query<&mut Section<Logical>>,
     mut points: Query<&mut Points<Logical>>,
+    mut stickies: Query<(&Sticky, &mut Stuck)>,
     mut clips: Query<&mut ResolvedClip>,
     mut inherited_clips: Query<&mut InheritedClip>,
     marked: Query<&ClipToViewport>,
@@ -903,12 +1387,13 @@ pub(crate) fn propagate_offsets(
             .get(root)
             .ok()
             .and_then(|s| s.id)
-            .and_then(|p| views.get(p).ok().map(|v| v.accumulated_offset))
+            .and_then(|p| views.get(p).ok().copied())
             .unwrap_or_default();
+        let origin = layouts.get(root).map(|l| l.0.position).unwrap_or_default();
         let accumulated = {
             let mut view = views.get_mut(root).unwrap();
-            view.accumulated_offset = inherited + view.snapped_offset;
-            view.accumulated_offset
+            view.accumulate(&inherited, origin);
+            *view
         };
         // The root's own box does not move when the root scrolls -- only what is inside it --
         // so its clip is already right, and what descends is the base its children clip
@@ -928,9 +1413,25 @@ pub(crate) fn propagate_offsets(
             for child in branch.ids.iter().copied() {
                 touched.insert(child);
                 let mut moved = None;
+                let mut shift = Position::default();
                 if let Ok(layout) = layouts.get(child) {
-                    let mut section = layout.0;
-                    section.position -= accumulated;
+                    let mut section = accumulated.place(layout.0);
+                    if let Ok((sticky, mut current)) = stickies.get_mut(child) {
+                        shift = stuck(
+                            child,
+                            accumulated.child_on_screen(section),
+                            *sticky,
+                            |e| stems.get(e).ok().and_then(|s| s.id),
+                            |e| {
+                                let view = *views.get(e).ok()?;
+                                Some((view, view.on_screen(*sections.get(e).ok()?)))
+                            },
+                        );
+                        section.position += shift / accumulated.accumulated_zoom;
+                        if current.0 != shift {
+                            current.0 = shift;
+                        }
+                    }
                     if let Ok(mut current) = sections.get_mut(child) {
                         *current = section;
                     }
@@ -940,7 +1441,7 @@ pub(crate) fn propagate_offsets(
                     if resolution.from_points {
                         let mut translated = resolution.points;
                         for pt in translated.data.iter_mut() {
-                            *pt -= accumulated;
+                            *pt = accumulated.place_point(*pt);
                         }
                         if let Ok(mut current) = points.get_mut(child) {
                             *current = translated;
@@ -970,10 +1471,14 @@ pub(crate) fn propagate_offsets(
                     base
                 };
                 let inherited = if let Ok(mut view) = views.get_mut(child) {
-                    view.accumulated_offset = accumulated + view.snapped_offset;
-                    view.accumulated_offset
+                    let origin = layouts.get(child).map(|l| l.0.position).unwrap_or_default();
+                    view.accumulate(&accumulated, origin);
+                    view.hold(shift);
+                    *view
                 } else {
-                    accumulated
+                    let mut passed = accumulated;
+                    passed.hold(shift);
+                    passed
                 };
                 stack.push((child, inherited, child_base));
             }
@@ -0,0 +1,222 @@
+use crate::boundary::bloom::Emissions;
+use crate::boundary::leaf::Grown;
+use crate::{
+    Bare, Bloom, Component, CoordinateUnit, Elevation, Grid, GridExt, LayoutSection, Leaf,
+    Location, Sprout, Tree, View, Visibility,
+};
+use bevy_ecs::change_detection::DetectChanges;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::system::{Query, ResMut};
+use bevy_ecs::world::{Mut, Ref};
+
+/// A view's content as `count` rows of one fixed height, of which only those on screen exist.
+///
+/// Given to a view with [`Grows::virtualize`](crate::Grows::virtualize). Foliage grows a row
+/// container for each row in sight, plus [`overscan`](Self::overscan) either side, and hands
+/// it over twice: once as [`Bloom::RowGrown`] when the container is new, for the app to build
+/// the row's contents under it, and as [`Bloom::RowBound`] each time it is given an index to
+/// show, for the app to fill them in. A row scrolled out of range is hidden and kept, and the
+/// next row scrolled into range is the same container bound again -- so scrolling ten thousand
+/// rows grows about a screenful of them, once.
+///
+/// The build is an emission rather than a callback because nothing crosses the boundary but
+/// plain data: the app answers `RowGrown` with the same `branch` calls it grows anything else
+/// with, in its own frame, and the container is a [`Leaf`] like any other.
+///
+/// With [`columns`](Self::columns) above one the rows are split into that many cells, and
+/// each cell is one index: a virtualized grid, bound left to right, top to bottom.
+#[derive(Component, Copy, Clone, Debug, PartialEq)]
+pub struct VirtualList {
+    /// How many indices there are.
+    pub count: usize,
+    /// Every row's height, in logical px.
+    pub extent: CoordinateUnit,
+    /// Rows kept bound beyond each edge of the view, so a fast scroll lands on rows that are
+    /// already filled in rather than ones whose contents arrive a frame later.
+    pub overscan: usize,
+    /// Cells per row.
+    pub columns: usize,
+}
+impl VirtualList {
+    /// `count` rows of `extent` logical px, with two rows of overscan.
+    pub fn new(count: usize, extent: CoordinateUnit) -> Self {
+        Self {
+            count,
+            extent,
+            overscan: 2,
+            columns: 1,
+        }
+    }
+    pub fn overscan(mut self, rows: usize) -> Self {
+        self.overscan = rows;
+        self
+    }
+    /// Splits each row into `columns` cells of equal width.
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns.max(1);
+        self
+    }
+}
+
+/// What a virtualized view has grown: the element that gives it its scrollable extent, and
+/// each row container with the index it is showing -- `None` for one in the pool.
+///
+/// The extent is a sizer rather than the rows themselves, because a view's extent is grown
+/// from its children and the rows that would reach the bottom of the list do not exist.
+#[derive(Component)]
+pub(crate) struct VirtualRows {
+    sizer: Entity,
+    rows: Vec<(Entity, Option<usize>)>,
+}
+
+/// The sizer's box: the full height of every row, at the list's left edge.
+fn sized(list: &VirtualList) -> Location {
+    let rows = list.count.div_ceil(list.columns.max(1));
+    Location::new().xs(
+        0.px().as_left().with(0.px().as_width()),
+        0.px()
+            .as_top()
+            .with((rows as CoordinateUnit * list.extent).px().as_height()),
+    )
+}
+
+/// Where index `index` sits.
+fn placed(list: &VirtualList, index: usize) -> Location {
+    let columns = list.columns.max(1);
+    let (row, column) = (index / columns, index % columns);
+    let share = 100.0 / columns as CoordinateUnit;
+    Location::new().xs(
+        (column as CoordinateUnit * share)
+            .pct()
+            .as_left()
+            .with(((column + 1) as CoordinateUnit * share).pct().as_right()),
+        (row as CoordinateUnit * list.extent)
+            .px()
+            .as_top()
+            .with(list.extent.px().as_height()),
+    )
+}
+
+/// Binds each virtualized view's rows to the indices in sight, growing containers only when
+/// the pool runs dry.
+///
+/// Reads the offset the view was left at last frame. The overscan is what covers that: a row
+/// coming into view this frame was already bound while it was still a row's height away.
+///
+/// Writing the [`VirtualList`] again -- a new count, or the same one after the data behind
+/// it moved -- rebinds every row in sight, since which item each index names may be all that
+/// changed.
+pub(crate) fn recycle(
+    mut lists: Query<(
+        Entity,
+        Ref<VirtualList>,
+        Option<&mut VirtualRows>,
+        &View,
+        &LayoutSection,
+    )>,
+    mut emissions: ResMut<Emissions>,
+    mut tree: Tree,
+) {
+    for (this, list, rows, view, section) in lists.iter_mut() {
+        let Some(mut rows) = rows else {
+            // The first frame only grows the sizer; binding starts on the next, with a view
+            // that has resolved a box to bind against.
+            let sizer = tree.branch(this, Bare::new().at(sized(&list)).elevate(Elevation::up(0)));
+            tree.write_to(
+                this,
+                VirtualRows {
+                    sizer,
+                    rows: Vec::new(),
+                },
+            );
+            continue;
+        };
+        let rebind = list.is_changed() && !list.is_added();
+        if rebind {
+            tree.write_to(rows.sizer, sized(&list));
+        }
+        bind(
+            this,
+            &list,
+            &mut rows,
+            view,
+            section,
+            rebind,
+            &mut emissions,
+            &mut tree,
+        );
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn bind(
+    this: Entity,
+    list: &VirtualList,
+    rows: &mut Mut<VirtualRows>,
+    view: &View,
+    section: &LayoutSection,
+    rebind: bool,
+    emissions: &mut Emissions,
+    tree: &mut Tree,
+) {
+    if list.extent <= 0.0 {
+        return;
+    }
+    let columns = list.columns.max(1);
+    let top = view.offset().top().max(0.0);
+    let first_row = ((top / list.extent).floor() as usize).saturating_sub(list.overscan);
+    let last_row = ((top + section.0.height()) / list.extent).ceil() as usize + list.overscan;
+    let wanted = (first_row * columns).min(list.count)..(last_row * columns).min(list.count);
+    let mut missing = wanted.clone().collect::<std::collections::BTreeSet<_>>();
+    for (row, index) in rows.rows.iter_mut() {
+        match *index {
+            Some(i) if wanted.contains(&i) => {
+                missing.remove(&i);
+                if rebind {
+                    emissions.push(Bloom::RowBound {
+                        list: Leaf(this),
+                        row: Leaf(*row),
+                        index: i,
+                    });
+                }
+            }
+            Some(_) => {
+                *index = None;
+                tree.write_to(*row, Visibility::new(false));
+            }
+            None => {}
+        }
+    }
+    for i in missing {
+        let pooled = rows.rows.iter().position(|(_, index)| index.is_none());
+        let row = match pooled {
+            Some(at) => {
+                let row = rows.rows[at].0;
+                rows.rows[at].1 = Some(i);
+                tree.write_to(row, (placed(list, i), Visibility::new(true)));
+                row
+            }
+            None => {
+                let row = tree.branch(
+                    this,
+                    Bare::new()
+                        .grid(Grid::default())
+                        .at(placed(list, i))
+                        .elevate(Elevation::up(1)),
+                );
+                tree.write_to(row, Grown);
+                rows.rows.push((row, Some(i)));
+                emissions.push(Bloom::RowGrown {
+                    list: Leaf(this),
+                    row: Leaf(row),
+                });
+                row
+            }
+        };
+        emissions.push(Bloom::RowBound {
+            list: Leaf(this),
+            row: Leaf(row),
+            index: i,
+        });
+    }
+}
@@ -0,0 +1,248 @@
+use crate::ash::clip::ClipToViewport;
+use crate::grid::view::ScrolledViews;
+use crate::{
+    Children, Component, CoordinateUnit, Grid, LayoutSection, Logical, Parent, Position,
+    ResolvedVisibility, Section, Tree, View,
+};
+use bevy_ecs::change_detection::DetectChanges;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::message::{Message, MessageReader};
+use bevy_ecs::prelude::{With, Without};
+use bevy_ecs::system::{Query, Res, ResMut};
+use bevy_ecs::world::{Ref, World};
+
+/// Makes a view a canvas that magnifies as well as pans: its children are laid out at their
+/// own sizes, in content space, and drawn scaled about the view's corner -- given with
+/// [`Author::zoomable`](crate::Sprout::zoomable).
+///
+/// The scale is drawn, not laid out: the children keep the boxes their layout gave them, and
+/// the zoom is composed into what each is drawn through, as a [`Transform`](crate::Transform)
+/// is. Text, icons, strokes and corners all grow with the boxes they sit in, a press is taken
+/// back through the scale to the box it lands on, and what the view holds is clipped to it
+/// as drawn. Nothing is laid out again as it zooms.
+///
+/// It is a [`View`] like any other for panning -- drag, wheel and coast move it, clamped to
+/// its content as it is currently scaled. Ctrl with the wheel, a trackpad pinch, or two
+/// fingers on a touch screen zoom it about the point between them, within
+/// [`min`](Self::min)..=[`max`](Self::max). [`Grows::zoom`](crate::Grows::zoom) sets a scale
+/// or fits the content, and [`Sap::Zoom`](crate::Sap::Zoom) reads it back.
+#[derive(Component, Copy, Clone, Debug, PartialEq)]
+#[require(Grid)]
+pub struct Zoom {
+    pub min: f32,
+    pub max: f32,
+}
+impl Zoom {
+    pub fn new(min: f32, max: f32) -> Self {
+        Self {
+            min: min.max(f32::EPSILON),
+            max: max.max(min),
+        }
+    }
+}
+impl Default for Zoom {
+    /// A quarter to four times.
+    fn default() -> Self {
+        Self::new(0.25, 4.0)
+    }
+}
+
+/// Where [`Grows::zoom`](crate::Grows::zoom) takes a [`Zoom`] view.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ZoomTo {
+    /// This scale, about the view's middle.
+    Scale(f32),
+    /// The largest scale at which all of its content fits, brought into sight.
+    Fit,
+}
+
+/// One-shot request carrying a [`ZoomTo`], consumed by [`zoom`] the frame it lands.
+#[derive(Component, Copy, Clone, Debug)]
+pub(crate) struct ZoomRequest(pub(crate) ZoomTo);
+
+/// A platform's pinch, reduced to what a zoom needs: where, and by how much.
+#[derive(Message, Copy, Clone, Debug)]
+pub(crate) struct ZoomInput {
+    pub(crate) at: Position<Logical>,
+    pub(crate) factor: f32,
+}
+
+/// Applies the frame's zoom input and [`ZoomTo`] requests, and holds every [`Zoom`] view
+/// inside its limits when they change.
+///
+/// Ahead of `extent_check`, and writing `offset` itself rather than through a
+/// `ViewAdjustment`: the offset that keeps the point under the pointer still is only in range
+/// of the extent at the *new* scale, and the extent on hand is the old one. The view goes to
+/// `propagate_offsets` to be re-placed; its children's `Section`s move with it, which is what
+/// regrows the extent on the next pass, and that pass's clamp settles the offset against it.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub(crate) fn zoom(
+    mut inputs: MessageReader<ZoomInput>,
+    requests: Query<(Entity, &ZoomRequest)>,
+    mut zooms: Query<(
+        Entity,
+        Ref<Zoom>,
+        &Section<Logical>,
+        &ResolvedVisibility,
+        &mut View,
+    )>,
+    stems: Query<&Parent>,
+    branches: Query<&Children>,
+    contents: Query<(&LayoutSection, &ResolvedVisibility), Without<ClipToViewport>>,
+    layouts: Query<&LayoutSection>,
+    mut scrolled: ResMut<ScrolledViews>,
+    scale_factor: Res<crate::ginkgo::ScaleFactor>,
+    mut tree: Tree,
+) {
+    let sf = scale_factor.value();
+    let depth = |mut e: Entity| {
+        let mut d = 0;
+        while let Some(p) = stems.get(e).ok().and_then(|s| s.id) {
+            e = p;
+            d += 1;
+        }
+        d
+    };
+    for input in inputs.read() {
+        if !input.factor.is_finite() || input.factor <= 0.0 {
+            continue;
+        }
+        let under = zooms
+            .iter()
+            .filter(|(_, _, section, visibility, view)| {
+                visibility.visible() && view.on_screen(**section).contains(input.at)
+            })
+            .map(|(entity, ..)| entity)
+            .max_by_key(|entity| depth(*entity));
+        let Some((entity, limits, section, _, mut view)) =
+            under.and_then(|e| zooms.get_mut(e).ok())
+        else {
+            continue;
+        };
+        let section = view.on_screen(*section);
+        let to = view.zoom * input.factor;
+        if settle(&mut view, &limits, section, to, input.at, None, sf) {
+            scrolled.0.insert(entity);
+        }
+    }
+    for (entity, request) in requests.iter() {
+        tree.strip::<ZoomRequest>(entity);
+        let Ok((_, limits, section, _, mut view)) = zooms.get_mut(entity) else {
+            continue;
+        };
+        let section = view.on_screen(*section);
+        let settled = match request.0 {
+            ZoomTo::Scale(to) => {
+                settle(&mut view, &limits, section, to, section.center(), None, sf)
+            }
+            ZoomTo::Fit => {
+                let Ok(origin) = layouts.get(entity).map(|l| l.0.position) else {
+                    continue;
+                };
+                // Content space, from the view's own corner -- the same children the extent
+                // is grown from.
+                let bounds = branches.get(entity).ok().and_then(|b| {
+                    b.ids
+                        .iter()
+                        .filter_map(|c| contents.get(*c).ok())
+                        .filter(|(_, v)| v.user_visible())
+                        .map(|(l, _)| (l.0.left(), l.0.top(), l.0.right(), l.0.bottom()))
+                        .reduce(|a, b| (a.0.min(b.0), a.1.min(b.1), a.2.max(b.2), a.3.max(b.3)))
+                });
+                let Some((left, top, right, bottom)) = bounds else {
+                    continue;
+                };
+                let (width, height) = (right - left, bottom - top);
+                let (left, top) = (left - origin.left(), top - origin.top());
+                if width <= 0.0 || height <= 0.0 {
+                    continue;
+                }
+                // What the views above already scale this one's content by.
+                let within = view.accumulated_zoom / view.zoom;
+                let to = (section.width() / (width * within))
+                    .min(section.height() / (height * within))
+                    .clamp(limits.min, limits.max);
+                let scale = to * within;
+                let centered = Position::logical((
+                    (left + width / 2.0) * scale - section.width() / 2.0,
+                    (top + height / 2.0) * scale - section.height() / 2.0,
+                ));
+                settle(
+                    &mut view,
+                    &limits,
+                    section,
+                    to,
+                    section.center(),
+                    Some(centered),
+                    sf,
+                )
+            }
+        };
+        if settled {
+            scrolled.0.insert(entity);
+        }
+    }
+    for (entity, limits, section, _, mut view) in zooms.iter_mut() {
+        if !limits.is_changed() {
+            continue;
+        }
+        let section = view.on_screen(*section);
+        let to = view.zoom;
+        if settle(&mut view, &limits, section, to, section.center(), None, sf) {
+            scrolled.0.insert(entity);
+        }
+    }
+}
+
+/// Takes `view` to `to`, within `limits`, keeping the content under `around` where it is --
+/// or to `offset` outright, when the caller has already worked out where it should sit.
+/// `section` is the view's box on screen. Whether anything moved.
+fn settle(
+    view: &mut View,
+    limits: &Zoom,
+    section: Section<Logical>,
+    to: f32,
+    around: Position<Logical>,
+    offset: Option<Position<Logical>>,
+    sf: f32,
+) -> bool {
+    let to = to.clamp(limits.min, limits.max);
+    if to == view.zoom && offset.is_none() {
+        return false;
+    }
+    // The content under `around` stays under it: its distance from the view's corner, offset
+    // included, grows by the same ratio the zoom does.
+    let from_corner = around - section.position;
+    view.offset = offset.unwrap_or((from_corner + view.offset) * (to / view.zoom) - from_corner);
+    view.snapped_offset = view.offset.to_physical(sf).rounded().to_logical(sf);
+    // Kept in step until `propagate_offsets` rebuilds it, so the view's own box -- which only
+    // the zooms above it scale -- reads the same on screen in between.
+    view.accumulated_zoom *= to / view.zoom;
+    view.zoom = to;
+    true
+}
+
+/// Whether a visible [`Zoom`] view is under `at` on screen -- what decides that Ctrl with the
+/// wheel zooms rather than scrolls.
+pub(crate) fn zoomable_at(world: &mut World, at: Position<Logical>) -> bool {
+    world
+        .query_filtered::<(&Section<Logical>, &ResolvedVisibility, &View), With<Zoom>>()
+        .iter(world)
+        .any(|(section, visibility, view)| {
+            visibility.visible() && view.on_screen(*section).contains(at)
+        })
+}
+
+/// The scale between two touches, and between where they were: how much a pinch has opened
+/// or closed since it last moved.
+pub(crate) fn spread(
+    a: Position<Logical>,
+    b: Position<Logical>,
+    was_a: Position<Logical>,
+    was_b: Position<Logical>,
+) -> Option<CoordinateUnit> {
+    let distance =
+        |p: Position<Logical>, q: Position<Logical>| (p.left() - q.left()).hypot(p.top() - q.top());
+    let before = distance(was_a, was_b);
+    (before > 0.0).then(|| distance(a, b) / before)
+}
@@ -14,6 +14,8 @@ struct Vertex {
     @location(3) color: vec4<f32>,
     @location(4) screen_px_range: f32,
     @location(5) opacity: f32,
+    @location(6) linear: vec4<f32>,
+    @location(7) translation: vec2<f32>,
 };
 struct Fragment {
     @builtin(position) position: vec4<f32>,
@@ -23,7 +25,8 @@ struct Fragment {
 };
 @vertex
 fn vertex_entry(vertex: Vertex) -> Fragment {
-    let position = vec4<f32>(vertex.section.xy + vertex.section.zw * vertex.vertex_pos, vertex.layer, 1.0);
+    let local = vertex.section.xy + vertex.section.zw * vertex.vertex_pos;
+    let position = vec4<f32>(transformed(local, vertex.linear, vertex.translation), vertex.layer, 1.0);
     return Fragment(
         viewport * position,
         vertex.color * vec4f(1.0, 1.0, 1.0, vertex.opacity),
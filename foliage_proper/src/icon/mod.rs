@@ -8,6 +8,7 @@ use crate::foliage::DiffMarkers;
 use crate::grid::AspectRatio;
 use crate::opacity::BlendedOpacity;
 use crate::remove::Remove;
+use crate::transform::ResolvedTransform;
 use crate::{
     AssetKey, Attachment, Author, Color, Component, Differential, Foliage, LeafSprout, Logical,
     ResolvedElevation, ResolvedVisibility, Section, Tree, Visibility,
@@ -36,6 +37,7 @@ pub struct IconValue(pub IconId);
 #[require(Differential<Icon, Icon>)]
 #[require(Differential<Icon, ResolvedElevation>)]
 #[require(Differential<Icon, BlendedOpacity>)]
+#[require(Differential<Icon, ResolvedTransform>)]
 /// A vector glyph drawn from a signed-distance field, so it stays sharp at any size and
 /// takes a [`Color`] like text does.
 ///
@@ -58,6 +60,7 @@ impl Attachment for Icon {
         foliage.differential::<Icon, ResolvedElevation>();
         foliage.differential::<Icon, Color>();
         foliage.differential::<Icon, BlendedOpacity>();
+        foliage.differential::<Icon, ResolvedTransform>();
         foliage
             .diff
             .add_systems(Icon::resend_attributes_on_group_change.in_set(DiffMarkers::Extract));
@@ -165,6 +168,7 @@ impl Icon {
                 &ClipContext,
                 &Color,
                 &BlendedOpacity,
+                &ResolvedTransform,
                 &ResolvedVisibility,
             ),
             Changed<Icon>,
@@ -174,8 +178,11 @@ impl Icon {
         mut clips: ResMut<RenderQueue<Icon, ClipContext>>,
         mut colors: ResMut<RenderQueue<Icon, Color>>,
         mut opacities: ResMut<RenderQueue<Icon, BlendedOpacity>>,
+        mut transforms: ResMut<RenderQueue<Icon, ResolvedTransform>>,
     ) {
-        for (entity, section, elevation, clip, color, opacity, visibility) in changed.iter() {
+        for (entity, section, elevation, clip, color, opacity, transform, visibility) in
+            changed.iter()
+        {
             // Requiring `&ResolvedVisibility` in the query rather than taking it as an `Option`
             // is deliberate: an entity that has not resolved one yet is skipped here exactly as
             // `cached_differential` skips it, so the two cannot disagree about who is queued.
@@ -187,6 +194,7 @@ impl Icon {
             clips.queue.insert(entity, *clip);
             colors.queue.insert(entity, *color);
             opacities.queue.insert(entity, *opacity);
+            transforms.queue.insert(entity, *transform);
         }
     }
 }
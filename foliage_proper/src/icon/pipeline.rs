@@ -6,6 +6,7 @@ use crate::ash::render::{GroupId, Parameters, PipelineId, Render, RenderGroup, R
 use crate::ginkgo::Ginkgo;
 use crate::icon::Icon;
 use crate::opacity::BlendedOpacity;
+use crate::transform::{CReprTransform, ResolvedTransform};
 use crate::{
     CReprColor, CReprSection, Color, Coordinates, IconMemory, Logical, Parent, ResolvedElevation,
     Section,
@@ -15,8 +16,9 @@ use bytemuck::{Pod, Zeroable};
 use std::collections::HashMap;
 use wgpu::{
     BindGroup, BindGroupDescriptor, BindGroupLayout, BindGroupLayoutDescriptor,
-    PipelineLayoutDescriptor, RenderPass, RenderPipelineDescriptor, ShaderStages, TextureFormat,
-    TextureSampleType, TextureViewDimension, VertexState, VertexStepMode, include_wgsl,
+    PipelineLayoutDescriptor, RenderPass, RenderPipelineDescriptor, ShaderModuleDescriptor,
+    ShaderSource, ShaderStages, TextureFormat, TextureSampleType, TextureViewDimension,
+    VertexState, VertexStepMode,
 };
 
 pub(crate) struct Resources {
@@ -30,6 +32,7 @@ pub(crate) struct Group {
     colors: InstanceBuffer<CReprColor>,
     px_ranges: InstanceBuffer<ScreenPxRange>,
     opacities: InstanceBuffer<BlendedOpacity>,
+    transforms: InstanceBuffer<CReprTransform>,
     /// The MTSDF field resolution and its baked distance spread -- together they convert an
     /// instance's on-screen pixel size into the shader's `screen_px_range` (how many screen
     /// pixels the field's distance range covers at this size), which sets the smoothstep width.
@@ -47,7 +50,17 @@ impl Render for Icon {
     type Resources = Resources;
 
     fn renderer(ginkgo: &Ginkgo) -> Renderer<Self> {
-        let shader = ginkgo.create_shader(include_wgsl!("icon.wgsl"));
+        let shader = ginkgo.create_shader(ShaderModuleDescriptor {
+            label: Some("icon-shader"),
+            source: ShaderSource::Wgsl(
+                format!(
+                    "{}{}",
+                    include_str!("../transform.wgsl"),
+                    include_str!("icon.wgsl")
+                )
+                .into(),
+            ),
+        });
         let vertex_buffer = ginkgo.create_vertex_buffer(VERTICES);
         let bind_group_layout = ginkgo.create_bind_group_layout(&BindGroupLayoutDescriptor {
             label: Some("icon-bind-group-layout"),
@@ -115,6 +128,10 @@ impl Render for Icon {
                         VertexStepMode::Instance,
                         &wgpu::vertex_attr_array![5 => Float32],
                     ),
+                    Ginkgo::vertex_buffer_layout::<CReprTransform>(
+                        VertexStepMode::Instance,
+                        &wgpu::vertex_attr_array![6 => Float32x4, 7 => Float32x2],
+                    ),
                 ],
             },
             primitive: Ginkgo::triangle_list_primitive(),
@@ -160,6 +177,7 @@ impl Render for Icon {
                 queues.remove_attr::<Icon, ClipContext>(entity);
                 queues.remove_attr::<Icon, Color>(entity);
                 queues.remove_attr::<Icon, BlendedOpacity>(entity);
+                queues.remove_attr::<Icon, ResolvedTransform>(entity);
             }
         }
         for (_, mem) in queues.attribute::<Icon, IconMemory>() {
@@ -180,6 +198,7 @@ impl Render for Icon {
                 colors: InstanceBuffer::new(ginkgo, 1),
                 px_ranges: InstanceBuffer::new(ginkgo, 1),
                 opacities: InstanceBuffer::new(ginkgo, 1),
+                transforms: InstanceBuffer::new(ginkgo, 1),
                 field_size: mem.field_size as f32,
                 px_range: mem.px_range,
             };
@@ -265,6 +284,13 @@ impl Render for Icon {
             let group = renderer.groups.get_mut(gid).unwrap();
             group.group.opacities.queue(id, opacity);
         }
+        for (entity, transform) in queues.attribute::<Icon, ResolvedTransform>() {
+            let gid = renderer.resources.entity_to_group.get(&entity).unwrap();
+            let id = entity.to_bits();
+            let group = renderer.groups.get_mut(gid).unwrap();
+            let sf = ginkgo.configuration().scale_factor.value();
+            group.group.transforms.queue(id, transform.c_repr(sf));
+        }
         for (gid, group) in renderer.groups.iter_mut() {
             if let Some(n) = group.coordinator.grown() {
                 group.group.sections.grow(ginkgo, n);
@@ -272,6 +298,7 @@ impl Render for Icon {
                 group.group.colors.grow(ginkgo, n);
                 group.group.px_ranges.grow(ginkgo, n);
                 group.group.opacities.grow(ginkgo, n);
+                group.group.transforms.grow(ginkgo, n);
             }
             for swap in group.coordinator.sort() {
                 group.group.sections.swap(swap);
@@ -279,6 +306,7 @@ impl Render for Icon {
                 group.group.colors.swap(swap);
                 group.group.px_ranges.swap(swap);
                 group.group.opacities.swap(swap);
+                group.group.transforms.swap(swap);
             }
             for (id, data) in group.group.sections.queued() {
                 let order = group.coordinator.order(id);
@@ -300,11 +328,16 @@ impl Render for Icon {
                 let order = group.coordinator.order(id);
                 group.group.opacities.write_cpu(order, data);
             }
+            for (id, data) in group.group.transforms.queued() {
+                let order = group.coordinator.order(id);
+                group.group.transforms.write_cpu(order, data);
+            }
             group.group.sections.write_gpu(ginkgo);
             group.group.elevations.write_gpu(ginkgo);
             group.group.colors.write_gpu(ginkgo);
             group.group.px_ranges.write_gpu(ginkgo);
             group.group.opacities.write_gpu(ginkgo);
+            group.group.transforms.write_gpu(ginkgo);
             for node in group.coordinator.updated_nodes(PipelineId::Icon, *gid) {
                 nodes.update(node);
             }
@@ -323,6 +356,7 @@ impl Render for Icon {
         render_pass.set_vertex_buffer(3, group.group.colors.buffer.slice(..));
         render_pass.set_vertex_buffer(4, group.group.px_ranges.buffer.slice(..));
         render_pass.set_vertex_buffer(5, group.group.opacities.buffer.slice(..));
+        render_pass.set_vertex_buffer(6, group.group.transforms.buffer.slice(..));
         render_pass.draw(0..VERTICES.len() as u32, parameters.range);
     }
 }
@@ -15,6 +15,8 @@ struct Vertex{
     @location(4) tx_coords: vec4<f32>,
     @location(5) opacity: f32,
     @location(6) radii: vec4<f32>,
+    @location(7) linear: vec4<f32>,
+    @location(8) translation: vec2<f32>,
 };
 struct Fragment {
     @builtin(position) position: vec4<f32>,
@@ -22,12 +24,14 @@ struct Fragment {
     @location(1) opacity: f32,
     @location(2) @interpolate(flat) section: vec4<f32>,
     @location(3) @interpolate(flat) radii: vec4<f32>,
+    @location(4) local: vec2<f32>,
 };
 @vertex
 fn vertex_entry(vertex: Vertex) -> Fragment {
-    let position = vec4<f32>(vertex.section.xy + vertex.vertex_pos * vertex.section.zw, vertex.layer, 1.0);
+    let local = vertex.section.xy + vertex.vertex_pos * vertex.section.zw;
+    let position = vec4<f32>(transformed(local, vertex.linear, vertex.translation), vertex.layer, 1.0);
     let tx_coords = vec2<f32>(vertex.tx_coords[vertex.tx_index.x], vertex.tx_coords[vertex.tx_index.y]);
-    return Fragment(viewport * position, tx_coords, vertex.opacity, vertex.section, vertex.radii);
+    return Fragment(viewport * position, tx_coords, vertex.opacity, vertex.section, vertex.radii, local);
 }
 @fragment
 fn fragment_entry(frag: Fragment) -> @location(0) vec4<f32> {
@@ -35,7 +39,7 @@ fn fragment_entry(frag: Fragment) -> @location(0) vec4<f32> {
     // cropping the sample, so a `Crop` view stays full-bleed right up to the curve.
     let half_extent = frag.section.zw * 0.5;
     let d = sd_rounded_box(
-        frag.position.xy - (frag.section.xy + half_extent),
+        frag.local - (frag.section.xy + half_extent),
         half_extent,
         frag.radii
     );
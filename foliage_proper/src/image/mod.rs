@@ -10,12 +10,14 @@ use crate::asset::{AssetLoader, OnRetrieval};
 use crate::foliage::DiffMarkers;
 use crate::ginkgo::ScaleFactor;
 use crate::grid::AspectRatio;
+use crate::interaction::listener::AlphaMask;
 use crate::opacity::BlendedOpacity;
 use crate::remove::Remove;
 use crate::rounding::CornerRadii;
+use crate::transform::ResolvedTransform;
 use crate::{
-    Area, Attachment, Author, Component, Foliage, Layout, LeafSprout, Logical, Numerical, Parent,
-    Resolved, ResolvedElevation, ResolvedVisibility, Rounding, Section, Side,
+    Area, Attachment, Author, Component, Foliage, InteractionShape, Layout, LeafSprout, Logical,
+    Numerical, Parent, Resolved, ResolvedElevation, ResolvedVisibility, Rounding, Section, Side,
 };
 use crate::{Differential, Tree, Visibility};
 use bevy_ecs::bundle::Bundle;
@@ -39,6 +41,7 @@ use wgpu::TextureFormat;
 #[require(Differential<Image, ClipContext>)]
 #[require(CropAdjustment, Differential<Image, CropAdjustment>)]
 #[require(CornerRadii, Differential<Image, CornerRadii>)]
+#[require(Differential<Image, ResolvedTransform>)]
 pub struct Image {
     pub key: AssetKey,
 }
@@ -98,6 +101,7 @@ impl Attachment for Image {
         foliage.differential::<Image, ResolvedElevation>();
         foliage.differential::<Image, CropAdjustment>();
         foliage.differential::<Image, CornerRadii>();
+        foliage.differential::<Image, ResolvedTransform>();
     }
 }
 impl Image {
@@ -112,6 +116,7 @@ impl Image {
             view: None,
             rounding: None,
             side: None,
+            alpha_hit: false,
         }
     }
     pub(crate) fn new_marker(key: AssetKey) -> Self {
@@ -146,6 +151,7 @@ impl Image {
             tree.refire::<(Parent,)>(this);
             tree.refire::<(BlendedOpacity,)>(this);
             tree.refire::<(CornerRadii,)>(this);
+            tree.refire::<(ResolvedTransform,)>(this);
         }
     }
     fn on_add(mut world: DeferredWorld, ctx: HookContext) {
@@ -176,6 +182,10 @@ impl Image {
             .into_rgba8();
         let extent = Area::from((rgba_image.width(), rgba_image.height()));
         world.tree().write_to(this, (ImageMetrics { extent }, view));
+        if world.get::<AlphaHitArea>(this).is_some_and(|alpha| alpha.0) {
+            let mask = AlphaMask::new(rgba_image.width(), rgba_image.height(), rgba_image.as_raw());
+            world.tree().write_to(this, InteractionShape::Alpha(mask));
+        }
         world
             .get_resource_mut::<RenderQueue<Image, ImageWrite>>()
             .unwrap()
@@ -199,6 +209,7 @@ impl Image {
                 &Side,
                 &mut CropAdjustment,
                 &mut CornerRadii,
+                &mut InteractionShape,
             ),
             Or<(
                 Changed<ImageView>,
@@ -214,7 +225,9 @@ impl Image {
         // direct Query mutation, NOT commands: this runs at Finalize and the differential
         // senders run at Extract in the same frame -- crop must ship in the same frame as
         // the Section that caused it, or resize/scroll shows a frame of wrong crop
-        for (view, metrics, section, rounding, side, mut crop, mut radii) in images.iter_mut() {
+        for (view, metrics, section, rounding, side, mut crop, mut radii, mut shape) in
+            images.iter_mut()
+        {
             match view {
                 ImageView::Crop => {
                     let fitted = AspectRatio::new()
@@ -234,6 +247,14 @@ impl Image {
                 }
                 _ => {}
             }
+            // An alpha hit area has to know what part of the image is showing, or a press on a
+            // cropped image is tested against pixels that are not on screen.
+            if let InteractionShape::Alpha(mask) = shape.as_ref()
+                && mask.crop != crop.adjustments
+                && let InteractionShape::Alpha(mask) = shape.as_mut()
+            {
+                mask.crop = crop.adjustments;
+            }
             let resolved = CornerRadii::resolve(
                 // Unrounded, matching what `image/pipeline.rs` hands the shader as the
                 // section -- the radii have to be measured against the same box.
@@ -255,6 +276,7 @@ pub struct ImageSprout {
     view: Option<ImageView>,
     rounding: Option<Rounding>,
     side: Option<Side>,
+    alpha_hit: bool,
 }
 impl Author for ImageSprout {
     fn seed(&mut self) -> &mut LeafSprout {
@@ -266,6 +288,7 @@ impl Author for ImageSprout {
             self.view.unwrap_or_default(),
             self.rounding.unwrap_or_default(),
             self.side.unwrap_or_default(),
+            AlphaHitArea(self.alpha_hit),
         )
     }
 }
@@ -287,7 +310,17 @@ impl ImageSprout {
         self.side = Some(s);
         self
     }
+    /// Takes presses only where the image itself is at least half opaque -- an icon-shaped
+    /// button, a cut-out sticker. Costs a copy of the alpha channel, kept for as long as the
+    /// image lives, so it is asked for rather than assumed.
+    pub fn alpha_hit_area(mut self) -> Self {
+        self.alpha_hit = true;
+        self
+    }
 }
+/// Whether [`ImageSprout::alpha_hit_area`] was asked for, read once the pixels decode.
+#[derive(Component, Copy, Clone, Default)]
+pub(crate) struct AlphaHitArea(bool);
 /// The decoded image's own pixel dimensions, written once the asset resolves. Read it to
 /// size a box to the real image rather than guessing.
 #[derive(Component, Copy, Clone, PartialEq, Default)]
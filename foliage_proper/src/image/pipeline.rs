@@ -8,6 +8,7 @@ use crate::image::{CropAdjustment, Image, ImageWrite};
 use crate::opacity::BlendedOpacity;
 use crate::rounding::CornerRadii;
 use crate::texture::TextureCoordinates;
+use crate::transform::{CReprTransform, ResolvedTransform};
 use crate::{
     Area, AssetKey, CReprSection, Logical, Numerical, Parent, ResolvedElevation, Section, texture,
 };
@@ -55,6 +56,7 @@ pub(crate) struct Group {
     coords: InstanceBuffer<TextureCoordinates>,
     opaque: InstanceBuffer<BlendedOpacity>,
     radii: InstanceBuffer<CornerRadii>,
+    transforms: InstanceBuffer<CReprTransform>,
 }
 impl Render for Image {
     type Group = Group;
@@ -65,8 +67,9 @@ impl Render for Image {
             label: Some("image-shader"),
             source: ShaderSource::Wgsl(
                 format!(
-                    "{}{}",
+                    "{}{}{}",
                     include_str!("../sdf.wgsl"),
+                    include_str!("../transform.wgsl"),
                     include_str!("image.wgsl")
                 )
                 .into(),
@@ -138,6 +141,10 @@ impl Render for Image {
                         VertexStepMode::Instance,
                         &wgpu::vertex_attr_array![6 => Float32x4],
                     ),
+                    Ginkgo::vertex_buffer_layout::<CReprTransform>(
+                        VertexStepMode::Instance,
+                        &wgpu::vertex_attr_array![7 => Float32x4, 8 => Float32x2],
+                    ),
                 ],
             },
             primitive: Ginkgo::triangle_list_primitive(),
@@ -186,6 +193,7 @@ impl Render for Image {
                 queues.remove_attr::<Image, Section<Logical>>(entity);
                 queues.remove_attr::<Image, ImageWrite>(entity);
                 queues.remove_attr::<Image, CornerRadii>(entity);
+                queues.remove_attr::<Image, ResolvedTransform>(entity);
             }
         }
         for (entity, image) in queues.attribute::<Image, ImageWrite>() {
@@ -224,6 +232,7 @@ impl Render for Image {
                     coords: InstanceBuffer::new(ginkgo, 1),
                     opaque: InstanceBuffer::new(ginkgo, 1),
                     radii: InstanceBuffer::new(ginkgo, 1),
+                    transforms: InstanceBuffer::new(ginkgo, 1),
                 };
                 renderer.groups.insert(group_id, RenderGroup::new(g));
             }
@@ -308,6 +317,15 @@ impl Render for Image {
                 );
             }
         }
+        for (entity, transform) in queues.attribute::<Image, ResolvedTransform>() {
+            if let Some(gid) = renderer.resources.entity_to_memory.get(&entity) {
+                let group = renderer.groups.get_mut(gid).unwrap();
+                group.group.transforms.queue(
+                    entity.to_bits(),
+                    transform.c_repr(ginkgo.configuration().scale_factor.value()),
+                );
+            }
+        }
         for (gid, group) in renderer.groups.iter_mut() {
             if let Some(n) = group.coordinator.grown() {
                 group.group.sections.grow(ginkgo, n);
@@ -315,6 +333,7 @@ impl Render for Image {
                 group.group.coords.grow(ginkgo, n);
                 group.group.opaque.grow(ginkgo, n);
                 group.group.radii.grow(ginkgo, n);
+                group.group.transforms.grow(ginkgo, n);
             }
             for swap in group.coordinator.sort() {
                 group.group.sections.swap(swap);
@@ -322,6 +341,7 @@ impl Render for Image {
                 group.group.coords.swap(swap);
                 group.group.opaque.swap(swap);
                 group.group.radii.swap(swap);
+                group.group.transforms.swap(swap);
             }
             for (id, data) in group.group.sections.queued() {
                 let order = group.coordinator.order(id);
@@ -343,11 +363,16 @@ impl Render for Image {
                 let order = group.coordinator.order(id);
                 group.group.radii.write_cpu(order, data);
             }
+            for (id, data) in group.group.transforms.queued() {
+                let order = group.coordinator.order(id);
+                group.group.transforms.write_cpu(order, data);
+            }
             group.group.sections.write_gpu(ginkgo);
             group.group.elevations.write_gpu(ginkgo);
             group.group.coords.write_gpu(ginkgo);
             group.group.opaque.write_gpu(ginkgo);
             group.group.radii.write_gpu(ginkgo);
+            group.group.transforms.write_gpu(ginkgo);
             for node in group.coordinator.updated_nodes(PipelineId::Image, *gid) {
                 nodes.update(node);
             }
@@ -366,6 +391,7 @@ impl Render for Image {
         render_pass.set_vertex_buffer(3, group.group.coords.buffer.slice(..));
         render_pass.set_vertex_buffer(4, group.group.opaque.buffer.slice(..));
         render_pass.set_vertex_buffer(5, group.group.radii.buffer.slice(..));
+        render_pass.set_vertex_buffer(6, group.group.transforms.buffer.slice(..));
         render_pass.draw(0..texture::VERTICES.len() as u32, parameters.range);
     }
 }
@@ -60,9 +60,46 @@ impl From<WinitKey> for Key {
 #[derive(Resource, Default)]
 pub(crate) struct TouchAdapter {
     primary: Option<u64>,
+    /// Every finger down, and where it last was -- only ever read for a pinch.
+    fingers: Vec<(u64, Position<Logical>)>,
 }
 
 impl TouchAdapter {
+    /// A two-finger pinch, as the zoom it asks for. Beside [`parse`](Self::parse) rather than
+    /// inside it: the primary finger still drives its ordinary gesture, so the content pans
+    /// with the pinch as well as scaling about it.
+    pub(crate) fn pinch(
+        &mut self,
+        touch: Touch,
+        viewport_position: Position<Logical>,
+        scale_factor: ScaleFactor,
+    ) -> Option<crate::grid::zoom::ZoomInput> {
+        let position = Position::physical((touch.location.x, touch.location.y))
+            .to_logical(scale_factor.value())
+            + viewport_position;
+        let index = self.fingers.iter().position(|(id, _)| *id == touch.id);
+        match (touch.phase, index) {
+            (TouchPhase::Started, None) => self.fingers.push((touch.id, position)),
+            (TouchPhase::Moved, Some(i)) => {
+                let was = self.fingers.clone();
+                self.fingers[i].1 = position;
+                if let ([(_, a), (_, b)], [(_, was_a), (_, was_b)]) =
+                    (self.fingers.as_slice(), was.as_slice())
+                {
+                    let factor = crate::grid::zoom::spread(*a, *b, *was_a, *was_b)?;
+                    return Some(crate::grid::zoom::ZoomInput {
+                        at: (*a + *b) / 2.0,
+                        factor,
+                    });
+                }
+            }
+            (TouchPhase::Ended | TouchPhase::Cancelled, Some(i)) => {
+                self.fingers.remove(i);
+            }
+            _ => {}
+        }
+        None
+    }
     pub(crate) fn parse(
         &mut self,
         touch: Touch,
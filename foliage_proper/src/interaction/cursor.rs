@@ -0,0 +1,141 @@
+use crate::ash::clip::ResolvedClip;
+use crate::coordinate::elevation::StackKey;
+use crate::ginkgo::viewport::ViewportHandle;
+use crate::interaction::listener::InteractionListener;
+use crate::interaction::{
+    CurrentInteraction, InteractionMethod, InteractionPropagation, MouseAdapter,
+};
+use crate::transform::ResolvedTransform;
+use crate::{Component, InteractionShape, Logical, Parent, ResolvedVisibility, Section, TextInput};
+use bevy_ecs::entity::Entity;
+use bevy_ecs::resource::Resource;
+use bevy_ecs::system::{Query, Res, ResMut};
+
+/// The pointer shown over an element. Set with [`cursor`](crate::Sprout::cursor); inherited by
+/// everything inside it that sets none of its own.
+///
+/// Foliage's own list rather than winit's `CursorIcon`, for the reason [`Key`](crate::Key) is
+/// its own: no winit type reaches a consumer. These are the shapes an app actually asks for;
+/// anything else is a conversion away.
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CursorIcon {
+    #[default]
+    Default,
+    /// The hand -- something pressable.
+    Pointer,
+    /// The I-beam. What a [`TextInput`] shows unless told otherwise.
+    Text,
+    Grab,
+    Grabbing,
+    Move,
+    Crosshair,
+    ResizeEw,
+    ResizeNs,
+    NotAllowed,
+    Wait,
+    Help,
+}
+impl From<CursorIcon> for winit::window::CursorIcon {
+    fn from(icon: CursorIcon) -> Self {
+        match icon {
+            CursorIcon::Default => Self::Default,
+            CursorIcon::Pointer => Self::Pointer,
+            CursorIcon::Text => Self::Text,
+            CursorIcon::Grab => Self::Grab,
+            CursorIcon::Grabbing => Self::Grabbing,
+            CursorIcon::Move => Self::Move,
+            CursorIcon::Crosshair => Self::Crosshair,
+            CursorIcon::ResizeEw => Self::EwResize,
+            CursorIcon::ResizeNs => Self::NsResize,
+            CursorIcon::NotAllowed => Self::NotAllowed,
+            CursorIcon::Wait => Self::Wait,
+            CursorIcon::Help => Self::Help,
+        }
+    }
+}
+
+/// The icon the frame decided on, and the one the window was last given. The window is not
+/// in the world, so the system only decides; the loop hands the answer over once `main` has
+/// run, and only when it changed -- `set_cursor` is a platform call on every platform, and on
+/// web it is a style write that restarts the browser's own cursor handling.
+#[derive(Resource, Default)]
+pub(crate) struct PointerCursor {
+    wanted: CursorIcon,
+    applied: CursorIcon,
+}
+impl PointerCursor {
+    /// The icon to give the window, if it differs from what the window already has.
+    pub(crate) fn take_change(&mut self) -> Option<CursorIcon> {
+        (self.wanted != self.applied).then(|| {
+            self.applied = self.wanted;
+            self.wanted
+        })
+    }
+}
+
+/// Picks the icon for whatever the mouse is over.
+///
+/// Over means what a press there would land on -- [`InteractionListener::topmost`], which
+/// `interactive_elements` picks a gesture's owner by, so the hand never shows over something a
+/// click would go straight through, and a disabled element shows the icon of whatever is
+/// beneath it. Once a gesture is under way the icon belongs to its owner instead, wherever the
+/// pointer has wandered, and an element that [`holds_drag`](crate::Sprout::holds_drag) shows
+/// the closed hand while dragged.
+///
+/// Asked every frame rather than on movement alone: the pointer standing still while a list
+/// scrolls beneath it is over something new all the same.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub(crate) fn hovered_cursor(
+    all: Query<(
+        Entity,
+        &InteractionPropagation,
+        &InteractionShape,
+        &Section<Logical>,
+        &ResolvedClip,
+        &ResolvedTransform,
+    )>,
+    icons: Query<&CursorIcon>,
+    stack_keys: Query<&StackKey>,
+    visibilities: Query<&ResolvedVisibility>,
+    parents: Query<&Parent>,
+    text_inputs: Query<&TextInput>,
+    mouse: Res<MouseAdapter>,
+    viewport: Res<ViewportHandle>,
+    current: Res<CurrentInteraction>,
+    mut cursor: ResMut<PointerCursor>,
+) {
+    let gesture = current.pressed && current.method == InteractionMethod::Mouse;
+    let over = if gesture {
+        current.primary
+    } else {
+        InteractionListener::topmost(
+            all.iter().filter(|(_, propagation, ..)| propagation.grab),
+            viewport.section().position + mouse.cursor,
+            None,
+            &stack_keys,
+            &visibilities,
+            &parents,
+        )
+    };
+    let dragging = gesture
+        && current.past_drag
+        && over.is_some_and(|o| all.get(o).is_ok_and(|(_, p, ..)| p.disable_drag));
+    cursor.wanted = if dragging {
+        CursorIcon::Grabbing
+    } else {
+        let mut icon = CursorIcon::Default;
+        let mut at = over;
+        while let Some(id) = at {
+            if let Ok(set) = icons.get(id) {
+                icon = *set;
+                break;
+            }
+            if text_inputs.contains(id) {
+                icon = CursorIcon::Text;
+                break;
+            }
+            at = parents.get(id).ok().and_then(|p| p.id);
+        }
+        icon
+    };
+}
@@ -0,0 +1,134 @@
+use crate::ash::clip::ResolvedClip;
+use crate::coordinate::elevation::StackKey;
+use crate::interaction::listener::InteractionListener;
+use crate::interaction::{CurrentInteraction, InteractionMethod, InteractionPropagation};
+use crate::transform::ResolvedTransform;
+use crate::{
+    Component, InteractionShape, Logical, Parent, Position, ResolvedVisibility, Section, Tree,
+};
+use bevy_ecs::entity::Entity;
+use bevy_ecs::prelude::With;
+use bevy_ecs::system::{Query, ResMut, SystemParam};
+
+/// Marks an entity as somewhere a dragged element can be let go.
+///
+/// A marker rather than a flag on [`InteractionListener`], because being dropped on and being
+/// pressed are unrelated: a kanban column is a drop target and nothing anyone clicks, and a card
+/// inside it is clicked, dragged and never dropped on. A target needs no listener at all -- but
+/// one it does have is respected, so a disabled target stops accepting drops along with
+/// everything else.
+#[derive(Component, Copy, Clone, Default, Debug)]
+pub struct DropTarget;
+
+/// A drag has moved over this drop target. Sent once on the way in, not every frame it stays.
+/// `source` is the entity being dragged.
+#[foliage_macros::targeted_event]
+#[derive(Copy)]
+pub struct DragOver {
+    pub source: Entity,
+}
+/// The drag that was over this drop target moved off it, or ended anywhere but on it -- a
+/// cancelled gesture included. Always follows a [`DragOver`], so a highlight has somewhere to
+/// reset.
+#[foliage_macros::targeted_event]
+#[derive(Copy)]
+pub struct DragLeave {
+    pub source: Entity,
+}
+/// `source` was let go over this drop target. Takes the place of the [`DragLeave`] that drag
+/// would otherwise have ended with, and arrives after the source's own
+/// [`Disengaged`](crate::Disengaged) -- the release is resolved first, and this is read off it.
+#[foliage_macros::targeted_event]
+#[derive(Copy)]
+pub struct Dropped {
+    pub source: Entity,
+}
+
+/// The drop targets, and the one hit test over them -- shared by a dragged element looking for
+/// somewhere to land and by a file dragged in from outside the window, which ask the same
+/// question of the same entities and should never get two different answers.
+#[derive(SystemParam)]
+pub(crate) struct DropTargets<'w, 's> {
+    targets: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static InteractionPropagation,
+            &'static InteractionShape,
+            &'static Section<Logical>,
+            &'static ResolvedClip,
+            &'static ResolvedTransform,
+        ),
+        With<DropTarget>,
+    >,
+    listeners: Query<'w, 's, &'static InteractionListener>,
+    stack_keys: Query<'w, 's, &'static StackKey>,
+    visibilities: Query<'w, 's, &'static ResolvedVisibility>,
+    parents: Query<'w, 's, &'static Parent>,
+}
+impl DropTargets<'_, '_> {
+    /// The topmost drop target containing `position`, by
+    /// [`InteractionListener::topmost`], skipping `ignoring` and everything beneath it. A
+    /// target whose own listener is disabled is not a candidate.
+    pub(crate) fn beneath(
+        &self,
+        position: Position<Logical>,
+        ignoring: Option<Entity>,
+    ) -> Option<Entity> {
+        InteractionListener::topmost(
+            self.targets
+                .iter()
+                .filter(|(entity, ..)| !self.listeners.get(*entity).is_ok_and(|l| l.disabled())),
+            position,
+            ignoring,
+            &self.stack_keys,
+            &self.visibilities,
+            &self.parents,
+        )
+    }
+}
+
+/// Resolves which drop target sits beneath the element being dragged.
+///
+/// Its own system, after `interactive_elements`, rather than more of it: that system picks a
+/// gesture's owner once, at `Start`, and acts on the choice from then on, where this asks again
+/// every frame and asks about a different set of entities. It also reads the release straight
+/// off `CurrentInteraction` -- `click.end` is only written by a release the gesture's owner saw.
+///
+/// The dragged element is ignored, along with everything beneath it. It is usually following
+/// the pointer, so it is almost always the topmost thing there -- hit-tested like any other
+/// box it would be its own drop target on every frame of every drag, which is the case that
+/// had apps working the target out from sections by hand. An *ancestor* of the dragged element
+/// stays eligible: a list a card is being reordered within is exactly where it gets dropped.
+pub(crate) fn drop_targets(
+    targets: DropTargets,
+    mut current: ResMut<CurrentInteraction>,
+    mut tree: Tree,
+) {
+    let dragging =
+        current.pressed && current.past_drag && current.method != InteractionMethod::ScrollWheel;
+    let over = if dragging {
+        current.primary.and_then(|source| {
+            targets
+                .beneath(current.click.current, Some(source))
+                .map(|target| (source, target))
+        })
+    } else {
+        None
+    };
+    if over == current.drop_over {
+        return;
+    }
+    if let Some((source, target)) = current.drop_over.take() {
+        if !dragging && current.click.end.is_some() {
+            tree.send_to(Dropped::new(source), target);
+        } else {
+            tree.send_to(DragLeave::new(source), target);
+        }
+    }
+    if let Some((source, target)) = over {
+        tree.send_to(DragOver::new(source), target);
+        current.drop_over = over;
+    }
+}
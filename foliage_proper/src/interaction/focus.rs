@@ -0,0 +1,240 @@
+use crate::interaction::listener::InteractionListener;
+use crate::interaction::{CurrentInteraction, FocusBehavior, InteractionPropagation};
+use crate::node::GrowOrder;
+use crate::text_input::{Handle, TabbedTo};
+use crate::{
+    Anchor, Color, Component, Elevation, Focused, InputSequence, Key, Leaf, Location, Modifiers,
+    OnClick, Panel, Parent, ResolvedVisibility, Rounding, Sprout, TextInput, Tree, Unfocused,
+    Visibility, anchor,
+};
+use bevy_ecs::entity::Entity;
+use bevy_ecs::resource::Resource;
+use bevy_ecs::system::{Query, Res, ResMut};
+
+/// Where an element falls in the Tab order, ahead of tree order.
+///
+/// The same three bands HTML's `tabindex` draws, because anyone reaching for this already knows
+/// them: a positive index is visited first, lowest to highest; `0` -- and every element without
+/// one -- follows in tree order; a negative index takes focus from a press but is never reached
+/// by Tab at all.
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TabIndex(pub i32);
+
+/// How the focus ring looks. Tuned like [`ClearColor`](crate::ClearColor), before the loop
+/// starts.
+///
+/// The ring is an ordinary [`Panel`] drawn with an [`Outline`](crate::Outline), held by the
+/// engine and anchored to whatever holds focus -- so it follows that element through layout,
+/// scroll and animation for nothing, and draws exactly the way every other ring in the app
+/// does.
+///
+/// Shown only for focus that moved by keyboard. A press shows the reader where focus went by
+/// the pressing; a ring appearing around every button anyone clicks is the one thing everybody
+/// who has shipped a focus ring gets asked to remove.
+#[derive(Resource, Copy, Clone)]
+pub struct FocusRing {
+    pub color: Color,
+    /// Ring width, in logical px.
+    pub weight: i32,
+    /// How far outside the focused element's own box the ring sits, in logical px. Outside by
+    /// default, so the ring never covers the edge of what it is marking.
+    pub offset: i32,
+    /// The ring's corners when the focused element has no [`Rounding`] of its own to follow.
+    pub rounding: Rounding,
+}
+impl Default for FocusRing {
+    fn default() -> Self {
+        Self {
+            color: Color::sky(400),
+            weight: 2,
+            offset: 3,
+            rounding: Rounding::Sm,
+        }
+    }
+}
+/// The ring entity once it exists, and whom it is drawn around. Grown on first use rather than
+/// at startup: most pointer-driven sessions never press Tab, and there is no tree to grow into
+/// before the loop starts.
+#[derive(Resource, Default)]
+pub(crate) struct FocusRingHandle {
+    ring: Option<Entity>,
+    around: Option<Entity>,
+}
+
+/// Moves focus on Tab and Shift+Tab, and presses what holds it on Enter or Space.
+///
+/// The order is built fresh on each press rather than kept: it is every enabled, visible
+/// grabbing listener that takes focus when pressed, which is a question the tree answers
+/// differently every time anything is enabled, hidden, grown or withered. A press is rare
+/// enough that asking again is cheaper than keeping an answer right.
+///
+/// Tree order is a depth-first walk, parents before their children. `Children` keeps no order
+/// of its own, so siblings are taken in the order they were grown -- by
+/// [`GrowOrder`], not by entity id, which is recycled and would reorder them after a wither.
+///
+/// A [`TextInput`] is one stop, not its several inner hit-areas. Reaching one focuses it and
+/// puts its caret after the last letter, where a press at the end of its text would -- so it
+/// takes keys with a caret to show where they go. Enter and Space on a focused input are its
+/// own to handle, so they are left alone here.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn traverse(
+    trigger: crate::Trigger<InputSequence>,
+    candidates: Query<(Entity, &InteractionListener, &InteractionPropagation)>,
+    tab_indices: Query<&TabIndex>,
+    behaviors: Query<&FocusBehavior>,
+    visibilities: Query<&ResolvedVisibility>,
+    parents: Query<&Parent>,
+    text_inputs: Query<(&TextInput, &Handle)>,
+    roundings: Query<&Rounding>,
+    grow_orders: Query<&GrowOrder>,
+    mut current: ResMut<CurrentInteraction>,
+    mut ring: ResMut<FocusRingHandle>,
+    style: Res<FocusRing>,
+    mut tree: Tree,
+) {
+    let sequence = trigger.event();
+    // The `TextInput` an entity belongs to, if any.
+    let input_of = |entity: Entity| {
+        let mut at = Some(entity);
+        while let Some(id) = at {
+            if text_inputs.contains(id) {
+                return Some(id);
+            }
+            at = parents.get(id).ok().and_then(|p| p.id);
+        }
+        None
+    };
+    if matches!(sequence.key, Key::Enter | Key::Space) && sequence.mods.is_empty() {
+        if let Some(focused) = current.focused
+            && input_of(focused).is_none()
+            && candidates
+                .get(focused)
+                .is_ok_and(|(_, listener, _)| !listener.disabled())
+        {
+            tree.send_to(OnClick::new(), focused);
+        }
+        return;
+    }
+    if sequence.key != Key::Tab || !(sequence.mods - Modifiers::SHIFT).is_empty() {
+        return;
+    }
+    let mut stops = candidates
+        .iter()
+        .filter(|(entity, listener, propagation)| {
+            !listener.disabled()
+                && propagation.grab
+                && !propagation.disabled
+                && !behaviors.get(*entity).map(|b| b.0).unwrap_or(false)
+                && tab_indices.get(*entity).map(|t| t.0).unwrap_or(0) >= 0
+                && visibilities
+                    .get(*entity)
+                    .map(|v| v.visible())
+                    .unwrap_or(true)
+                && input_of(*entity)
+                    .is_none_or(|input| text_inputs.get(input).unwrap().1.panel == *entity)
+        })
+        .map(|(entity, ..)| {
+            // Anything the tree did not grow -- there is nothing else -- would sort last.
+            let step = |id: Entity| (grow_orders.get(id).map(|o| o.0).unwrap_or(u64::MAX), id);
+            let mut path = vec![step(entity)];
+            let mut up = parents.get(entity).ok().and_then(|p| p.id);
+            while let Some(id) = up {
+                path.push(step(id));
+                up = parents.get(id).ok().and_then(|p| p.id);
+            }
+            path.reverse();
+            // Positive indices first and in their own order; everything else after, at `MAX`,
+            // where the stable sort leaves it in tree order.
+            let band = match tab_indices.get(entity).map(|t| t.0).unwrap_or(0) {
+                0 => i32::MAX,
+                index => index,
+            };
+            (band, path, entity)
+        })
+        .collect::<Vec<_>>();
+    stops.sort();
+    if stops.is_empty() {
+        return;
+    }
+    // Where focus is now, as a stop: focus can sit on something inside one (a press on an
+    // input's text lands on the text, not the panel that is its stop).
+    let from = current.focused.and_then(|focused| {
+        let mut at = Some(focused);
+        while let Some(id) = at {
+            if let Some(i) = stops.iter().position(|(.., entity)| *entity == id) {
+                return Some(i);
+            }
+            at = parents.get(id).ok().and_then(|p| p.id);
+        }
+        None
+    });
+    let backwards = sequence.mods.contains(Modifiers::SHIFT);
+    let to = match (from, backwards) {
+        (None, false) => 0,
+        (None, true) => stops.len() - 1,
+        (Some(i), false) => (i + 1) % stops.len(),
+        (Some(i), true) => (i + stops.len() - 1) % stops.len(),
+    };
+    let (.., next) = stops[to];
+    if current.focused != Some(next) {
+        if let Some(old) = current.focused {
+            tree.send_to(Unfocused::new(), old);
+        }
+        tree.send_to(Focused::new(), next);
+        current.focused = Some(next);
+    }
+    if let Some(input) = input_of(next) {
+        tree.send_to(TabbedTo::new(), input);
+    }
+    let rounding = roundings.get(next).copied().unwrap_or(style.rounding);
+    match ring.ring {
+        Some(existing) => {
+            tree.write_to(
+                existing,
+                (Anchor::new(next), rounding, Visibility::new(true)),
+            );
+        }
+        None => {
+            let offset = style.offset;
+            let grown = tree.leaf(
+                Panel::new()
+                    .color(style.color)
+                    .outline(style.weight)
+                    .rounding(rounding)
+                    .at(Location::new().xs(
+                        anchor()
+                            .left()
+                            .as_left()
+                            .adjust(-offset)
+                            .with(anchor().right().as_right().adjust(offset)),
+                        anchor()
+                            .top()
+                            .as_top()
+                            .adjust(-offset)
+                            .with(anchor().bottom().as_bottom().adjust(offset)),
+                    ))
+                    .anchored(Leaf(next))
+                    .elevate(Elevation::abs(200))
+                    .clip_to_viewport()
+                    .pass_through(),
+            );
+            ring.ring = Some(grown);
+        }
+    }
+    ring.around = Some(next);
+}
+
+/// Hides the ring once focus has gone anywhere Tab did not send it -- a press elsewhere, a blur,
+/// the element it was drawn around withering.
+pub(crate) fn follow_focus(
+    current: Res<CurrentInteraction>,
+    mut ring: ResMut<FocusRingHandle>,
+    mut tree: Tree,
+) {
+    if ring.around.is_some() && ring.around != current.focused {
+        ring.around = None;
+        if let Some(existing) = ring.ring {
+            tree.write_to(existing, Visibility::new(false));
+        }
+    }
+}
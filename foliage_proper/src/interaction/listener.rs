@@ -1,6 +1,15 @@
 use crate::ash::clip::ResolvedClip;
-use crate::{Component, CoordinateUnit, Logical, Position, Section};
+use crate::coordinate::elevation::StackKey;
+use crate::interaction::InteractionPropagation;
+use crate::transform::ResolvedTransform;
+use crate::{
+    Component, CoordinateUnit, Logical, Numerical, Parent, Polygon, Position, ResolvedVisibility,
+    Rounding, Section, Side,
+};
+use bevy_ecs::entity::Entity;
+use bevy_ecs::system::Query;
 use bitflags::bitflags;
+use std::sync::Arc;
 
 /// Makes an entity a hit target: pointer and touch events resolve to it, and it can fire
 /// [`Engaged`](crate::Engaged)/[`Dragged`](crate::Dragged)/[`OnClick`](crate::OnClick).
@@ -25,6 +34,11 @@ impl Default for InteractionListener {
 impl InteractionListener {
     /// Logical pixels the pointer must travel before a press is read as a drag rather
     /// than a click. Below it, releasing still counts as a click on the grabbed entity.
+    ///
+    /// A constant rather than a tuning because everything else is built on top of it: the
+    /// double-click, long-press and swipe recognizers read their own thresholds from
+    /// [`GestureThresholds`](crate::GestureThresholds), and each assumes this has already
+    /// decided whether the gesture was a click or a drag.
     pub const DRAG_THRESHOLD: CoordinateUnit = 10.0;
     /// A listener, enabled.
     pub fn new() -> Self {
@@ -41,32 +55,242 @@ impl InteractionListener {
     }
     /// Whether `event` hits this entity: inside its shape *and* inside its resolved clip,
     /// so content scrolled out of a view is not grabbable where it would have been drawn.
+    ///
+    /// The shape is tested in the box as laid out, so `event` is taken back through the
+    /// entity's [`Transform`](crate::Transform)s first; the clip is already the box as drawn,
+    /// and is tested as it lands.
     pub(crate) fn is_contained(
-        shape: InteractionShape,
+        shape: &InteractionShape,
         section: Section<Logical>,
         clip: ResolvedClip,
+        transform: ResolvedTransform,
         event: Position<Logical>,
     ) -> bool {
+        let clip_contained = clip.0.contains(event);
+        let Some(p) = transform.invert(event) else {
+            return false;
+        };
         let section_contained = match shape {
-            InteractionShape::Rectangle => section.contains(event),
-            InteractionShape::Circle => section.center().distance(event) <= section.width() / 2f32,
+            InteractionShape::Rectangle => section.contains(p),
+            InteractionShape::Circle => section.center().distance(p) <= section.width() / 2f32,
+            InteractionShape::Rounded { rounding, side } => {
+                section.contains(p) && within_corners(*rounding, *side, section, p)
+            }
+            InteractionShape::Polygon(polygon) => {
+                section.contains(p) && within_polygon(*polygon, section, p)
+            }
+            InteractionShape::Alpha(mask) => section.contains(p) && mask.opaque_at(section, p),
         };
-        let clip_contained = clip.0.contains(event);
         section_contained && clip_contained
     }
+    /// Every one of `candidates` a press at `at` could land on: enabled, visible, and hit by
+    /// [`is_contained`](Self::is_contained). `ignoring` takes an entity out of the running
+    /// along with everything beneath it.
+    ///
+    /// The one rule for what is under a point, so the press, the cursor and a drop can never
+    /// disagree about it. Which entities are candidates at all is the caller's to say.
+    pub(crate) fn hits<'a>(
+        candidates: impl IntoIterator<Item = HitCandidate<'a>> + 'a,
+        at: Position<Logical>,
+        ignoring: Option<Entity>,
+        visibilities: &'a Query<&ResolvedVisibility>,
+        parents: &'a Query<&Parent>,
+    ) -> impl Iterator<Item = Entity> + 'a {
+        candidates.into_iter().filter_map(
+            move |(entity, propagation, shape, section, clip, transform)| {
+                let hit = !propagation.disabled
+                    && visibilities
+                        .get(entity)
+                        .map(|v| v.visible())
+                        .unwrap_or(true)
+                    && Self::is_contained(shape, *section, *clip, *transform, at)
+                    && !ignoring.is_some_and(|ignored| within(entity, ignored, parents));
+                hit.then_some(entity)
+            },
+        )
+    }
+    /// The topmost of [`hits`](Self::hits) by [`StackKey`], a tie going to the later.
+    pub(crate) fn topmost<'a>(
+        candidates: impl IntoIterator<Item = HitCandidate<'a>> + 'a,
+        at: Position<Logical>,
+        ignoring: Option<Entity>,
+        stack_keys: &Query<&StackKey>,
+        visibilities: &'a Query<&ResolvedVisibility>,
+        parents: &'a Query<&Parent>,
+    ) -> Option<Entity> {
+        Self::hits(candidates, at, ignoring, visibilities, parents).reduce(|found, entity| {
+            if stack_keys.get(entity).unwrap() >= stack_keys.get(found).unwrap() {
+                entity
+            } else {
+                found
+            }
+        })
+    }
+}
+
+/// An entity, and everything [`InteractionListener::hits`] reads to test it.
+pub(crate) type HitCandidate<'a> = (
+    Entity,
+    &'a InteractionPropagation,
+    &'a InteractionShape,
+    &'a Section<Logical>,
+    &'a ResolvedClip,
+    &'a ResolvedTransform,
+);
+
+/// Whether `entity` is `ancestor` or sits anywhere beneath it.
+fn within(entity: Entity, ancestor: Entity, parents: &Query<&Parent>) -> bool {
+    let mut at = Some(entity);
+    while let Some(id) = at {
+        if id == ancestor {
+            return true;
+        }
+        at = parents.get(id).ok().and_then(|p| p.id);
+    }
+    false
 }
 
 /// The shape an entity is hit-tested against, independent of what it draws.
 ///
-/// [`Rounding::Full`](crate::Rounding::Full) sets `Circle` automatically, so a pill or dot
-/// only responds where it appears solid.
-#[derive(Component, Copy, Clone, Default)]
+/// Follows what is drawn wherever the engine knows it: [`Rounding`] (with its [`Side`]) sets
+/// `Rounded`, so a card's transparent corners -- and everything outside a pill or a dot --
+/// pass the press on to whatever is beneath, and a [`Polygon`] sets `Polygon`, kept in step
+/// with it as it animates. An image's own alpha is opt-in, through
+/// [`ImageSprout::alpha_hit_area`](crate::ImageSprout::alpha_hit_area): it costs a copy of
+/// the alpha channel held on the CPU for as long as the image lives.
+#[derive(Component, Clone, Default)]
 pub enum InteractionShape {
     /// The entity's whole `Section`.
     #[default]
     Rectangle,
     /// A circle inscribed in the `Section`, using its width as the diameter.
     Circle,
+    /// The `Section` with its corners rounded exactly as a [`Panel`](crate::Panel) draws them.
+    /// Resolved against the section at the moment of the press, so a box that grows keeps the
+    /// same curve.
+    Rounded { rounding: Rounding, side: Side },
+    /// The regular polygon `polygon.wgsl` draws into the `Section`, by the same distance field.
+    Polygon(Polygon),
+    /// Wherever an image is at least half opaque.
+    Alpha(AlphaMask),
+}
+
+/// An image's alpha channel, kept for hit-testing, and the part of the image its box shows.
+///
+/// Shared rather than copied: an `InteractionShape` is cloned on every re-authoring, and an
+/// alpha channel is a byte per pixel.
+#[derive(Clone)]
+pub struct AlphaMask {
+    width: u32,
+    height: u32,
+    alpha: Arc<[u8]>,
+    /// What [`ImageView::Crop`](crate::ImageView::Crop) cut from each side, as fractions of
+    /// the whole image; zero for every other view, which shows all of it.
+    pub(crate) crop: Section<Numerical>,
+}
+impl AlphaMask {
+    /// Alpha at or above this counts as solid -- the point an anti-aliased edge reads as more
+    /// image than background.
+    const THRESHOLD: u8 = 128;
+    /// Keeps the alpha of `rgba`, an image `width` pixels across.
+    pub(crate) fn new(width: u32, height: u32, rgba: &[u8]) -> Self {
+        Self {
+            width,
+            height,
+            alpha: rgba.chunks_exact(4).map(|pixel| pixel[3]).collect(),
+            crop: Section::default(),
+        }
+    }
+    fn opaque_at(&self, section: Section<Logical>, event: Position<Logical>) -> bool {
+        if self.width == 0 || self.height == 0 || section.width() <= 0.0 || section.height() <= 0.0
+        {
+            return false;
+        }
+        let u = (event.left() - section.left()) / section.width();
+        let v = (event.top() - section.top()) / section.height();
+        let u = self.crop.left() + u * (1.0 - self.crop.left() - self.crop.width());
+        let v = self.crop.top() + v * (1.0 - self.crop.top() - self.crop.height());
+        let x = ((u * self.width as f32) as u32).min(self.width - 1);
+        let y = ((v * self.height as f32) as u32).min(self.height - 1);
+        self.alpha[(y * self.width + x) as usize] >= Self::THRESHOLD
+    }
+}
+
+/// Whether `event` is inside the corners [`Rounding`] cuts -- tested only in the square each
+/// rounded corner occupies, since everywhere else in the section is inside by definition.
+fn within_corners(
+    rounding: Rounding,
+    side: Side,
+    section: Section<Logical>,
+    event: Position<Logical>,
+) -> bool {
+    let r = rounding.depth(section, 1.0);
+    if r <= 0.0 {
+        return true;
+    }
+    let (x, y) = (event.left(), event.top());
+    // Each corner's disc centre, and which way is outward from it.
+    let corners = [
+        (
+            side.top_left,
+            section.left() + r,
+            section.top() + r,
+            -1.0,
+            -1.0,
+        ),
+        (
+            side.top_right,
+            section.right() - r,
+            section.top() + r,
+            1.0,
+            -1.0,
+        ),
+        (
+            side.bottom_left,
+            section.left() + r,
+            section.bottom() - r,
+            -1.0,
+            1.0,
+        ),
+        (
+            side.bottom_right,
+            section.right() - r,
+            section.bottom() - r,
+            1.0,
+            1.0,
+        ),
+    ];
+    corners.into_iter().all(|(rounded, cx, cy, out_x, out_y)| {
+        let in_corner = (x - cx) * out_x > 0.0 && (y - cy) * out_y > 0.0;
+        !(rounded && in_corner) || Position::logical((cx, cy)).distance(event) <= r
+    })
+}
+
+/// `polygon.wgsl`'s distance field, on the CPU -- the same apothem, rounding and side-count
+/// blend, so the hit area is the drawn shape and not an approximation of it.
+fn within_polygon(polygon: Polygon, section: Section<Logical>, event: Position<Logical>) -> bool {
+    fn regular(p: (f32, f32), r: f32, n: f32) -> f32 {
+        let an = std::f32::consts::PI / n;
+        let (acs_x, acs_y) = (an.cos(), an.sin());
+        let bn = (p.0.atan2(p.1)).rem_euclid(2.0 * an) - an;
+        let length = (p.0 * p.0 + p.1 * p.1).sqrt();
+        let (mut qx, mut qy) = (length * bn.cos(), length * bn.sin().abs());
+        qx -= r * acs_x;
+        qy -= r * acs_y;
+        qy += (-qy).clamp(0.0, r * acs_y);
+        (qx * qx + qy * qy).sqrt() * qx.signum()
+    }
+    let center = section.center();
+    let apothem = section.width().min(section.height()) * 0.5;
+    let (dx, dy) = (event.left() - center.left(), event.top() - center.top());
+    let (c, s) = ((-polygon.rotation).cos(), (-polygon.rotation).sin());
+    let p = (dx * c - dy * s, dx * s + dy * c);
+    let round = polygon.rounding.clamp(0.0, 1.0) * apothem;
+    let r = (apothem - round).max(0.0);
+    let sides = polygon.sides.max(3.0);
+    let d0 = regular(p, r, sides.floor());
+    let d1 = regular(p, r, sides.ceil());
+    d0 + (d1 - d0) * sides.fract() - round <= 0.0
 }
 /// Three independent reasons a listener may be off, so none can overwrite another:
 /// `ENABLED` is the author's own switch, `AUTO_ENABLED` the engine's (cleared while the
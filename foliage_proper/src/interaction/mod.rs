@@ -6,17 +6,24 @@ use bevy_ecs::prelude::IntoScheduleConfigs;
 use bevy_ecs::resource::Resource;
 use bevy_ecs::system::{Query, Res, ResMut};
 mod adapter;
+pub(crate) mod cursor;
+pub(crate) mod drop_target;
+pub(crate) mod focus;
 pub(crate) mod listener;
 
 use crate::ash::clip::ResolvedClip;
 use crate::coordinate::elevation::StackKey;
 use crate::foliage::{Foliage, MainMarkers};
-use crate::grid::view::{Coasting, ScrollMomentum, ViewAdjustment};
+use crate::grid::view::{Coasting, ScrollMomentum, Settle, ViewAdjustment};
+use crate::transform::ResolvedTransform;
 use crate::{
     Attachment, Component, InteractionShape, Moment, Parent, ResolvedElevation, Section, Tree, View,
 };
 pub use adapter::{InputSequence, Key, Modifiers, PhysicalInputSequence, PhysicalKey};
 pub(crate) use adapter::{KeyboardAdapter, MouseAdapter, TouchAdapter};
+pub use cursor::CursorIcon;
+pub use drop_target::{DragLeave, DragOver, DropTarget, Dropped};
+pub use focus::{FocusRing, TabIndex};
 use listener::InteractionListener;
 
 impl Attachment for Interaction {
@@ -24,11 +31,35 @@ impl Attachment for Interaction {
         foliage
             .main
             .add_systems(interactive_elements.in_set(MainMarkers::Process));
+        foliage.main.add_systems(
+            drop_target::drop_targets
+                .in_set(MainMarkers::Process)
+                .after(interactive_elements),
+        );
         foliage.world.insert_resource(KeyboardAdapter::default());
         foliage.world.insert_resource(MouseAdapter::default());
         foliage.world.insert_resource(TouchAdapter::default());
         foliage.world.insert_resource(CurrentInteraction::default());
         foliage.world.insert_resource(AxisCommitment::default());
+        foliage.world.insert_resource(GestureThresholds::default());
+        foliage.world.insert_resource(FocusRing::default());
+        foliage
+            .world
+            .insert_resource(focus::FocusRingHandle::default());
+        foliage.define(focus::traverse);
+        foliage
+            .world
+            .insert_resource(cursor::PointerCursor::default());
+        foliage.main.add_systems(
+            cursor::hovered_cursor
+                .in_set(MainMarkers::Process)
+                .after(interactive_elements),
+        );
+        foliage.main.add_systems(
+            focus::follow_focus
+                .in_set(MainMarkers::Process)
+                .after(interactive_elements),
+        );
         foliage.enable_queued_event::<Interaction>();
     }
 }
@@ -136,6 +167,19 @@ pub struct CurrentInteraction {
     /// question -- "is this a click" -- and is deliberately tiny. The first pixels of a gesture
     /// are the least representative part of it, and committing on them guesses wrong constantly.
     pub(crate) axis: Option<GestureAxis>,
+    /// When the pointer went down, for judging a long press. `None` between gestures and for a
+    /// wheel notch, which is never held.
+    pub(crate) pressed_at: Option<Moment>,
+    /// Set once a hold has been reported as a [`LongPressed`], so it is reported once rather than
+    /// every frame it goes on being held -- and so the release does not also read as a click.
+    pub(crate) long_pressed: bool,
+    /// The last click the grabbed entity produced: who, when and where. What the next click is
+    /// measured against to decide whether the pair was a double click.
+    pub(crate) last_click: Option<(Entity, Moment, Position<Logical>)>,
+    /// The dragged entity and the drop target it is currently over, as last reported by a
+    /// [`DragOver`]. What the next frame compares against to know whether to say anything, and
+    /// what a release is resolved into a [`Dropped`] or a [`DragLeave`] from.
+    pub(crate) drop_over: Option<(Entity, Entity)>,
 }
 
 /// Which way a drag turned out to be going.
@@ -192,6 +236,72 @@ impl Default for AxisCommitment {
         Self { distance: 12.0 }
     }
 }
+/// How the built-in recognizers tell a double click, a long press and a swipe from the plain
+/// gestures they are made of.
+///
+/// Tuned like [`AxisCommitment`] -- `foliage.tune(GestureThresholds { long_press_ms: 700.0,
+/// ..Default::default() })` before `photosynthesize` -- and for the same reason: every one of
+/// these is a claim about hands and devices rather than about content. A stylus taps more
+/// precisely than a thumb; somebody with a tremor needs a longer double-click window than the
+/// default allows. One set for the app, because there is one pointer.
+///
+/// All of them sit on top of [`InteractionListener::DRAG_THRESHOLD`], which still decides
+/// whether a gesture is a click or a drag: a double click is two clicks, a long press is a
+/// press that never became a drag, and a swipe is a drag that let go fast.
+#[derive(Resource, Copy, Clone, Debug)]
+pub struct GestureThresholds {
+    /// ms between two clicks on the same element beyond which they are two single clicks
+    /// rather than one double click.
+    pub double_click_ms: f32,
+    /// Logical px the second click may land from the first and still pair with it. Two quick
+    /// clicks on opposite ends of a wide element are two separate intentions.
+    pub double_click_distance: f32,
+    /// ms a press has to be held, without becoming a drag, before it reads as a long press.
+    pub long_press_ms: f32,
+    /// px/ms of release velocity a drag has to carry to count as a swipe. Measured from the
+    /// same smoothed rate a view's release coast is handed, so a drag that would fling a view
+    /// and one that swipes agree about what fast means.
+    pub swipe_velocity: f32,
+    /// Logical px a swipe has to have travelled from where it was pressed. Keeps a twitch that
+    /// only just crossed the drag threshold from reading as a deliberate swipe however fast it was.
+    pub swipe_distance: f32,
+}
+impl Default for GestureThresholds {
+    fn default() -> Self {
+        Self {
+            double_click_ms: 400.0,
+            double_click_distance: InteractionListener::DRAG_THRESHOLD,
+            long_press_ms: 500.0,
+            swipe_velocity: 0.5,
+            swipe_distance: 40.0,
+        }
+    }
+}
+/// Which way a [`Swiped`] went -- the way the pointer travelled, not the way content under it
+/// would scroll.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SwipeDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+impl SwipeDirection {
+    /// The dominant direction of `travel`, on `axis` if the gesture committed to one.
+    fn of(travel: Position<Logical>, axis: Option<GestureAxis>) -> Self {
+        let across = match axis {
+            Some(GestureAxis::Across) => true,
+            Some(GestureAxis::Down) => false,
+            None => travel.left().abs() >= travel.top().abs(),
+        };
+        match (across, travel.left() < 0.0, travel.top() < 0.0) {
+            (true, true, _) => SwipeDirection::Left,
+            (true, false, _) => SwipeDirection::Right,
+            (false, _, true) => SwipeDirection::Up,
+            (false, _, false) => SwipeDirection::Down,
+        }
+    }
+}
 impl CurrentInteraction {
     /// The current gesture's own start/current/end positions.
     pub fn click(&self) -> Click {
@@ -240,6 +350,29 @@ pub struct DragStarted {}
 #[foliage_macros::targeted_event]
 #[derive(Copy)]
 pub struct Disengaged {}
+/// A second click on the same entity, close enough in time and place to the first to be one
+/// gesture. Sent after the [`OnClick`] for that second click -- the single click always fires
+/// too, so something that only cares about one never has to wait to find out it was not two.
+/// See [`GestureThresholds`].
+#[foliage_macros::targeted_event]
+#[derive(Copy)]
+pub struct DoubleClicked {}
+/// The pointer has been held down on this entity for
+/// [`long_press_ms`](GestureThresholds::long_press_ms) without becoming a drag. Sent once, while
+/// the pointer is still down; the release that follows is not also an [`OnClick`].
+#[foliage_macros::targeted_event]
+#[derive(Copy)]
+pub struct LongPressed {}
+/// A drag on this entity let go fast -- past [`swipe_velocity`](GestureThresholds::swipe_velocity)
+/// after travelling at least [`swipe_distance`](GestureThresholds::swipe_distance). Sent at
+/// release, before [`Disengaged`].
+#[foliage_macros::targeted_event]
+#[derive(Copy)]
+pub struct Swiped {
+    pub direction: SwipeDirection,
+    /// The smoothed release rate, px/ms, signed the way the pointer travelled.
+    pub velocity: Position<Logical>,
+}
 /// Whether an entity competes for a gesture or lets it through to whatever is beneath.
 #[derive(Component, Copy, Clone)]
 pub struct InteractionPropagation {
@@ -315,8 +448,10 @@ pub(crate) fn interactive_elements(
         &ResolvedClip,
         &InteractionPropagation,
         &InteractionShape,
+        &ResolvedTransform,
     )>,
     commitment: Res<AxisCommitment>,
+    thresholds: Res<GestureThresholds>,
     stack_keys: Query<&StackKey>,
     behaviors: Query<&FocusBehavior>,
     mut listeners: Query<&mut InteractionListener>,
@@ -333,6 +468,7 @@ pub(crate) fn interactive_elements(
         .any(|e| e.click_phase == InteractionPhase::Cancel)
     {
         current.pressed = false;
+        current.pressed_at = None;
         if let Some(entity) = current.primary.take() {
             tree.send_to(
                 Disengaged {
@@ -384,54 +520,50 @@ pub(crate) fn interactive_elements(
                 );
             }
             current.past_drag = false;
+            current.long_pressed = false;
+            current.pressed_at = (event.method != InteractionMethod::ScrollWheel).then(Moment::now);
             // A new gesture decides its own direction. Carried over, the last drag's axis would
             // still be committed on the first pixel of this one -- and the commonest thing a
             // reader does after a vertical drag is a horizontal one.
             current.axis = None;
-            for (entity, section, _elevation, clip, propagation, shape) in all.iter() {
-                // Disabled entities are out of the running entirely, not merely stopped
-                // from acting on a grab they won: a disabled overlay sitting on top would
-                // otherwise take the gesture on elevation alone and silently swallow
-                // input meant for what is underneath.
-                //
-                // Read off `InteractionPropagation`, which every entity has, rather than off
-                // `InteractionListener`, which only an `.interactive()` one has. Read off the
-                // listener this check was true only for things that were already targets, so
-                // `disable` on a plain container did nothing whatsoever and the container went
-                // on winning gestures -- which is the very case the paragraph above forbids.
-                //
-                // And an entity nobody can see is not a thing anybody can be pointing at, so
-                // it never begins a gesture either. This is the only place the engine picks
-                // what is under the pointer -- `Moved` and `End` act on what was chosen here --
-                // so one test here is the whole rule, and it needs no exception for a target
-                // that is scrolled or faded away mid-drag. A gesture belongs to whoever won
-                // it; this governs the winning, not the holding.
-                if propagation.disabled
-                    || !visibilities
-                        .get(entity)
-                        .map(|v| v.visible())
-                        .unwrap_or(true)
-                {
-                    continue;
-                }
-                if propagation.grab {
-                    if InteractionListener::is_contained(*shape, *section, *clip, event.position) {
-                        let wins = match current.primary {
-                            None => true,
-                            Some(existing) => {
-                                stack_keys.get(entity).unwrap() >= stack_keys.get(existing).unwrap()
-                            }
-                        };
-                        if wins {
-                            current.primary.replace(entity);
-                        }
-                    }
-                } else {
-                    if InteractionListener::is_contained(*shape, *section, *clip, event.position) {
-                        current.pass_through.push(entity);
-                    }
-                }
-            }
+            // Disabled entities are out of the running entirely, not merely stopped from acting
+            // on a grab they won: a disabled overlay sitting on top would otherwise take the
+            // gesture on elevation alone and silently swallow input meant for what is underneath.
+            //
+            // Read off `InteractionPropagation`, which every entity has, rather than off
+            // `InteractionListener`, which only an `.interactive()` one has. Read off the
+            // listener this check was true only for things that were already targets, so
+            // `disable` on a plain container did nothing whatsoever and the container went on
+            // winning gestures -- which is the very case the paragraph above forbids.
+            //
+            // And an entity nobody can see is not a thing anybody can be pointing at, so it
+            // never begins a gesture either. All of that is `InteractionListener::hits`, and this
+            // is the only place a gesture's owner is picked -- `Moved` and `End` act on what was
+            // chosen here -- so it needs no exception for a target that is scrolled or faded
+            // away mid-drag. A gesture belongs to whoever won it; this governs the winning, not
+            // the holding.
+            let candidates = || {
+                all.iter().map(
+                    |(entity, section, _, clip, propagation, shape, transform)| {
+                        (entity, propagation, shape, section, clip, transform)
+                    },
+                )
+            };
+            current.primary = InteractionListener::topmost(
+                candidates().filter(|(_, propagation, ..)| propagation.grab),
+                event.position,
+                None,
+                &stack_keys,
+                &visibilities,
+                &contexts,
+            );
+            current.pass_through.extend(InteractionListener::hits(
+                candidates().filter(|(_, propagation, ..)| !propagation.grab),
+                event.position,
+                None,
+                &visibilities,
+                &contexts,
+            ));
             if let Some(p) = current.primary {
                 current.method = event.method;
                 current.pass_through = current
@@ -527,7 +659,7 @@ pub(crate) fn interactive_elements(
                         // so would never compute a nonzero velocity at all.
                         current.last_drag_time = Some(Moment::now());
                     }
-                } else if !all.get(p).unwrap().4.disable_drag {
+                } else {
                     let diff = current.last_drag - event.position;
                     // smoothed (EMA) px/ms rate of this drag's own motion, read at `ended`
                     // to decide whether release velocity is fast enough to hand off to a
@@ -545,49 +677,54 @@ pub(crate) fn interactive_elements(
                         }
                     }
                     current.last_drag_time = Some(now);
-                    if let Ok(_) = views.get(p) {
-                        // cleared right here, not left to `coast`'s own separate
-                        // ancestor-walk check -- this is the one place that knows with
-                        // certainty which entity is receiving a *live* pan this frame.
-                        // `coast` runs in the same `MainMarkers::Process` set with no
-                        // ordering constraint against this system, so relying on it to
-                        // notice and cancel independently left a real window where its
-                        // own stale, decaying write could land after (and overwrite) the
-                        // live one right back, every single frame, for as long as the
-                        // coast kept running -- reading as the coast fighting the drag.
-                        tree.write_to(p, ViewAdjustment(diff, event.method));
-                    } else {
-                        let mut context = *contexts.get(p).unwrap();
-                        while let Some(id) = context.id {
-                            // `p`'s own `disable_drag` (checked above) only covers "the
-                            // grabbed entity refuses to let anything pan" (a knob/cursor
-                            // with no view of its own). It says nothing about a *view*
-                            // reached by walking up from some other grabbed content (a
-                            // Carousel page's own author content, say) that wants to
-                            // refuse drag-panning itself regardless of what's grabbed
-                            // inside it -- so that view's own `disable_drag` is checked
-                            // here too, independently. A disabled view doesn't stop the
-                            // search, though -- it keeps walking up for the next ancestor
-                            // view instead: touch has no separate wheel channel, so a drag
-                            // that isn't meant for this view (Carousel swiping its own
-                            // pages, say) must still be able to reach whatever scrollable
-                            // ancestor further out IS meant to receive it (the page behind
-                            // the Carousel, on mobile where dragging is the only scroll
-                            // input there is).
-                            let mut wrote = false;
-                            if let Ok(_) = views.get(id) {
-                                if !all.get(id).unwrap().4.disable_drag {
-                                    tree.write_to(id, ViewAdjustment(diff, event.method));
-                                    wrote = true;
+                    // The rate above is tracked whatever the entity does with its drag -- a
+                    // swipe on a card that holds its own drag is still a swipe -- but only an
+                    // entity that lets drags through pans anything with it.
+                    if !all.get(p).unwrap().4.disable_drag {
+                        if let Ok(_) = views.get(p) {
+                            // cleared right here, not left to `coast`'s own separate
+                            // ancestor-walk check -- this is the one place that knows with
+                            // certainty which entity is receiving a *live* pan this frame.
+                            // `coast` runs in the same `MainMarkers::Process` set with no
+                            // ordering constraint against this system, so relying on it to
+                            // notice and cancel independently left a real window where its
+                            // own stale, decaying write could land after (and overwrite) the
+                            // live one right back, every single frame, for as long as the
+                            // coast kept running -- reading as the coast fighting the drag.
+                            tree.write_to(p, ViewAdjustment(diff, event.method));
+                        } else {
+                            let mut context = *contexts.get(p).unwrap();
+                            while let Some(id) = context.id {
+                                // `p`'s own `disable_drag` (checked above) only covers "the
+                                // grabbed entity refuses to let anything pan" (a knob/cursor
+                                // with no view of its own). It says nothing about a *view*
+                                // reached by walking up from some other grabbed content (a
+                                // Carousel page's own author content, say) that wants to
+                                // refuse drag-panning itself regardless of what's grabbed
+                                // inside it -- so that view's own `disable_drag` is checked
+                                // here too, independently. A disabled view doesn't stop the
+                                // search, though -- it keeps walking up for the next ancestor
+                                // view instead: touch has no separate wheel channel, so a drag
+                                // that isn't meant for this view (Carousel swiping its own
+                                // pages, say) must still be able to reach whatever scrollable
+                                // ancestor further out IS meant to receive it (the page behind
+                                // the Carousel, on mobile where dragging is the only scroll
+                                // input there is).
+                                let mut wrote = false;
+                                if let Ok(_) = views.get(id) {
+                                    if !all.get(id).unwrap().4.disable_drag {
+                                        tree.write_to(id, ViewAdjustment(diff, event.method));
+                                        wrote = true;
+                                    }
+                                }
+                                if wrote {
+                                    break;
+                                }
+                                if let Ok(up) = contexts.get(id) {
+                                    context = *up;
+                                } else {
+                                    break;
                                 }
-                            }
-                            if wrote {
-                                break;
-                            }
-                            if let Ok(up) = contexts.get(id) {
-                                context = *up;
-                            } else {
-                                break;
                             }
                         }
                     }
@@ -716,7 +853,22 @@ pub(crate) fn interactive_elements(
                     // cases where it does climb higher land the coast somewhere the drag
                     // never touched.
                     let maybe_coast = |tree: &mut Tree, target: Entity| {
+                        // A wheel has no release of its own to settle a snap on, so the wait
+                        // for it to go quiet starts here and restarts with every notch.
                         if event.method == InteractionMethod::ScrollWheel {
+                            tree.write_to(
+                                target,
+                                Settle {
+                                    after_ms: momentum.wheel_settle_ms,
+                                    method: event.method,
+                                },
+                            );
+                            return;
+                        }
+                        // A grabbed entity holding its own drag never panned anything, so there
+                        // is no motion of a view's to carry on -- its velocity is tracked for
+                        // `Swiped`, not for this.
+                        if all.get(p).unwrap().4.disable_drag {
                             return;
                         }
                         let speed = current.velocity.left().hypot(current.velocity.top());
@@ -744,6 +896,10 @@ pub(crate) fn interactive_elements(
                                     method: event.method,
                                 },
                             );
+                        } else {
+                            // Too slow to coast, so the gesture ends here rather than where a
+                            // coast would have run out.
+                            tree.write_to(target, Settle::now(event.method));
                         }
                     };
                     if let Ok(_) = views.get(p) {
@@ -791,15 +947,52 @@ pub(crate) fn interactive_elements(
                     // "dragged it and let go here by coincidence."
                     if !listener.disabled()
                         && !current.past_drag
+                        && !current.long_pressed
                         && event.method != InteractionMethod::ScrollWheel
                     {
                         if InteractionListener::is_contained(
-                            *data.5,
+                            data.5,
                             *data.1,
                             *data.3,
+                            *data.6,
                             event.position,
                         ) {
                             tree.send_to(OnClick::new(), p);
+                            // Paired against the last click rather than counted, so a triple
+                            // click is a double followed by the start of another, not two
+                            // doubles sharing a middle.
+                            let now = Moment::now();
+                            let doubled = current.last_click.is_some_and(|(last, at, from)| {
+                                last == p
+                                    && now.duration_since(at).as_secs_f32() * 1000.0
+                                        <= thresholds.double_click_ms
+                                    && from.distance(event.position)
+                                        <= thresholds.double_click_distance
+                            });
+                            if doubled {
+                                current.last_click = None;
+                                tree.send_to(DoubleClicked::new(), p);
+                            } else {
+                                current.last_click = Some((p, now, event.position));
+                            }
+                        }
+                    }
+                    // Read after the stillness guard above has had its say, so a fast drag that
+                    // came to rest before letting go is not a swipe any more than it is a flick.
+                    if !listener.disabled()
+                        && current.past_drag
+                        && event.method != InteractionMethod::ScrollWheel
+                    {
+                        let velocity = current.velocity * -1.0;
+                        let speed = velocity.left().hypot(velocity.top());
+                        let travel = event.position - current.click.start;
+                        if speed > thresholds.swipe_velocity
+                            && travel.left().hypot(travel.top()) >= thresholds.swipe_distance
+                        {
+                            tree.send_to(
+                                Swiped::new(SwipeDirection::of(velocity, current.axis), velocity),
+                                p,
+                            );
                         }
                     }
                     tree.send_to(
@@ -811,18 +1004,22 @@ pub(crate) fn interactive_elements(
                 }
             }
             let past_drag = current.past_drag;
+            let long_pressed = current.long_pressed;
+            current.pressed_at = None;
             for ps in current.pass_through.drain(..) {
                 if let Ok(listener) = listeners.get_mut(ps) {
                     let data = all.get(ps).unwrap();
                     // same reasoning as the primary's own gate above.
                     if !listener.disabled()
                         && !past_drag
+                        && !long_pressed
                         && event.method != InteractionMethod::ScrollWheel
                     {
                         if InteractionListener::is_contained(
-                            *data.5,
+                            data.5,
                             *data.1,
                             *data.3,
+                            *data.6,
                             event.position,
                         ) {
                             tree.send_to(OnClick::new(), ps);
@@ -838,6 +1035,19 @@ pub(crate) fn interactive_elements(
             }
         }
     }
+    // Checked every frame rather than on input, because a long press is precisely the pointer
+    // *not* doing anything: the hold that crosses the threshold produces no event of its own.
+    if current.pressed
+        && !current.past_drag
+        && !current.long_pressed
+        && let (Some(p), Some(at)) = (current.primary, current.pressed_at)
+        && at.elapsed().as_secs_f32() * 1000.0 >= thresholds.long_press_ms
+    {
+        current.long_pressed = true;
+        if listeners.get(p).is_ok_and(|listener| !listener.disabled()) {
+            tree.send_to(LongPressed::new(), p);
+        }
+    }
 }
 #[foliage_macros::targeted_event]
 #[derive(Copy, Debug)]
@@ -14,6 +14,8 @@ mod color;
 mod coordinate;
 mod disable;
 mod enable;
+mod file_drop;
+mod fill;
 mod foliage;
 mod ginkgo;
 mod grid;
@@ -25,16 +27,21 @@ mod node;
 mod opacity;
 mod ops;
 mod panel;
+mod path;
 mod photosynthesis;
 mod platform;
 mod polygon;
 mod polyline;
 mod remove;
 mod rounding;
+mod shader;
+mod shadow;
+mod shortcut;
 mod text;
 mod text_input;
 mod texture;
 mod time;
+mod transform;
 mod tree;
 mod virtual_keyboard;
 mod visibility;
@@ -77,38 +84,49 @@ pub(crate) type Trigger<'w, 't, E, B = ()> = bevy_ecs::observer::On<'w, 't, E, B
 pub use alignment::{HorizontalAlignment, VerticalAlignment};
 pub use ash::ClearColor;
 pub use ash::clip::ClipToViewport;
+pub use ash::layer::Layer;
 pub use clipboard::Clipboard;
 pub use color::{CReprColor, Color, Luminance};
 pub use coordinate::elevation::{Elevation, ResolvedElevation};
 pub use disable::Disable;
 pub use enable::Enable;
+pub use fill::{Fill, Stops};
 pub use foliage::Foliage;
 pub use grid::{
     Adjust, Anchor, AnchorDeps, AnchorDescriptor, ConfigurationDescriptor, GridExt, Justify,
     LocationValue, ValueDescriptor,
 };
 pub use grid::{
-    AspectRatio, Grid, Layout, Location, ScrollMomentum, ScrollProgress, ScrollTo, Short, View,
-    anchor, text_content,
-    view::{DirectionalLock, OverscrollPropagation, ScrollAxes},
+    Align, AspectRatio, Breadth, Breakpoints, BreakpointsError, Flow, FlowAlign, FlowDirection,
+    FlowItem, FlowJustify, Grid, GridItem, GridPlace, GridTemplate, Layout, Location,
+    ScrollMomentum, ScrollProgress, ScrollTo, ScrollbarStyle, Short, Template, Track, View,
+    VirtualList, Zoom, ZoomTo, anchor, children_content, text_content,
+    view::{
+        DirectionalLock, OverscrollPropagation, ScrollAxes, ScrollSnap, Snap, Sticky, StickyEdge,
+    },
 };
 pub use icon::{Icon, IconId, IconMemory, IconSprout, IconValue};
 pub use image::{Image, ImageSprout, ImageView};
 pub use interaction::CurrentInteraction;
 pub use interaction::{
-    AxisCommitment, FocusBehavior, GestureAxis, InputSequence, Interaction, InteractionMethod,
-    InteractionPhase, InteractionPropagation, Key, Modifiers, OnClick, PhysicalInputSequence,
-    PhysicalKey, listener::InteractionListener, listener::InteractionShape,
+    AxisCommitment, CursorIcon, FocusBehavior, FocusRing, GestureAxis, GestureThresholds,
+    InputSequence, Interaction, InteractionMethod, InteractionPhase, InteractionPropagation, Key,
+    Modifiers, OnClick, PhysicalInputSequence, PhysicalKey, SwipeDirection, TabIndex,
+    listener::AlphaMask, listener::InteractionListener, listener::InteractionShape,
     listener::InteractionState,
 };
-pub use interaction::{Disengaged, DragStarted, Dragged, Engaged, Focused, Unfocused};
+pub use interaction::{
+    Disengaged, DoubleClicked, DragLeave, DragOver, DragStarted, Dragged, DropTarget, Dropped,
+    Engaged, Focused, LongPressed, Swiped, Unfocused,
+};
 pub use line::{Line, LineSprout, MIN_LINE_WEIGHT};
 pub use node::Bare;
 pub(crate) use node::{Children, Node, Parent};
 pub use opacity::Opacity;
 pub use ops::Named;
 pub use ops::{Keyring, Resolve, Resolved};
-pub use panel::{Outline, Panel, PanelSprout};
+pub use panel::{BackdropBlur, Outline, Panel, PanelSprout};
+pub use path::{FillRule, Path, PathSprout, Stroke, StrokeCap, StrokeJoin, SvgParseError};
 #[cfg(target_os = "android")]
 pub use platform::AndroidApp;
 pub use platform::AndroidConnection;
@@ -118,6 +136,9 @@ pub use polyline::{
     PolylineSprout, PolylineStyle,
 };
 pub use rounding::{Rounding, Side};
+pub use shader::{Shader, ShaderId, ShaderSprout, ShaderUniforms};
+pub use shadow::Shadow;
+pub use shortcut::Chord;
 pub use text::GlyphOffset;
 pub use text::monospaced::FontId;
 pub use text::{FontSize, GlyphColors, Text, TextSprout, TextValue};
@@ -127,6 +148,7 @@ pub use text_input::{
     TextInputStyle, keybindings::KeyBindings,
 };
 pub use time::{Moment, OnEnd, Time, TimeDelta, TimeMarker, Timer};
+pub use transform::Transform;
 pub(crate) use tree::{AsTree, TargetedEvent, Tree};
 pub use visibility::{InheritedVisibility, ResolvedVisibility, Visibility};
 pub use web_ext::{Extensions, HrefLink};
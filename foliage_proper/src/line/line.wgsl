@@ -9,12 +9,15 @@ struct Vertex {
     @location(3) layer: f32,
     @location(4) color: vec4f,
     @location(5) opacity: f32,
+    @location(6) linear: vec4f,
+    @location(7) translation: vec2f,
 };
 struct Fragment {
     @builtin(position) position: vec4<f32>,
     @location(0) color: vec4f,
     @location(1) left: vec4f,
     @location(2) right: vec4f,
+    @location(3) local: vec2f,
 };
 // Physical px the drawn quad is grown by past the line's true edge, on every side. The
 // rasterizer only produces a fragment where a pixel *center* lands inside the triangles, so
@@ -56,10 +59,11 @@ fn vertex_entry(vertex: Vertex) -> Fragment {
         grown += axis * sign(dot(rel, axis)) * AA_MARGIN;
     }
     return Fragment(
-        viewport * vec4f(grown, vertex.layer, 1.0),
+        viewport * vec4f(transformed(grown, vertex.linear, vertex.translation), vertex.layer, 1.0),
         vertex.color * vec4f(1.0, 1.0, 1.0, vertex.opacity),
         vertex.left,
         vertex.right,
+        grown,
     );
 }
 // Signed: positive inside the quad, negative out. Unsigned would do when the rasterizer only
@@ -106,10 +110,10 @@ fn fragment_entry(frag: Fragment) -> @location(0) vec4<f32> {
     // always done. A line's drawn width is therefore its stated width, not its stated width
     // less a fading px, and `Polyline` no longer has to shrink its joints to match.
     let edge_precision = min(0.5, half_weight);
-    let left_inclusion = signed_distance_to_edge(frag.left, frag.position.xy, center, edge_precision);
-    let top_inclusion = signed_distance_to_edge(top, frag.position.xy, center, edge_precision);
-    let right_inclusion = signed_distance_to_edge(frag.right, frag.position.xy, center, edge_precision);
-    let bot_inclusion = signed_distance_to_edge(bot, frag.position.xy, center, edge_precision);
+    let left_inclusion = signed_distance_to_edge(frag.left, frag.local, center, edge_precision);
+    let top_inclusion = signed_distance_to_edge(top, frag.local, center, edge_precision);
+    let right_inclusion = signed_distance_to_edge(frag.right, frag.local, center, edge_precision);
+    let bot_inclusion = signed_distance_to_edge(bot, frag.local, center, edge_precision);
     let inclusion = min(min(min(left_inclusion, top_inclusion), right_inclusion), bot_inclusion);
     // Linear, where every other shape here uses `smoothstep`. A thin line lands across two
     // pixel rows, and what has to stay constant as its centerline drifts between their
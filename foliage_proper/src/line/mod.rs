@@ -6,6 +6,7 @@ use crate::foliage::DiffMarkers;
 use crate::ginkgo::ScaleFactor;
 use crate::opacity::BlendedOpacity;
 use crate::remove::Remove;
+use crate::transform::ResolvedTransform;
 use crate::{
     Attachment, Author, Color, Component, Coordinates, Foliage, LeafSprout, Logical, Position,
     ResolvedElevation, Visibility,
@@ -40,6 +41,7 @@ impl Attachment for Line {
         foliage.differential::<LineQuad, ResolvedElevation>();
         foliage.differential::<LineQuad, ClipContext>();
         foliage.differential::<LineQuad, Color>();
+        foliage.differential::<LineQuad, ResolvedTransform>();
     }
 }
 /// Thinnest line this draws: one logical pixel, the thinnest that means anything.
@@ -180,8 +182,8 @@ impl EdgePoints {
 /// unexported: only `Line::distill_descriptor` ever constructs or touches one, so a
 /// self-intersecting/degenerate quad can only happen via a real bug in that one system,
 /// never from outside code reaching in through `Query<&mut LineQuad>` and hand-editing
-/// corners. There's deliberately no path to author one directly -- this framework doesn't
-/// support arbitrary/tessellated shapes, only the rectangle `Line` itself needs.
+/// corners. There's deliberately no path to author one directly -- arbitrary shapes are
+/// [`Path`](crate::Path)'s job, not this rectangle's.
 #[repr(C)]
 #[derive(Component, Pod, Zeroable, Copy, Clone, Debug, Default, PartialEq)]
 #[require(Differential<LineQuad, LineQuad>)]
@@ -189,6 +191,7 @@ impl EdgePoints {
 #[require(Color, Differential<LineQuad, Color>)]
 #[require(Differential<LineQuad, ResolvedElevation>)]
 #[require(Differential<LineQuad, BlendedOpacity>)]
+#[require(Differential<LineQuad, ResolvedTransform>)]
 #[require(Points<Logical>)]
 #[component(on_add = Self::on_add)]
 pub(crate) struct LineQuad {
@@ -6,12 +6,14 @@ use crate::ash::render::{Parameters, PipelineId, Render, RenderGroup, Renderer};
 use crate::ginkgo::Ginkgo;
 use crate::line::LineQuad;
 use crate::opacity::BlendedOpacity;
+use crate::transform::{CReprTransform, ResolvedTransform};
 use crate::{CReprColor, Color, Coordinates, Parent, ResolvedElevation};
 use bytemuck::{Pod, Zeroable};
 use std::collections::HashMap;
 use wgpu::{
     BindGroupDescriptor, BindGroupLayoutDescriptor, PipelineLayoutDescriptor, RenderPass,
-    RenderPipelineDescriptor, ShaderStages, VertexState, VertexStepMode, include_wgsl,
+    RenderPipelineDescriptor, ShaderModuleDescriptor, ShaderSource, ShaderStages, VertexState,
+    VertexStepMode,
 };
 
 #[repr(C)]
@@ -41,13 +43,24 @@ pub(crate) struct Group {
     elevations: InstanceBuffer<ResolvedElevation>,
     colors: InstanceBuffer<CReprColor>,
     opacities: InstanceBuffer<BlendedOpacity>,
+    transforms: InstanceBuffer<CReprTransform>,
 }
 impl Render for LineQuad {
     type Group = Group;
     type Resources = Resources;
 
     fn renderer(ginkgo: &Ginkgo) -> Renderer<Self> {
-        let shader = ginkgo.create_shader(include_wgsl!("line.wgsl"));
+        let shader = ginkgo.create_shader(ShaderModuleDescriptor {
+            label: Some("line-shader"),
+            source: ShaderSource::Wgsl(
+                format!(
+                    "{}{}",
+                    include_str!("../transform.wgsl"),
+                    include_str!("line.wgsl")
+                )
+                .into(),
+            ),
+        });
         let vertex_buffer = ginkgo.create_vertex_buffer(VERTICES);
         let bind_group_layout = ginkgo.create_bind_group_layout(&BindGroupLayoutDescriptor {
             label: Some("line-bind-group-layout"),
@@ -96,6 +109,10 @@ impl Render for LineQuad {
                         VertexStepMode::Instance,
                         &wgpu::vertex_attr_array![5 => Float32],
                     ),
+                    Ginkgo::vertex_buffer_layout::<CReprTransform>(
+                        VertexStepMode::Instance,
+                        &wgpu::vertex_attr_array![6 => Float32x4, 7 => Float32x2],
+                    ),
                 ],
             },
             primitive: Ginkgo::triangle_list_primitive(),
@@ -115,6 +132,7 @@ impl Render for LineQuad {
             elevations: InstanceBuffer::new(ginkgo, 1),
             colors: InstanceBuffer::new(ginkgo, 1),
             opacities: InstanceBuffer::new(ginkgo, 1),
+            transforms: InstanceBuffer::new(ginkgo, 1),
         };
         groups.insert(0, RenderGroup::new(group));
         Renderer {
@@ -145,6 +163,7 @@ impl Render for LineQuad {
                 queues.remove_attr::<LineQuad, ClipContext>(entity);
                 queues.remove_attr::<LineQuad, Color>(entity);
                 queues.remove_attr::<LineQuad, BlendedOpacity>(entity);
+                queues.remove_attr::<LineQuad, ResolvedTransform>(entity);
             }
         }
         for (entity, quad) in queues.attribute::<Self, Self>() {
@@ -175,17 +194,24 @@ impl Render for LineQuad {
             let id = entity.to_bits();
             group.group.opacities.queue(id, opacity);
         }
+        for (entity, transform) in queues.attribute::<Self, ResolvedTransform>() {
+            let id = entity.to_bits();
+            let sf = ginkgo.configuration().scale_factor.value();
+            group.group.transforms.queue(id, transform.c_repr(sf));
+        }
         if let Some(n) = group.coordinator.grown() {
             group.group.quads.grow(ginkgo, n);
             group.group.elevations.grow(ginkgo, n);
             group.group.colors.grow(ginkgo, n);
             group.group.opacities.grow(ginkgo, n);
+            group.group.transforms.grow(ginkgo, n);
         }
         for swap in group.coordinator.sort() {
             group.group.quads.swap(swap);
             group.group.elevations.swap(swap);
             group.group.colors.swap(swap);
             group.group.opacities.swap(swap);
+            group.group.transforms.swap(swap);
         }
         for (id, data) in group.group.quads.queued() {
             let order = group.coordinator.order(id);
@@ -203,10 +229,15 @@ impl Render for LineQuad {
             let order = group.coordinator.order(id);
             group.group.opacities.write_cpu(order, data);
         }
+        for (id, data) in group.group.transforms.queued() {
+            let order = group.coordinator.order(id);
+            group.group.transforms.write_cpu(order, data);
+        }
         group.group.quads.write_gpu(ginkgo);
         group.group.elevations.write_gpu(ginkgo);
         group.group.colors.write_gpu(ginkgo);
         group.group.opacities.write_gpu(ginkgo);
+        group.group.transforms.write_gpu(ginkgo);
         for node in group.coordinator.updated_nodes(PipelineId::Line, 0) {
             nodes.update(node);
         }
@@ -222,6 +253,7 @@ impl Render for LineQuad {
         render_pass.set_vertex_buffer(2, group.group.elevations.buffer.slice(..));
         render_pass.set_vertex_buffer(3, group.group.colors.buffer.slice(..));
         render_pass.set_vertex_buffer(4, group.group.opacities.buffer.slice(..));
+        render_pass.set_vertex_buffer(5, group.group.transforms.buffer.slice(..));
         render_pass.draw(0..VERTICES.len() as u32, parameters.range);
     }
 }
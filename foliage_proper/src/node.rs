@@ -8,19 +8,21 @@ use crate::Trigger;
 use crate::Visibility;
 use crate::ash::clip::ClipSection;
 use crate::interaction::CurrentInteraction;
+use crate::transform::ResolvedTransform;
 use crate::{
     Animation, Component, FocusBehavior, InteractionPropagation, InteractionShape, Location,
     Resolve, Tree,
 };
 use bevy_ecs::entity::Entity;
 use bevy_ecs::lifecycle::HookContext;
+use bevy_ecs::resource::Resource;
 use bevy_ecs::system::Query;
-use bevy_ecs::world::DeferredWorld;
+use bevy_ecs::world::{DeferredWorld, World};
 use std::collections::HashSet;
 
 #[derive(Component)]
 #[require(Parent, Children)]
-#[require(Opacity, Visibility, ClipSection)]
+#[require(Opacity, Visibility, ClipSection, ResolvedTransform)]
 #[require(Section<Logical>, LayoutSection, Elevation, InteractionShape, InteractionPropagation)]
 #[require(FocusBehavior)]
 #[component(on_add = Self::on_add)]
@@ -143,6 +145,31 @@ impl Default for Bare {
 #[derive(Component, Copy, Clone, Debug)]
 pub struct SpawnedAt(pub &'static core::panic::Location<'static>);
 
+/// Where this entity falls among everything grown, first to last.
+///
+/// Entity ids are recycled, so an id says nothing about which of two siblings came first --
+/// after a wither and a regrow the newer one can hold the lower id. Anything ordering
+/// siblings "as grown" reads this instead.
+#[derive(Component, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub(crate) struct GrowOrder(pub(crate) u64);
+/// The next [`GrowOrder`] to hand out.
+#[derive(Resource, Default)]
+pub(crate) struct GrowCount(u64);
+impl GrowOrder {
+    /// Numbers `entity` after everything grown before it. Queued behind the spawn, so it
+    /// lands on the entity the commands before it made.
+    pub(crate) fn assign(entity: Entity) -> impl FnOnce(&mut World) + Send + 'static {
+        move |world: &mut World| {
+            let mut count = world.get_resource_or_init::<GrowCount>();
+            count.0 += 1;
+            let order = GrowOrder(count.0);
+            if let Ok(mut e) = world.get_entity_mut(entity) {
+                e.insert(order);
+            }
+        }
+    }
+}
+
 /// This entity's parent, and so what its `Location` resolves against, what clips it,
 /// what its opacity and visibility inherit from, and where it sits in draw order.
 ///
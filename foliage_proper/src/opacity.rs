@@ -1,6 +1,7 @@
 use crate::AsTree;
 use crate::Trigger;
 use crate::anim::interpolation::Interpolations;
+use crate::ash::layer::Layer;
 use crate::{Animate, Attachment, Children, Component, Foliage, Parent, Tree};
 use bevy_ecs::event::EntityEvent;
 use bevy_ecs::lifecycle::HookContext;
@@ -16,7 +17,8 @@ use bevy_ecs::world::DeferredWorld;
 ///
 /// Multiplies down the `Parent` chain: a child at `0.5` inside a parent at `0.5` renders at
 /// `0.25`, so fading a container fades everything in it as one. The product the renderer
-/// reads is `BlendedOpacity` (engine-internal).
+/// reads is `BlendedOpacity` (engine-internal). Inside a [`layer`](crate::Sprout::layer) the
+/// product restarts at the layer's root, whose own share fades the layer as one.
 ///
 /// Animatable, and the usual way to fade UI in and out. Distinct from a color's own alpha
 /// ([`Color::with_opacity`](crate::Color::with_opacity)), which is a fixed property of
@@ -58,7 +60,13 @@ impl Opacity {
         let this = ctx.entity;
         let inherited = world.get::<InheritedOpacity>(this).unwrap();
         let current = world.get::<Opacity>(this).unwrap();
-        let blended = BlendedOpacity::new(inherited.value * current.value);
+        // A layer's fade is laid over its texture once, at the composite; inside it, the
+        // root and everything beneath draw as if unfaded.
+        let blended = if world.get::<Layer>(this).is_some() {
+            BlendedOpacity::new(1.0)
+        } else {
+            BlendedOpacity::new(inherited.value * current.value)
+        };
         world.tree().write_to(this, blended);
         let deps = world.get::<Children>(this).unwrap().ids.clone();
         for d in deps.iter() {
@@ -0,0 +1,52 @@
+// A panel's `BackdropBlur`: what `ash` blurred beneath it, laid down in the panel's shape at
+// its depth, just before the panel itself is drawn over it.
+struct Blur {
+    direction: vec2<f32>,
+    sigma: f32,
+    opacity: f32,
+};
+@group(0)
+@binding(0)
+var<uniform> viewport: mat4x4<f32>;
+@group(1)
+@binding(0)
+var backdrop: texture_2d<f32>;
+@group(1)
+@binding(1)
+var backdrop_sampler: sampler;
+@group(1)
+@binding(2)
+var<uniform> blur: Blur;
+struct Vertex {
+    @location(0) vertex_pos: vec2<f32>,
+    @location(1) section: vec4<f32>,
+    @location(2) layer_and_weight: vec2<f32>,
+    @location(4) radii: vec4<f32>,
+    @location(5) linear: vec4<f32>,
+    @location(6) translation: vec2<f32>,
+};
+struct Fragment {
+    @builtin(position) position: vec4<f32>,
+    @location(0) @interpolate(flat) section: vec4<f32>,
+    @location(1) @interpolate(flat) radii: vec4<f32>,
+    @location(2) local: vec2<f32>,
+};
+@vertex
+fn vertex_entry(vertex: Vertex) -> Fragment {
+    let local = vertex.section.xy + vertex.vertex_pos * vertex.section.zw;
+    let position = vec4<f32>(
+        transformed(local, vertex.linear, vertex.translation),
+        vertex.layer_and_weight.x,
+        1.0
+    );
+    return Fragment(viewport * position, vertex.section, vertex.radii, local);
+}
+@fragment
+fn fragment_entry(frag: Fragment) -> @location(0) vec4<f32> {
+    let half_extent = frag.section.zw * 0.5;
+    let p = frag.local - (frag.section.xy + half_extent);
+    let coverage = sd_coverage(sd_rounded_box(p, half_extent, frag.radii));
+    let size = vec2<f32>(textureDimensions(backdrop));
+    let behind = textureSampleLevel(backdrop, backdrop_sampler, frag.position.xy / size, 0.0);
+    return vec4<f32>(behind.rgb, coverage * blur.opacity);
+}
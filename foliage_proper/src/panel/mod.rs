@@ -6,9 +6,10 @@ use crate::ginkgo::ScaleFactor;
 use crate::opacity::BlendedOpacity;
 use crate::remove::Remove;
 use crate::rounding::CornerRadii;
+use crate::transform::ResolvedTransform;
 use crate::{
-    Animate, Attachment, Color, Component, Differential, Foliage, Logical, Resolve, Resolved,
-    ResolvedElevation, Rounding, Section, Side, Tree, Visibility,
+    Animate, Attachment, Color, Component, Differential, Fill, Foliage, Logical, Resolve, Resolved,
+    ResolvedElevation, Rounding, Section, Shadow, Side, Tree, Visibility,
 };
 use bevy_ecs::bundle::Bundle;
 use bevy_ecs::lifecycle::HookContext;
@@ -19,14 +20,18 @@ mod pipeline;
 mod vertex;
 
 #[derive(Component, Copy, Clone, Default, PartialEq)]
-#[require(Rounding, Side, Color, Outline)]
+#[require(Rounding, Side, Color, Fill, Shadow, Outline, BackdropBlur)]
 #[require(Differential<Self, ResolvedElevation>)]
 #[require(Differential<Self, Color>)]
+#[require(Differential<Self, Fill>)]
+#[require(Differential<Self, Shadow>)]
 #[require(Differential<Self, Panel>)]
 #[require(Differential<Self, Outline>)]
+#[require(Differential<Self, BackdropBlur>)]
 #[require(Differential<Self, Section<Logical>>)]
 #[require(Differential<Self, BlendedOpacity>)]
 #[require(Differential<Self, ClipContext>)]
+#[require(Differential<Self, ResolvedTransform>)]
 #[component(on_add = Self::on_add)]
 #[component(on_insert = Self::on_insert)]
 /// A filled rectangle with optionally rounded corners and an optional outline -- the
@@ -37,9 +42,9 @@ mod vertex;
 /// fill). The radii the shader consumes are resolved from those into [`CornerRadii`],
 /// never authored -- see `Panel::update`.
 ///
-/// [`Rounding::Full`] also switches the entity's hit test to
-/// [`InteractionShape::Circle`](crate::InteractionShape), so a pill or dot only responds
-/// where it is actually drawn.
+/// Any [`Rounding`] also switches the entity's hit test to
+/// [`InteractionShape::Rounded`](crate::InteractionShape::Rounded), so a card's corners, a
+/// pill or a dot only respond where they are actually drawn.
 pub struct Panel {
     pub(crate) radii: CornerRadii,
 }
@@ -102,10 +107,15 @@ impl Attachment for Panel {
         foliage.differential::<Self, BlendedOpacity>();
         foliage.differential::<Self, Panel>();
         foliage.differential::<Self, Color>();
+        foliage.differential::<Self, Fill>();
+        foliage.differential::<Self, Shadow>();
         foliage.differential::<Self, Outline>();
+        foliage.differential::<Self, BackdropBlur>();
         foliage.differential::<Self, ResolvedElevation>();
         foliage.differential::<Self, ClipContext>();
+        foliage.differential::<Self, ResolvedTransform>();
         foliage.enable_animation::<Outline>();
+        foliage.enable_animation::<BackdropBlur>();
     }
 }
 /// Builder for a [`Panel`] entity -- see [`Panel::new`].
@@ -113,9 +123,12 @@ impl Attachment for Panel {
 pub struct PanelSprout {
     leaf: crate::LeafSprout,
     color: Option<Color>,
+    fill: Option<Fill>,
+    shadow: Option<Shadow>,
     rounding: Option<Rounding>,
     side: Option<Side>,
     outline: Option<i32>,
+    backdrop_blur: Option<f32>,
 }
 impl crate::Author for PanelSprout {
     fn seed(&mut self) -> &mut crate::LeafSprout {
@@ -125,9 +138,14 @@ impl crate::Author for PanelSprout {
         (
             Panel::new_marker(),
             self.color.unwrap_or_default(),
+            self.fill.unwrap_or_default(),
+            self.shadow.unwrap_or_default(),
             self.rounding.unwrap_or_default(),
             self.side.unwrap_or_default(),
             self.outline.map(Outline::new).unwrap_or_default(),
+            self.backdrop_blur
+                .map(BackdropBlur::new)
+                .unwrap_or_default(),
         )
     }
 }
@@ -137,6 +155,16 @@ impl PanelSprout {
         self.color = Some(c);
         self
     }
+    /// A gradient in place of the [`color`](Self::color) -- see [`Fill`].
+    pub fn fill(mut self, f: Fill) -> Self {
+        self.fill = Some(f);
+        self
+    }
+    /// A soft shadow beneath, following the rounding -- see [`Shadow`].
+    pub fn shadow(mut self, s: Shadow) -> Self {
+        self.shadow = Some(s);
+        self
+    }
     /// Corner radius bracket. Applies to the corners [`Side`] names; defaults to square.
     pub fn rounding(mut self, r: Rounding) -> Self {
         self.rounding = Some(r);
@@ -153,6 +181,12 @@ impl PanelSprout {
         self.outline = Some(w);
         self
     }
+    /// Frosts what is drawn beneath, blurred by `radius` logical pixels -- see
+    /// [`BackdropBlur`]. Give the panel a translucent [`color`](Self::color) to see it.
+    pub fn backdrop_blur(mut self, radius: f32) -> Self {
+        self.backdrop_blur = Some(radius);
+        self
+    }
 }
 /// Draws the panel as a ring of this width in logical pixels instead of a solid fill.
 /// Animatable, so a border can be drawn on.
@@ -188,3 +222,39 @@ impl Animate for Outline {
         }
     }
 }
+/// Blurs whatever is drawn beneath a [`Panel`] before the panel's own color is laid over
+/// it -- the frosted glass that overlays, drawers and modals sit on.
+///
+/// `radius` is in logical pixels and reads as CSS's `backdrop-filter: blur()` does: the
+/// Gaussian's deviation. `0.0`, the default, blurs nothing. The blur only shows through as
+/// much as the panel's [`Color`] lets it, so pair it with a translucent one. It follows the
+/// panel's rounding and [`Transform`](crate::Transform), and fades with its
+/// [`Opacity`](crate::Opacity). Animatable as
+/// [`Motion::BackdropBlur`](crate::Motion::BackdropBlur), so a modal's scrim can frost in.
+///
+/// Each blurring panel breaks the frame's render pass to read back what is under it, so
+/// keep it to the few surfaces that need it. Where the surface cannot be read back, the
+/// panel draws as though it had none.
+#[derive(Component, Copy, Clone, PartialEq, Default)]
+pub struct BackdropBlur {
+    pub radius: f32,
+}
+impl BackdropBlur {
+    /// Blurred by `radius` logical pixels; `0.0` and below is none.
+    pub fn new(radius: f32) -> BackdropBlur {
+        BackdropBlur {
+            radius: radius.max(0.0),
+        }
+    }
+}
+impl Animate for BackdropBlur {
+    fn interpolations(start: &Self, end: &Self) -> Interpolations {
+        Interpolations::new().with(start.radius, end.radius)
+    }
+
+    fn apply(&mut self, interpolations: &mut Interpolations) {
+        if let Some(r) = interpolations.read(0) {
+            self.radius = r;
+        }
+    }
+}
@@ -7,6 +7,16 @@ struct Vertex {
     @location(2) layer_and_weight: vec2<f32>,
     @location(3) color: vec4<f32>,
     @location(4) radii: vec4<f32>,
+    @location(5) linear: vec4<f32>,
+    @location(6) translation: vec2<f32>,
+    @location(7) fill_geometry: vec4<f32>,
+    @location(8) fill_offsets: vec4<f32>,
+    @location(9) fill_c0: vec4<f32>,
+    @location(10) fill_c1: vec4<f32>,
+    @location(11) fill_c2: vec4<f32>,
+    @location(12) fill_c3: vec4<f32>,
+    @location(13) shadow_color: vec4<f32>,
+    @location(14) shadow_geometry: vec4<f32>,
 };
 struct Fragment {
     @builtin(position) position: vec4<f32>,
@@ -14,11 +24,23 @@ struct Fragment {
     @location(1) @interpolate(flat) weight: f32,
     @location(2) @interpolate(flat) section: vec4<f32>,
     @location(3) @interpolate(flat) radii: vec4<f32>,
+    @location(4) local: vec2<f32>,
+    @location(5) @interpolate(flat) fill_geometry: vec4<f32>,
+    @location(6) @interpolate(flat) fill_offsets: vec4<f32>,
+    @location(7) @interpolate(flat) fill_c0: vec4<f32>,
+    @location(8) @interpolate(flat) fill_c1: vec4<f32>,
+    @location(9) @interpolate(flat) fill_c2: vec4<f32>,
+    @location(10) @interpolate(flat) fill_c3: vec4<f32>,
+    @location(11) @interpolate(flat) shadow_color: vec4<f32>,
+    @location(12) @interpolate(flat) shadow_geometry: vec4<f32>,
 };
 @vertex
 fn vertex_entry(vertex: Vertex) -> Fragment {
+    // Grown past the section to hold the shadow; everything the fill draws is still inside it.
+    let reach = shadow_reach(vertex.shadow_color, vertex.shadow_geometry);
+    let local = vertex.section.xy - reach + vertex.vertex_pos * (vertex.section.zw + 2.0 * reach);
     let position = vec4<f32>(
-        vertex.section.xy + vertex.vertex_pos * vertex.section.zw,
+        transformed(local, vertex.linear, vertex.translation),
         vertex.layer_and_weight.x,
         1.0
     );
@@ -27,21 +49,53 @@ fn vertex_entry(vertex: Vertex) -> Fragment {
         vertex.color,
         vertex.layer_and_weight.y,
         vertex.section,
-        vertex.radii
+        vertex.radii,
+        local,
+        vertex.fill_geometry,
+        vertex.fill_offsets,
+        vertex.fill_c0,
+        vertex.fill_c1,
+        vertex.fill_c2,
+        vertex.fill_c3,
+        vertex.shadow_color,
+        vertex.shadow_geometry
     );
 }
 @fragment
 fn fragment_entry(frag: Fragment) -> @location(0) vec4<f32> {
     let half_extent = frag.section.zw * 0.5;
-    var d = sd_rounded_box(
-        frag.position.xy - (frag.section.xy + half_extent),
-        half_extent,
-        frag.radii
-    );
+    let p = frag.local - (frag.section.xy + half_extent);
+    let solid = sd_rounded_box(p, half_extent, frag.radii);
+    var d = solid;
     // A negative weight is `Outline`'s "no outline" -- the only reading that leaves a
     // zero-width outline meaning a hairline rather than a solid fill.
     if frag.weight >= 0.0 {
         d = sd_outline(d, frag.weight);
     }
-    return vec4<f32>(frag.color.rgb, frag.color.a * sd_coverage(d));
+    let color = filled(
+        frag.color,
+        frag.local,
+        frag.section,
+        frag.fill_geometry,
+        frag.fill_offsets,
+        frag.fill_c0,
+        frag.fill_c1,
+        frag.fill_c2,
+        frag.fill_c3
+    );
+    let spread = frag.shadow_geometry.w;
+    let caster = sd_rounded_box(
+        p - frag.shadow_geometry.xy,
+        max(half_extent + spread, vec2<f32>(0.0)),
+        max(frag.radii + spread, vec4<f32>(0.0))
+    );
+    let shadow = vec4<f32>(
+        frag.shadow_color.rgb,
+        frag.shadow_color.a * shadow_coverage(caster, frag.shadow_geometry.z)
+    );
+    return over_shadow(
+        vec4<f32>(color.rgb, color.a * sd_coverage(d)),
+        shadow,
+        sd_coverage(solid)
+    );
 }
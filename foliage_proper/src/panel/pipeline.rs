@@ -1,35 +1,71 @@
+use crate::ash::backdrop::{Backdrop, BackdropRegion};
 use crate::ash::clip::ClipContext;
 use crate::ash::differential::RenderQueueHandle;
-use crate::ash::instance::{Instance, InstanceBuffer};
+use crate::ash::instance::{Instance, InstanceBuffer, InstanceId};
 use crate::ash::node::{Nodes, RemoveNode};
 use crate::ash::render::{Parameters, PipelineId, Render, RenderGroup, Renderer};
+use crate::fill::CReprFill;
 use crate::ginkgo::Ginkgo;
 use crate::opacity::BlendedOpacity;
 use crate::panel::vertex;
 use crate::rounding::CornerRadii;
+use crate::shadow::CReprShadow;
+use crate::transform::{CReprTransform, ResolvedTransform};
 use crate::{
-    CReprColor, CReprSection, Color, Coordinates, Logical, Outline, Panel, Parent,
-    ResolvedElevation, Section,
+    BackdropBlur, CReprColor, CReprSection, Color, Coordinates, Fill, Logical, Outline, Panel,
+    Parent, Physical, ResolvedElevation, Section, Shadow,
 };
 use bevy_ecs::entity::Entity;
 use bytemuck::{Pod, Zeroable};
 use std::collections::HashMap;
 use wgpu::{
-    BindGroupDescriptor, BindGroupLayoutDescriptor, PipelineLayoutDescriptor, RenderPass,
-    RenderPipelineDescriptor, ShaderModuleDescriptor, ShaderSource, ShaderStages, VertexState,
-    VertexStepMode,
+    BindGroup, BindGroupDescriptor, BindGroupLayout, BindGroupLayoutDescriptor,
+    PipelineLayoutDescriptor, RenderPass, RenderPipeline, RenderPipelineDescriptor,
+    ShaderModuleDescriptor, ShaderSource, ShaderStages, VertexState, VertexStepMode,
 };
 
 pub(crate) struct Resources {
     layer_and_weights: HashMap<Entity, LayerAndWeight>,
     opacity: HashMap<Entity, BlendedOpacity>,
     color: HashMap<Entity, Color>,
+    fill: HashMap<Entity, Fill>,
+    shadow: HashMap<Entity, Shadow>,
+    /// Each blurring panel's deviation in physical pixels; a panel without one is absent.
+    backdrop: HashMap<Entity, f32>,
+    /// Where each panel is drawn, in physical pixels -- kept for the box a blur covers.
+    sections: HashMap<Entity, Section<Physical>>,
+    transforms: HashMap<Entity, ResolvedTransform>,
+    /// Set when a panel starts or stops blurring, so `ash` splits its spans afresh.
+    pub(crate) backdrop_changed: bool,
+    backdrop_pipeline: RenderPipeline,
+    pub(crate) backdrop_layout: BindGroupLayout,
+}
+impl Resources {
+    /// The [`BackdropBlur`] beneath instance `id`, if it has one.
+    pub(crate) fn backdrop(&self, id: InstanceId) -> Option<BackdropRegion> {
+        let entity = Entity::from_bits(id);
+        let sigma = *self.backdrop.get(&entity)?;
+        let section = *self.sections.get(&entity)?;
+        let bounds = self
+            .transforms
+            .get(&entity)
+            .map(|t| t.bounds(section))
+            .unwrap_or(section);
+        Some(BackdropRegion {
+            sigma,
+            bounds,
+            opacity: self.opacity.get(&entity).map(|o| o.value).unwrap_or(1.0),
+        })
+    }
 }
 pub(crate) struct Group {
     sections: InstanceBuffer<CReprSection>,
     lws: InstanceBuffer<LayerAndWeight>,
     colors: InstanceBuffer<CReprColor>,
     radii: InstanceBuffer<CornerRadii>,
+    transforms: InstanceBuffer<CReprTransform>,
+    fills: InstanceBuffer<CReprFill>,
+    shadows: InstanceBuffer<CReprShadow>,
 }
 #[repr(C)]
 #[derive(Pod, Zeroable, Copy, Clone, Debug, Default)]
@@ -51,8 +87,11 @@ impl Render for Panel {
             label: Some("panel-shader"),
             source: ShaderSource::Wgsl(
                 format!(
-                    "{}{}",
+                    "{}{}{}{}{}",
                     include_str!("../sdf.wgsl"),
+                    include_str!("../transform.wgsl"),
+                    include_str!("../fill.wgsl"),
+                    include_str!("../shadow.wgsl"),
                     include_str!("panel.wgsl")
                 )
                 .into(),
@@ -103,6 +142,25 @@ impl Render for Panel {
                         VertexStepMode::Instance,
                         &wgpu::vertex_attr_array![4 => Float32x4],
                     ),
+                    Ginkgo::vertex_buffer_layout::<CReprTransform>(
+                        VertexStepMode::Instance,
+                        &wgpu::vertex_attr_array![5 => Float32x4, 6 => Float32x2],
+                    ),
+                    Ginkgo::vertex_buffer_layout::<CReprFill>(
+                        VertexStepMode::Instance,
+                        &wgpu::vertex_attr_array![
+                            7 => Float32x4,
+                            8 => Float32x4,
+                            9 => Float32x4,
+                            10 => Float32x4,
+                            11 => Float32x4,
+                            12 => Float32x4
+                        ],
+                    ),
+                    Ginkgo::vertex_buffer_layout::<CReprShadow>(
+                        VertexStepMode::Instance,
+                        &wgpu::vertex_attr_array![13 => Float32x4, 14 => Float32x4],
+                    ),
                 ],
             },
             primitive: Ginkgo::triangle_list_primitive(),
@@ -116,6 +174,71 @@ impl Render for Panel {
             multiview_mask: None,
             cache: None,
         });
+        let backdrop_shader = ginkgo.create_shader(ShaderModuleDescriptor {
+            label: Some("panel-backdrop-shader"),
+            source: ShaderSource::Wgsl(
+                format!(
+                    "{}{}{}",
+                    include_str!("../sdf.wgsl"),
+                    include_str!("../transform.wgsl"),
+                    include_str!("backdrop.wgsl")
+                )
+                .into(),
+            ),
+        });
+        let backdrop_layout = Backdrop::layout(ginkgo);
+        let backdrop_pipeline_layout = ginkgo.create_pipeline_layout(&PipelineLayoutDescriptor {
+            label: Some("panel-backdrop-pipeline-layout-descriptor"),
+            bind_group_layouts: &[Some(&bind_group_layout), Some(&backdrop_layout)],
+            immediate_size: 0,
+        });
+        // The same buffers in the same slots as the panel's own pipeline, short of the fill
+        // and shadow, which the blur beneath has no use for.
+        let backdrop_pipeline = ginkgo.create_pipeline(&RenderPipelineDescriptor {
+            label: Some("panel-backdrop-render-pipeline"),
+            layout: Option::from(&backdrop_pipeline_layout),
+            vertex: VertexState {
+                module: &backdrop_shader,
+                entry_point: Option::from("vertex_entry"),
+                compilation_options: Default::default(),
+                buffers: &[
+                    Ginkgo::vertex_buffer_layout::<Coordinates>(
+                        VertexStepMode::Vertex,
+                        &wgpu::vertex_attr_array![0 => Float32x2],
+                    ),
+                    Ginkgo::vertex_buffer_layout::<CReprSection>(
+                        VertexStepMode::Instance,
+                        &wgpu::vertex_attr_array![1 => Float32x4],
+                    ),
+                    Ginkgo::vertex_buffer_layout::<LayerAndWeight>(
+                        VertexStepMode::Instance,
+                        &wgpu::vertex_attr_array![2 => Float32x2],
+                    ),
+                    Ginkgo::vertex_buffer_layout::<CReprColor>(
+                        VertexStepMode::Instance,
+                        &wgpu::vertex_attr_array![3 => Float32x4],
+                    ),
+                    Ginkgo::vertex_buffer_layout::<CornerRadii>(
+                        VertexStepMode::Instance,
+                        &wgpu::vertex_attr_array![4 => Float32x4],
+                    ),
+                    Ginkgo::vertex_buffer_layout::<CReprTransform>(
+                        VertexStepMode::Instance,
+                        &wgpu::vertex_attr_array![5 => Float32x4, 6 => Float32x2],
+                    ),
+                ],
+            },
+            primitive: Ginkgo::triangle_list_primitive(),
+            depth_stencil: ginkgo.depth_stencil_state(),
+            multisample: ginkgo.msaa_state(),
+            fragment: Ginkgo::fragment_state(
+                &backdrop_shader,
+                "fragment_entry",
+                &ginkgo.alpha_color_target_state(),
+            ),
+            multiview_mask: None,
+            cache: None,
+        });
         Renderer {
             pipeline,
             vertex_buffer,
@@ -129,6 +252,9 @@ impl Render for Panel {
                         lws: InstanceBuffer::new(ginkgo, 10),
                         colors: InstanceBuffer::new(ginkgo, 10),
                         radii: InstanceBuffer::new(ginkgo, 10),
+                        transforms: InstanceBuffer::new(ginkgo, 10),
+                        fills: InstanceBuffer::new(ginkgo, 10),
+                        shadows: InstanceBuffer::new(ginkgo, 10),
                     }),
                 );
                 groups
@@ -137,6 +263,14 @@ impl Render for Panel {
                 layer_and_weights: HashMap::new(),
                 opacity: Default::default(),
                 color: Default::default(),
+                fill: Default::default(),
+                shadow: Default::default(),
+                backdrop: Default::default(),
+                sections: Default::default(),
+                transforms: Default::default(),
+                backdrop_changed: false,
+                backdrop_pipeline,
+                backdrop_layout,
             },
         }
     }
@@ -152,6 +286,13 @@ impl Render for Panel {
         for r in queues.removes::<Panel>() {
             if render_group.coordinator.has_instance(r.to_bits()) {
                 renderer.resources.layer_and_weights.remove(&r);
+                renderer.resources.fill.remove(&r);
+                renderer.resources.shadow.remove(&r);
+                renderer.resources.sections.remove(&r);
+                renderer.resources.transforms.remove(&r);
+                if renderer.resources.backdrop.remove(&r).is_some() {
+                    renderer.resources.backdrop_changed = true;
+                }
                 nodes.remove(RemoveNode::new(PipelineId::Panel, 0, r.to_bits()));
                 let order = render_group.coordinator.order(r.to_bits());
                 render_group.coordinator.remove(order);
@@ -162,6 +303,10 @@ impl Render for Panel {
                 queues.remove_attr::<Panel, BlendedOpacity>(r);
                 queues.remove_attr::<Panel, Color>(r);
                 queues.remove_attr::<Panel, Panel>(r);
+                queues.remove_attr::<Panel, ResolvedTransform>(r);
+                queues.remove_attr::<Panel, Fill>(r);
+                queues.remove_attr::<Panel, Shadow>(r);
+                queues.remove_attr::<Panel, BackdropBlur>(r);
             }
         }
         for (entity, elevation) in queues.attribute::<Panel, ResolvedElevation>() {
@@ -184,13 +329,14 @@ impl Render for Panel {
             render_group.group.lws.queue(id, lw);
         }
         for (entity, section) in queues.attribute::<Self, Section<Logical>>() {
-            render_group.group.sections.queue(
-                entity.to_bits(),
-                section
-                    .to_physical(ginkgo.configuration().scale_factor.value())
-                    .rounded()
-                    .c_repr(),
-            );
+            let section = section
+                .to_physical(ginkgo.configuration().scale_factor.value())
+                .rounded();
+            renderer.resources.sections.insert(entity, section);
+            render_group
+                .group
+                .sections
+                .queue(entity.to_bits(), section.c_repr());
         }
         for (entity, clip_context) in queues.attribute::<Panel, ClipContext>() {
             render_group
@@ -215,6 +361,19 @@ impl Render for Panel {
                     .colors
                     .queue(entity.to_bits(), color.with_opacity(opacity.value).c_repr())
             }
+            if let Some(fill) = renderer.resources.fill.get(&entity) {
+                render_group
+                    .group
+                    .fills
+                    .queue(entity.to_bits(), fill.c_repr(opacity.value))
+            }
+            if let Some(shadow) = renderer.resources.shadow.get(&entity) {
+                let sf = ginkgo.configuration().scale_factor.value();
+                render_group
+                    .group
+                    .shadows
+                    .queue(entity.to_bits(), shadow.c_repr(opacity.value, sf))
+            }
         }
         for (entity, color) in queues.attribute::<Self, Color>() {
             renderer.resources.color.insert(entity, color);
@@ -224,23 +383,68 @@ impl Render for Panel {
                 .colors
                 .queue(entity.to_bits(), color.with_opacity(opacity.value).c_repr());
         }
+        for (entity, fill) in queues.attribute::<Self, Fill>() {
+            renderer.resources.fill.insert(entity, fill);
+            let opacity = renderer.resources.opacity.get(&entity).unwrap();
+            render_group
+                .group
+                .fills
+                .queue(entity.to_bits(), fill.c_repr(opacity.value));
+        }
+        for (entity, shadow) in queues.attribute::<Self, Shadow>() {
+            renderer.resources.shadow.insert(entity, shadow);
+            let opacity = renderer.resources.opacity.get(&entity).unwrap();
+            let sf = ginkgo.configuration().scale_factor.value();
+            render_group
+                .group
+                .shadows
+                .queue(entity.to_bits(), shadow.c_repr(opacity.value, sf));
+        }
         for (entity, panel) in queues.attribute::<Self, Self>() {
             render_group
                 .group
                 .radii
                 .queue(entity.to_bits(), panel.radii);
         }
+        for (entity, blur) in queues.attribute::<Self, BackdropBlur>() {
+            let had = if blur.radius > 0.0 {
+                let sigma = blur.radius * ginkgo.configuration().scale_factor.value();
+                renderer.resources.backdrop.insert(entity, sigma)
+            } else {
+                renderer.resources.backdrop.remove(&entity)
+            };
+            if had.is_some() != (blur.radius > 0.0) {
+                renderer.resources.backdrop_changed = true;
+            }
+        }
+        for (entity, transform) in queues.attribute::<Self, ResolvedTransform>() {
+            let sf = ginkgo.configuration().scale_factor.value();
+            renderer
+                .resources
+                .transforms
+                .insert(entity, transform.to_physical(sf));
+            render_group.group.transforms.queue(
+                entity.to_bits(),
+                transform.c_repr(ginkgo.configuration().scale_factor.value()),
+            );
+        }
         if let Some(n) = render_group.coordinator.grown() {
             render_group.group.sections.grow(ginkgo, n);
             render_group.group.lws.grow(ginkgo, n);
             render_group.group.colors.grow(ginkgo, n);
             render_group.group.radii.grow(ginkgo, n);
+            render_group.group.transforms.grow(ginkgo, n);
+            render_group.group.fills.grow(ginkgo, n);
+            render_group.group.shadows.grow(ginkgo, n);
         }
         for swap in render_group.coordinator.sort() {
             render_group.group.sections.swap(swap);
             render_group.group.lws.swap(swap);
             render_group.group.colors.swap(swap);
             render_group.group.radii.swap(swap);
+            render_group.group.transforms.swap(swap);
+            render_group.group.fills.swap(swap);
+            render_group.group.shadows.swap(swap);
         }
         for (id, data) in render_group.group.sections.queued() {
             let order = render_group.coordinator.order(id);
@@ -258,10 +462,25 @@ impl Render for Panel {
             let order = render_group.coordinator.order(id);
             render_group.group.radii.write_cpu(order, data);
         }
+        for (id, data) in render_group.group.transforms.queued() {
+            let order = render_group.coordinator.order(id);
+            render_group.group.transforms.write_cpu(order, data);
+        }
+        for (id, data) in render_group.group.fills.queued() {
+            let order = render_group.coordinator.order(id);
+            render_group.group.fills.write_cpu(order, data);
+        }
+        for (id, data) in render_group.group.shadows.queued() {
+            let order = render_group.coordinator.order(id);
+            render_group.group.shadows.write_cpu(order, data);
+        }
         render_group.group.sections.write_gpu(ginkgo);
         render_group.group.lws.write_gpu(ginkgo);
         render_group.group.colors.write_gpu(ginkgo);
         render_group.group.radii.write_gpu(ginkgo);
+        render_group.group.transforms.write_gpu(ginkgo);
+        render_group.group.fills.write_gpu(ginkgo);
+        render_group.group.shadows.write_gpu(ginkgo);
         for node in render_group.coordinator.updated_nodes(PipelineId::Panel, 0) {
             nodes.update(node);
         }
@@ -277,6 +496,31 @@ impl Render for Panel {
         render_pass.set_vertex_buffer(2, group.lws.buffer.slice(..));
         render_pass.set_vertex_buffer(3, group.colors.buffer.slice(..));
         render_pass.set_vertex_buffer(4, group.radii.buffer.slice(..));
+        render_pass.set_vertex_buffer(5, group.transforms.buffer.slice(..));
+        render_pass.set_vertex_buffer(6, group.fills.buffer.slice(..));
+        render_pass.set_vertex_buffer(7, group.shadows.buffer.slice(..));
+        render_pass.draw(0..vertex::VERTICES.len() as u32, parameters.range);
+    }
+}
+impl Panel {
+    /// Lays the blur `backdrop` holds beneath the panels in `parameters`, in their shape and
+    /// at their depth, for [`Render::render`] to draw them over.
+    pub(crate) fn render_backdrop(
+        renderer: &Renderer<Self>,
+        render_pass: &mut RenderPass,
+        parameters: Parameters,
+        backdrop: &BindGroup,
+    ) {
+        render_pass.set_pipeline(&renderer.resources.backdrop_pipeline);
+        render_pass.set_bind_group(0, &renderer.bind_group, &[]);
+        render_pass.set_bind_group(1, backdrop, &[]);
+        render_pass.set_vertex_buffer(0, renderer.vertex_buffer.slice(..));
+        let group = &renderer.groups.get(&0).unwrap().group;
+        render_pass.set_vertex_buffer(1, group.sections.buffer.slice(..));
+        render_pass.set_vertex_buffer(2, group.lws.buffer.slice(..));
+        render_pass.set_vertex_buffer(3, group.colors.buffer.slice(..));
+        render_pass.set_vertex_buffer(4, group.radii.buffer.slice(..));
+        render_pass.set_vertex_buffer(5, group.transforms.buffer.slice(..));
         render_pass.draw(0..vertex::VERTICES.len() as u32, parameters.range);
     }
 }
@@ -0,0 +1,379 @@
+use crate::AsTree;
+use crate::DashPattern;
+use crate::ash::clip::ClipContext;
+use crate::foliage::DiffMarkers;
+use crate::ginkgo::ScaleFactor;
+use crate::opacity::BlendedOpacity;
+use crate::path::tessellate::PathTriangle;
+use crate::remove::Remove;
+use crate::transform::ResolvedTransform;
+use crate::{
+    Attachment, Author, Color, Component, Differential, Foliage, LeafSprout, Logical,
+    ResolvedElevation, Section, Visibility,
+};
+use bevy_ecs::bundle::Bundle;
+use bevy_ecs::change_detection::{DetectChanges, Res};
+use bevy_ecs::lifecycle::HookContext;
+use bevy_ecs::prelude::IntoScheduleConfigs;
+use bevy_ecs::system::Query;
+use bevy_ecs::world::{DeferredWorld, Ref};
+use kurbo::{BezPath, Point, SvgArc, Vec2};
+
+mod pipeline;
+mod tessellate;
+
+/// An arbitrary vector shape -- straight, quadratic, cubic and elliptical-arc segments, filled
+/// under a [`FillRule`] and outlined by an optional [`Stroke`] with its own joins, caps and
+/// dashes. What charts, custom icons and illustrations draw with, where
+/// [`Polygon`](crate::Polygon) is one regular n-gon and [`Polyline`](crate::Polyline) is
+/// straight segments only.
+///
+/// Commands are in the element's own local px, its resolved box's top-left at `(0, 0)`, the
+/// same space a polyline's points are in: the box places the shape but does not stretch it.
+/// Placed like a `Panel` otherwise, by a bounding box.
+///
+/// The shape is flattened and tessellated into triangles on the CPU (`path/tessellate.rs`)
+/// whenever it, its rule or its stroke change, so the GPU only ever sees triangles. Their
+/// antialiasing is a pixel-wide feather laid outward along every edge, so a path reads up to
+/// half a pixel heavier than its exact outline.
+#[derive(Component, Clone, PartialEq, Default, Debug)]
+#[require(FillRule, Stroke, PathMesh)]
+#[require(Color, Differential<Self, Color>)]
+#[require(Differential<Self, Stroke>)]
+#[require(Differential<Self, PathMesh>)]
+#[require(Differential<Self, Section<Logical>>)]
+#[require(Differential<Self, ResolvedElevation>)]
+#[require(Differential<Self, BlendedOpacity>)]
+#[require(Differential<Self, ClipContext>)]
+#[require(Differential<Self, ResolvedTransform>)]
+#[component(on_add = Self::on_add)]
+pub struct Path {
+    shape: BezPath,
+}
+/// Why [`Path::svg`] turned down its path data: the command or number it could not read.
+pub type SvgParseError = kurbo::SvgParseError;
+impl Path {
+    /// How closely an arc's cubics follow the true ellipse, in logical px.
+    const ARC_TOLERANCE: f64 = 0.01;
+    /// Starts a [`Path`] entity. Draw the shape with [`move_to`](PathSprout::move_to) and the
+    /// rest on the builder, or hand it a whole [`Path`] through [`shape`](PathSprout::shape).
+    pub fn new() -> PathSprout {
+        PathSprout::default()
+    }
+    /// A path with nothing in it yet, to draw with the methods below -- what
+    /// [`shape`](PathSprout::shape) and [`Grows::path`](crate::Grows::path) take.
+    pub fn empty() -> Self {
+        Self::default()
+    }
+    /// The shape SVG path data `d` describes -- the `d` attribute of a `<path>`, every command
+    /// in both absolute and relative form -- or where `d` stops being valid path data.
+    pub fn svg(d: &str) -> Result<Self, SvgParseError> {
+        BezPath::from_svg(d).map(|shape| Self { shape })
+    }
+    /// Starts a new subpath at `(x, y)`, leaving the last one open.
+    pub fn move_to(mut self, x: f32, y: f32) -> Self {
+        self.shape.move_to(Self::point(x, y));
+        self
+    }
+    /// A straight segment to `(x, y)`.
+    pub fn line_to(mut self, x: f32, y: f32) -> Self {
+        self.open();
+        self.shape.line_to(Self::point(x, y));
+        self
+    }
+    /// A quadratic Bézier to `(x, y)`, pulled toward `(cx, cy)`.
+    pub fn quad_to(mut self, cx: f32, cy: f32, x: f32, y: f32) -> Self {
+        self.open();
+        self.shape.quad_to(Self::point(cx, cy), Self::point(x, y));
+        self
+    }
+    /// A cubic Bézier to `(x, y)`, leaving toward `(c1x, c1y)` and arriving from
+    /// `(c2x, c2y)`.
+    pub fn cubic_to(mut self, c1x: f32, c1y: f32, c2x: f32, c2y: f32, x: f32, y: f32) -> Self {
+        self.open();
+        self.shape.curve_to(
+            Self::point(c1x, c1y),
+            Self::point(c2x, c2y),
+            Self::point(x, y),
+        );
+        self
+    }
+    /// An elliptical arc to `(x, y)`, as SVG's `A` command draws one: radii `rx` and `ry`, the
+    /// ellipse turned by `rotation` radians, and `large_arc` and `sweep` choosing which of the
+    /// four arcs through both ends it is. Radii too small to reach are scaled up until they
+    /// do; a zero radius is a straight segment.
+    #[allow(clippy::too_many_arguments)]
+    pub fn arc_to(
+        mut self,
+        rx: f32,
+        ry: f32,
+        rotation: f32,
+        large_arc: bool,
+        sweep: bool,
+        x: f32,
+        y: f32,
+    ) -> Self {
+        self.open();
+        let arc = SvgArc {
+            from: self.shape.current_position().unwrap_or_default(),
+            to: Self::point(x, y),
+            radii: Vec2::new(rx as f64, ry as f64),
+            x_rotation: rotation as f64,
+            large_arc,
+            sweep,
+        };
+        match kurbo::Arc::from_svg_arc(&arc) {
+            Some(arc) => arc.to_cubic_beziers(Self::ARC_TOLERANCE, |p1, p2, p3| {
+                self.shape.curve_to(p1, p2, p3)
+            }),
+            None => self.shape.line_to(arc.to),
+        }
+        self
+    }
+    /// Closes the subpath with a straight segment back to where it started. Only a stroke
+    /// can tell: a fill closes every subpath anyway, and a stroke leaves an open one capped.
+    pub fn close(mut self) -> Self {
+        if !self.shape.elements().is_empty() {
+            self.shape.close_path();
+        }
+        self
+    }
+    /// Drawing with nothing started begins at `(0, 0)`, as it would on a canvas.
+    fn open(&mut self) {
+        if self.shape.elements().is_empty() {
+            self.shape.move_to(Point::ORIGIN);
+        }
+    }
+    fn point(x: f32, y: f32) -> Point {
+        Point::new(x as f64, y as f64)
+    }
+    fn on_add(mut world: DeferredWorld, ctx: HookContext) {
+        let this = ctx.entity;
+        let mut tree = world.tree();
+        tree.subscribe(this, Remove::push_remove_packet::<Self>);
+        tree.subscribe(this, Visibility::push_remove_packet::<Self>);
+    }
+    /// Re-tessellates each path whose shape, rule or stroke changed -- or every path, when
+    /// the scale factor did, since the mesh is in physical pixels.
+    #[allow(clippy::type_complexity)]
+    fn tessellate(
+        mut paths: Query<(Ref<Path>, Ref<FillRule>, Ref<Stroke>, &mut PathMesh)>,
+        scale_factor: Res<ScaleFactor>,
+    ) {
+        for (path, rule, stroke, mut mesh) in paths.iter_mut() {
+            if !scale_factor.is_changed()
+                && !path.is_changed()
+                && !rule.is_changed()
+                && !stroke.is_changed()
+            {
+                continue;
+            }
+            mesh.0 = tessellate::tessellate(&path.shape, *rule, &stroke, scale_factor.value());
+        }
+    }
+}
+impl Attachment for Path {
+    fn attach(foliage: &mut Foliage) {
+        foliage
+            .diff
+            .add_systems(Path::tessellate.in_set(DiffMarkers::Finalize));
+        foliage.remove_queue::<Self>();
+        foliage.differential::<Self, PathMesh>();
+        foliage.differential::<Self, Section<Logical>>();
+        foliage.differential::<Self, Color>();
+        foliage.differential::<Self, Stroke>();
+        foliage.differential::<Self, ResolvedElevation>();
+        foliage.differential::<Self, ClipContext>();
+        foliage.differential::<Self, BlendedOpacity>();
+        foliage.differential::<Self, ResolvedTransform>();
+    }
+}
+/// Which parts of a [`Path`] that crosses itself, or holds one subpath inside another, are
+/// filled -- SVG's `fill-rule`.
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum FillRule {
+    /// Filled wherever the outline winds round more times one way than the other: a subpath
+    /// inside another is a hole only if it runs the other way.
+    #[default]
+    NonZero,
+    /// Filled wherever a line out of the shape crosses the outline an odd number of times: a
+    /// subpath inside another is always a hole.
+    EvenOdd,
+}
+impl FillRule {
+    pub(crate) fn inside(self, winding: i32) -> bool {
+        match self {
+            FillRule::NonZero => winding != 0,
+            FillRule::EvenOdd => winding % 2 != 0,
+        }
+    }
+}
+/// The outline drawn along a [`Path`], over its fill. `width` is in logical px, centered on
+/// the path; [`Stroke::none`] -- the default -- draws nothing.
+#[derive(Component, Copy, Clone, Debug, PartialEq)]
+pub struct Stroke {
+    pub width: f32,
+    pub color: Color,
+    pub join: StrokeJoin,
+    pub cap: StrokeCap,
+    /// Dashed along the path's length, continuing through its corners; `None` is solid.
+    pub dash: Option<DashPattern>,
+}
+impl Default for Stroke {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+impl Stroke {
+    /// A solid stroke `width` logical px wide, with round joins and butt caps.
+    pub fn new(width: f32, color: Color) -> Self {
+        Self {
+            width: width.max(0.0),
+            color,
+            join: StrokeJoin::Round,
+            cap: StrokeCap::Butt,
+            dash: None,
+        }
+    }
+    /// No stroke.
+    pub fn none() -> Self {
+        Self::new(0.0, Color::default())
+    }
+    pub fn join(mut self, join: StrokeJoin) -> Self {
+        self.join = join;
+        self
+    }
+    pub fn cap(mut self, cap: StrokeCap) -> Self {
+        self.cap = cap;
+        self
+    }
+    pub fn dash(mut self, dash: DashPattern) -> Self {
+        self.dash = Some(dash);
+        self
+    }
+}
+/// How a [`Stroke`] turns a corner.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum StrokeJoin {
+    /// Out to a point, beveled instead past four times the width -- SVG's default limit.
+    Miter,
+    #[default]
+    Round,
+    Bevel,
+}
+impl From<StrokeJoin> for kurbo::Join {
+    fn from(join: StrokeJoin) -> Self {
+        match join {
+            StrokeJoin::Miter => kurbo::Join::Miter,
+            StrokeJoin::Round => kurbo::Join::Round,
+            StrokeJoin::Bevel => kurbo::Join::Bevel,
+        }
+    }
+}
+/// How a [`Stroke`] ends an open subpath, and each dash.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum StrokeCap {
+    /// Square, exactly at the end.
+    #[default]
+    Butt,
+    Round,
+    /// Square, half the width past the end.
+    Square,
+}
+impl From<StrokeCap> for kurbo::Cap {
+    fn from(cap: StrokeCap) -> Self {
+        match cap {
+            StrokeCap::Butt => kurbo::Cap::Butt,
+            StrokeCap::Round => kurbo::Cap::Round,
+            StrokeCap::Square => kurbo::Cap::Square,
+        }
+    }
+}
+/// What `Path::tessellate` makes of a path, fill and stroke both: the one thing the pipeline
+/// draws. Rewritten whole on any change, never edited in place.
+#[derive(Component, Clone, Default, PartialEq)]
+pub(crate) struct PathMesh(pub(crate) Vec<PathTriangle>);
+/// Builder for a [`Path`] entity -- see [`Path::new`].
+#[derive(Default)]
+pub struct PathSprout {
+    leaf: LeafSprout,
+    path: Path,
+    color: Option<Color>,
+    rule: FillRule,
+    stroke: Stroke,
+}
+impl Author for PathSprout {
+    fn seed(&mut self) -> &mut LeafSprout {
+        &mut self.leaf
+    }
+    fn root(self) -> impl Bundle {
+        // A path given a stroke and no color is an outline, the way a chart's line is.
+        let unfilled = self.stroke.width > 0.0 && self.color.is_none();
+        let color = match self.color {
+            Some(c) => c,
+            None if unfilled => Color::default().with_opacity(0.0),
+            None => Color::default(),
+        };
+        (self.path, color, self.rule, self.stroke)
+    }
+}
+impl PathSprout {
+    /// The whole shape at once, in place of drawing it here.
+    pub fn shape(mut self, path: Path) -> Self {
+        self.path = path;
+        self
+    }
+    /// Fill color. Left unset on a path with a [`stroke`](Self::stroke), it is not filled.
+    pub fn color(mut self, c: Color) -> Self {
+        self.color = Some(c);
+        self
+    }
+    pub fn fill_rule(mut self, rule: FillRule) -> Self {
+        self.rule = rule;
+        self
+    }
+    pub fn stroke(mut self, stroke: Stroke) -> Self {
+        self.stroke = stroke;
+        self
+    }
+    /// See [`Path::move_to`].
+    pub fn move_to(mut self, x: f32, y: f32) -> Self {
+        self.path = self.path.move_to(x, y);
+        self
+    }
+    /// See [`Path::line_to`].
+    pub fn line_to(mut self, x: f32, y: f32) -> Self {
+        self.path = self.path.line_to(x, y);
+        self
+    }
+    /// See [`Path::quad_to`].
+    pub fn quad_to(mut self, cx: f32, cy: f32, x: f32, y: f32) -> Self {
+        self.path = self.path.quad_to(cx, cy, x, y);
+        self
+    }
+    /// See [`Path::cubic_to`].
+    pub fn cubic_to(mut self, c1x: f32, c1y: f32, c2x: f32, c2y: f32, x: f32, y: f32) -> Self {
+        self.path = self.path.cubic_to(c1x, c1y, c2x, c2y, x, y);
+        self
+    }
+    /// See [`Path::arc_to`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn arc_to(
+        mut self,
+        rx: f32,
+        ry: f32,
+        rotation: f32,
+        large_arc: bool,
+        sweep: bool,
+        x: f32,
+        y: f32,
+    ) -> Self {
+        self.path = self.path.arc_to(rx, ry, rotation, large_arc, sweep, x, y);
+        self
+    }
+    /// See [`Path::close`].
+    pub fn close(mut self) -> Self {
+        self.path = self.path.close();
+        self
+    }
+}
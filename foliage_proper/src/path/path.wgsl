@@ -0,0 +1,49 @@
+// A `Path`'s mesh, one triangle per instance, as `path/tessellate.rs` cut it. Each corner's
+// `z` is how far out into the antialiased fringe it sits, 0 on the shape to 1 a pixel past.
+struct Placement {
+    // origin.xy, layer, opacity
+    origin: vec4<f32>,
+    linear: vec4<f32>,
+    fill: vec4<f32>,
+    stroke: vec4<f32>,
+};
+@group(0)
+@binding(0)
+var<uniform> placement: Placement;
+@group(1)
+@binding(0)
+var<uniform> viewport: mat4x4<f32>;
+struct Vertex {
+    @location(0) weights: vec2<f32>,
+    @location(1) a: vec3<f32>,
+    @location(2) b: vec3<f32>,
+    @location(3) c: vec3<f32>,
+    @location(4) paint: f32,
+};
+struct Fragment {
+    @builtin(position) position: vec4<f32>,
+    @location(0) fringe: f32,
+    @location(1) @interpolate(flat) color: vec4<f32>,
+};
+@vertex
+fn vertex_entry(vertex: Vertex) -> Fragment {
+    let corner = vertex.a * vertex.weights.x
+        + vertex.b * vertex.weights.y
+        + vertex.c * (1.0 - vertex.weights.x - vertex.weights.y);
+    let position = vec4<f32>(
+        placement.origin.xy + transformed(corner.xy, placement.linear, vec2<f32>(0.0)),
+        placement.origin.z,
+        1.0
+    );
+    let color = select(placement.fill, placement.stroke, vertex.paint > 0.5);
+    return Fragment(
+        viewport * position,
+        corner.z,
+        color * vec4<f32>(1.0, 1.0, 1.0, placement.origin.w)
+    );
+}
+@fragment
+fn fragment_entry(frag: Fragment) -> @location(0) vec4<f32> {
+    let coverage = clamp(1.0 - frag.fringe, 0.0, 1.0);
+    return vec4<f32>(frag.color.rgb, frag.color.a * coverage);
+}
@@ -0,0 +1,309 @@
+use crate::ash::clip::ClipContext;
+use crate::ash::differential::RenderQueueHandle;
+use crate::ash::instance::{InstanceBuffer, InstanceId};
+use crate::ash::node::{Node, Nodes, RemoveNode};
+use crate::ash::render::{GroupId, Parameters, PipelineId, Render, RenderGroup, Renderer};
+use crate::ginkgo::{Ginkgo, Uniform};
+use crate::opacity::BlendedOpacity;
+use crate::path::tessellate::PathTriangle;
+use crate::path::{Path, PathMesh, Stroke};
+use crate::transform::ResolvedTransform;
+use crate::{Color, Coordinates, Logical, Parent, Physical, Position, ResolvedElevation, Section};
+use bevy_ecs::entity::Entity;
+use bytemuck::{Pod, Zeroable};
+use std::collections::HashMap;
+use wgpu::{
+    BindGroupDescriptor, BindGroupLayoutDescriptor, PipelineLayoutDescriptor, RenderPass,
+    RenderPipelineDescriptor, ShaderModuleDescriptor, ShaderSource, ShaderStages, VertexState,
+    VertexStepMode,
+};
+
+/// Which of an instance's three corners each vertex is, as weights on the first two -- the
+/// third takes what is left.
+const VERTICES: [Coordinates; 3] = [
+    Coordinates::new(1f32, 0f32),
+    Coordinates::new(0f32, 1f32),
+    Coordinates::new(0f32, 0f32),
+];
+const ONE_NODE_PER_GROUP_OPTIMIZATION: InstanceId = 0;
+
+pub(crate) struct Resources {
+    entity_to_group: HashMap<Entity, GroupId>,
+    group_layout: wgpu::BindGroupLayout,
+}
+/// Everything a path's triangles share, in one uniform: where its origin lands with its
+/// depth and opacity, the transform's matrix less identity, and the fill and stroke colors.
+#[repr(C)]
+#[derive(Pod, Zeroable, Copy, Clone, Default, PartialEq)]
+pub(crate) struct Placement {
+    origin: [f32; 4],
+    linear: [f32; 4],
+    fill: [f32; 4],
+    stroke: [f32; 4],
+}
+/// One path. Its triangles are a whole mesh rewritten on every change, not instances the
+/// coordinator orders, so the group is a single node at its element's elevation.
+pub(crate) struct Group {
+    triangles: InstanceBuffer<PathTriangle>,
+    count: u32,
+    placement: Placement,
+    uniform: Uniform<Placement>,
+    bind_group: wgpu::BindGroup,
+    write_uniform: bool,
+    update_node: bool,
+    elevation: ResolvedElevation,
+    clip_context: Parent,
+    position: Position<Physical>,
+    transform: ResolvedTransform,
+}
+impl Group {
+    fn new(ginkgo: &Ginkgo, layout: &wgpu::BindGroupLayout, elevation: ResolvedElevation) -> Self {
+        let placement = Placement {
+            origin: [0.0, 0.0, elevation.value(), 1.0],
+            ..Default::default()
+        };
+        let uniform = Uniform::new(ginkgo.context(), placement);
+        let bind_group = ginkgo.create_bind_group(&BindGroupDescriptor {
+            label: Some("path-group"),
+            layout,
+            entries: &[Ginkgo::uniform_bind_group_entry(&uniform, 0)],
+        });
+        Self {
+            triangles: InstanceBuffer::new(ginkgo, 1),
+            count: 0,
+            placement,
+            uniform,
+            bind_group,
+            write_uniform: false,
+            update_node: true,
+            elevation,
+            clip_context: Default::default(),
+            position: Position::default(),
+            transform: ResolvedTransform::IDENTITY,
+        }
+    }
+    /// Draws the path's origin where its transform takes it, and turns the mesh about it.
+    fn place(&mut self) {
+        let origin = self.transform.apply(self.position);
+        self.placement.origin[0] = origin.left();
+        self.placement.origin[1] = origin.top();
+        let [a, b, c, d] = self.transform.linear;
+        self.placement.linear = [a - 1.0, b, c, d - 1.0];
+        self.write_uniform = true;
+    }
+    /// Replaces the mesh wholesale, growing the buffer only when it no longer fits.
+    fn mesh(&mut self, ginkgo: &Ginkgo, mesh: PathMesh) {
+        let count = mesh.0.len() as u32;
+        if count > self.triangles.capacity {
+            self.triangles = InstanceBuffer::new(ginkgo, count.next_power_of_two());
+        }
+        self.triangles.cpu[..mesh.0.len()].copy_from_slice(&mesh.0);
+        self.triangles.write_range.replace(0..mesh.0.len());
+        self.count = count;
+    }
+}
+impl Render for Path {
+    type Group = Group;
+    type Resources = Resources;
+
+    fn renderer(ginkgo: &Ginkgo) -> Renderer<Self> {
+        let shader = ginkgo.create_shader(ShaderModuleDescriptor {
+            label: Some("path-shader"),
+            source: ShaderSource::Wgsl(
+                format!(
+                    "{}{}",
+                    include_str!("../transform.wgsl"),
+                    include_str!("path.wgsl")
+                )
+                .into(),
+            ),
+        });
+        let vertex_buffer = ginkgo.create_vertex_buffer(VERTICES);
+        let bind_group_layout = ginkgo.create_bind_group_layout(&BindGroupLayoutDescriptor {
+            label: Some("path-bind-group-layout"),
+            entries: &[Ginkgo::bind_group_layout_entry(0)
+                .at_stages(ShaderStages::VERTEX)
+                .uniform_entry()],
+        });
+        let bind_group = ginkgo.create_bind_group(&BindGroupDescriptor {
+            label: Some("path-bind-group"),
+            layout: &bind_group_layout,
+            entries: &[ginkgo.viewport_bind_group_entry(0)],
+        });
+        let group_layout = ginkgo.create_bind_group_layout(&BindGroupLayoutDescriptor {
+            label: Some("path-group-bind-group-layout"),
+            entries: &[Ginkgo::bind_group_layout_entry(0)
+                .at_stages(ShaderStages::VERTEX)
+                .uniform_entry()],
+        });
+        let pipeline_layout = ginkgo.create_pipeline_layout(&PipelineLayoutDescriptor {
+            label: Some("path-pipeline-layout"),
+            bind_group_layouts: &[Some(&group_layout), Some(&bind_group_layout)],
+            immediate_size: 0,
+        });
+        let pipeline = ginkgo.create_pipeline(&RenderPipelineDescriptor {
+            label: Some("path-render-pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: VertexState {
+                module: &shader,
+                entry_point: Option::from("vertex_entry"),
+                compilation_options: Default::default(),
+                buffers: &[
+                    Ginkgo::vertex_buffer_layout::<Coordinates>(
+                        VertexStepMode::Vertex,
+                        &wgpu::vertex_attr_array![0 => Float32x2],
+                    ),
+                    Ginkgo::vertex_buffer_layout::<PathTriangle>(
+                        VertexStepMode::Instance,
+                        &wgpu::vertex_attr_array![
+                            1 => Float32x3,
+                            2 => Float32x3,
+                            3 => Float32x3,
+                            4 => Float32
+                        ],
+                    ),
+                ],
+            },
+            primitive: Ginkgo::triangle_list_primitive(),
+            depth_stencil: ginkgo.depth_stencil_state(),
+            multisample: ginkgo.msaa_state(),
+            fragment: Ginkgo::fragment_state(
+                &shader,
+                "fragment_entry",
+                &ginkgo.alpha_color_target_state(),
+            ),
+            multiview_mask: None,
+            cache: None,
+        });
+        Renderer {
+            pipeline,
+            vertex_buffer,
+            bind_group,
+            groups: Default::default(),
+            resources: Resources {
+                entity_to_group: Default::default(),
+                group_layout,
+            },
+        }
+    }
+
+    fn prepare(
+        renderer: &mut Renderer<Self>,
+        queues: &mut RenderQueueHandle,
+        ginkgo: &Ginkgo,
+    ) -> Nodes {
+        tracing::trace!("pipeline: path prepare");
+        let mut nodes = Nodes::new();
+        for entity in queues.removes::<Path>() {
+            if let Some(id) = renderer.resources.entity_to_group.remove(&entity) {
+                queues.remove_attr::<Path, PathMesh>(entity);
+                queues.remove_attr::<Path, Section<Logical>>(entity);
+                queues.remove_attr::<Path, Color>(entity);
+                queues.remove_attr::<Path, Stroke>(entity);
+                queues.remove_attr::<Path, ResolvedElevation>(entity);
+                queues.remove_attr::<Path, ClipContext>(entity);
+                queues.remove_attr::<Path, BlendedOpacity>(entity);
+                queues.remove_attr::<Path, ResolvedTransform>(entity);
+                renderer.groups.remove(&id);
+                nodes.remove(RemoveNode::new(
+                    PipelineId::Path,
+                    id,
+                    ONE_NODE_PER_GROUP_OPTIMIZATION,
+                ));
+            }
+        }
+        for (entity, elevation) in queues.attribute::<Path, ResolvedElevation>() {
+            if let std::collections::hash_map::Entry::Vacant(e) =
+                renderer.resources.entity_to_group.entry(entity)
+            {
+                let group = Group::new(ginkgo, &renderer.resources.group_layout, elevation);
+                renderer
+                    .groups
+                    .insert(entity.to_bits(), RenderGroup::new(group));
+                e.insert(entity.to_bits());
+            } else {
+                let id = renderer.resources.entity_to_group.get(&entity).unwrap();
+                let group = &mut renderer.groups.get_mut(id).unwrap().group;
+                group.elevation = elevation;
+                group.placement.origin[2] = elevation.value();
+                group.write_uniform = true;
+                group.update_node = true;
+            }
+        }
+        let sf = ginkgo.configuration().scale_factor.value();
+        for (entity, clip) in queues.attribute::<Path, ClipContext>() {
+            let id = renderer.resources.entity_to_group.get(&entity).unwrap();
+            let group = &mut renderer.groups.get_mut(id).unwrap().group;
+            group.clip_context = clip.0;
+            group.update_node = true;
+        }
+        for (entity, section) in queues.attribute::<Path, Section<Logical>>() {
+            let id = renderer.resources.entity_to_group.get(&entity).unwrap();
+            let group = &mut renderer.groups.get_mut(id).unwrap().group;
+            group.position = section.position.to_physical(sf).rounded();
+            group.place();
+        }
+        for (entity, transform) in queues.attribute::<Path, ResolvedTransform>() {
+            let id = renderer.resources.entity_to_group.get(&entity).unwrap();
+            let group = &mut renderer.groups.get_mut(id).unwrap().group;
+            group.transform = transform.to_physical(sf);
+            group.place();
+        }
+        for (entity, opacity) in queues.attribute::<Path, BlendedOpacity>() {
+            let id = renderer.resources.entity_to_group.get(&entity).unwrap();
+            let group = &mut renderer.groups.get_mut(id).unwrap().group;
+            group.placement.origin[3] = opacity.value;
+            group.write_uniform = true;
+        }
+        for (entity, color) in queues.attribute::<Path, Color>() {
+            let id = renderer.resources.entity_to_group.get(&entity).unwrap();
+            let group = &mut renderer.groups.get_mut(id).unwrap().group;
+            group.placement.fill = color.c_repr().value;
+            group.write_uniform = true;
+        }
+        for (entity, stroke) in queues.attribute::<Path, Stroke>() {
+            let id = renderer.resources.entity_to_group.get(&entity).unwrap();
+            let group = &mut renderer.groups.get_mut(id).unwrap().group;
+            group.placement.stroke = stroke.color.c_repr().value;
+            group.write_uniform = true;
+        }
+        for (entity, mesh) in queues.attribute::<Path, PathMesh>() {
+            let id = renderer.resources.entity_to_group.get(&entity).unwrap();
+            let group = &mut renderer.groups.get_mut(id).unwrap().group;
+            group.mesh(ginkgo, mesh);
+        }
+        for (group_id, render_group) in renderer.groups.iter_mut() {
+            let group = &mut render_group.group;
+            if group.write_uniform {
+                group.uniform.write(ginkgo.context(), group.placement);
+                group.write_uniform = false;
+            }
+            group.triangles.write_gpu(ginkgo);
+            if group.update_node {
+                nodes.update(Node::new(
+                    group.elevation,
+                    PipelineId::Path,
+                    *group_id,
+                    0,
+                    group.clip_context,
+                    ONE_NODE_PER_GROUP_OPTIMIZATION,
+                ));
+                group.update_node = false;
+            }
+        }
+        nodes
+    }
+
+    fn render(renderer: &mut Renderer<Self>, render_pass: &mut RenderPass, parameters: Parameters) {
+        let group = &renderer.groups.get(&parameters.group).unwrap().group;
+        if group.count == 0 {
+            return;
+        }
+        render_pass.set_pipeline(&renderer.pipeline);
+        render_pass.set_bind_group(0, &group.bind_group, &[]);
+        render_pass.set_bind_group(1, &renderer.bind_group, &[]);
+        render_pass.set_vertex_buffer(0, renderer.vertex_buffer.slice(..));
+        render_pass.set_vertex_buffer(1, group.triangles.buffer.slice(..));
+        render_pass.draw(0..VERTICES.len() as u32, 0..group.count);
+    }
+}
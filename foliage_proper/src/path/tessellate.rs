@@ -0,0 +1,446 @@
+use crate::path::{FillRule, Stroke};
+use bytemuck::{Pod, Zeroable};
+use kurbo::{Affine, BezPath, PathEl, Point, StrokeOpts, Vec2};
+
+/// How far a flattened curve may stray from the true one, in physical pixels.
+const TOLERANCE: f64 = 0.1;
+/// Closer than this, two coordinates are the same one.
+const EPSILON: f64 = 1e-4;
+/// Deepest one band is cut to pull apart edges crossing inside it -- a guard for edges so
+/// nearly parallel that where they meet is lost in rounding.
+const MAX_CUTS: u32 = 24;
+
+/// One triangle of a [`Path`](crate::Path), in physical pixels from its section's top-left.
+/// Each corner's third value is how far out into the antialiased fringe it sits: 0 on or
+/// inside the shape, 1 a pixel beyond it. `paint` is 0 for the fill's color, 1 for the
+/// stroke's.
+#[repr(C)]
+#[derive(Pod, Zeroable, Copy, Clone, Debug, Default, PartialEq)]
+pub(crate) struct PathTriangle {
+    pub(crate) a: [f32; 3],
+    pub(crate) b: [f32; 3],
+    pub(crate) c: [f32; 3],
+    pub(crate) paint: f32,
+}
+
+/// `shape` filled under `rule`, then `stroke`'s outline over it, at `scale_factor` -- the
+/// triangles a `PathMesh` holds.
+pub(crate) fn tessellate(
+    shape: &BezPath,
+    rule: FillRule,
+    stroke: &Stroke,
+    scale_factor: f32,
+) -> Vec<PathTriangle> {
+    let mut physical = shape.clone();
+    physical.apply_affine(Affine::scale(scale_factor as f64));
+    let mut triangles = vec![];
+    fill(&physical, rule, 0.0, &mut triangles);
+    if stroke.width > 0.0 {
+        let sf = scale_factor as f64;
+        let mut style = kurbo::Stroke::new(stroke.width as f64 * sf)
+            .with_join(stroke.join.into())
+            .with_caps(stroke.cap.into());
+        if let Some(dash) = stroke.dash {
+            style = style.with_dashes(0.0, [dash.on as f64 * sf, dash.off as f64 * sf]);
+        }
+        let outline = kurbo::stroke(physical.iter(), &style, &StrokeOpts::default(), TOLERANCE);
+        // An outline overlaps itself at every tight turn; non-zero covers each pixel once.
+        fill(&outline, FillRule::NonZero, 1.0, &mut triangles);
+    }
+    triangles
+}
+
+/// Triangles covering what `path` encloses under `rule`, plus a pixel of fringe round it.
+///
+/// A scanline sweep with the scanlines only where something happens. The shape is flattened
+/// to straight edges and cut into horizontal bands at every vertex, and again wherever two
+/// edges cross, so that inside a band no edge starts, ends or passes another. Crossing a
+/// band left to right then meets the edges in one fixed order, and the running winding
+/// number says which gaps between them are inside -- each of those is a trapezoid, two
+/// triangles. Nothing is covered twice, so a translucent fill stays even.
+///
+/// The fringe is a strip a pixel wide laid outward along every boundary: each trapezoid's
+/// slanted sides, and whatever part of its top or bottom the band beside it does not carry
+/// on. Its distance runs 0 to 1 across the strip, and the shader fades coverage with it.
+fn fill(path: &BezPath, rule: FillRule, paint: f32, out: &mut Vec<PathTriangle>) {
+    let edges = edges(path);
+    let mut ys = edges
+        .iter()
+        .flat_map(|e| [e.top.y, e.bottom.y])
+        .collect::<Vec<_>>();
+    ys.sort_by(f64::total_cmp);
+    ys.dedup_by(|a, b| (*a - *b).abs() < EPSILON);
+    let mut sweep = Sweep {
+        rule,
+        paint,
+        out,
+        above: vec![],
+    };
+    let mut active: Vec<Edge> = vec![];
+    let mut next = 0;
+    for band in ys.windows(2) {
+        let (top, bottom) = (band[0], band[1]);
+        active.retain(|e| e.bottom.y > top + EPSILON);
+        while next < edges.len() && edges[next].top.y <= top + EPSILON {
+            active.push(edges[next]);
+            next += 1;
+        }
+        sweep.band(top, bottom, &active, 0);
+    }
+    if let Some(&floor) = ys.last() {
+        let above = std::mem::take(&mut sweep.above);
+        for (left, right) in above {
+            sweep.fringe(
+                Point::new(left, floor),
+                Point::new(right, floor),
+                Vec2::new(0.0, 1.0),
+            );
+        }
+    }
+}
+
+/// `path` flattened into straight edges, each pointing down, in order of where they start.
+/// Subpaths left open are closed, as a fill reads them.
+fn edges(path: &BezPath) -> Vec<Edge> {
+    let mut edges = vec![];
+    let mut start = Point::ZERO;
+    let mut current = Point::ZERO;
+    kurbo::flatten(path.iter(), TOLERANCE, |element| match element {
+        PathEl::MoveTo(p) => {
+            Edge::push(&mut edges, current, start);
+            start = p;
+            current = p;
+        }
+        PathEl::LineTo(p) => {
+            Edge::push(&mut edges, current, p);
+            current = p;
+        }
+        PathEl::ClosePath => {
+            Edge::push(&mut edges, current, start);
+            current = start;
+        }
+        // `flatten` hands back lines alone.
+        _ => {}
+    });
+    Edge::push(&mut edges, current, start);
+    edges.sort_by(|a, b| a.top.y.total_cmp(&b.top.y));
+    edges
+}
+
+#[derive(Copy, Clone)]
+struct Edge {
+    top: Point,
+    bottom: Point,
+    /// +1 if the path runs down this edge, -1 if up.
+    winding: i32,
+}
+impl Edge {
+    /// Flat edges are dropped: a band never crosses one, so it never changes the winding.
+    fn push(edges: &mut Vec<Edge>, from: Point, to: Point) {
+        if !(to.y - from.y).is_finite() || (to.y - from.y).abs() < EPSILON {
+            return;
+        }
+        edges.push(if from.y < to.y {
+            Edge {
+                top: from,
+                bottom: to,
+                winding: 1,
+            }
+        } else {
+            Edge {
+                top: to,
+                bottom: from,
+                winding: -1,
+            }
+        });
+    }
+    fn x_at(&self, y: f64) -> f64 {
+        let t = (y - self.top.y) / (self.bottom.y - self.top.y);
+        self.top.x + t * (self.bottom.x - self.top.x)
+    }
+}
+
+struct Sweep<'a> {
+    rule: FillRule,
+    paint: f32,
+    out: &'a mut Vec<PathTriangle>,
+    /// Where the last band was inside along its bottom, left to right, for the next band to
+    /// find which parts of the boundary between them are open.
+    above: Vec<(f64, f64)>,
+}
+impl Sweep<'_> {
+    fn band(&mut self, top: f64, bottom: f64, edges: &[Edge], depth: u32) {
+        let mut crossings = edges
+            .iter()
+            .map(|e| (e.x_at(top), e.x_at(bottom), e.winding))
+            .collect::<Vec<_>>();
+        crossings.sort_by(|a, b| (a.0 + a.1).total_cmp(&(b.0 + b.1)));
+        if depth < MAX_CUTS {
+            // Neighbours by midpoint that swap sides between the top and the bottom meet
+            // inside the band; cut at the first such meeting and sort each half again.
+            let cut = crossings
+                .windows(2)
+                .filter_map(|pair| {
+                    let at_top = pair[1].0 - pair[0].0;
+                    let at_bottom = pair[1].1 - pair[0].1;
+                    if at_top >= -EPSILON && at_bottom >= -EPSILON {
+                        return None;
+                    }
+                    let y = top + at_top / (at_top - at_bottom) * (bottom - top);
+                    (y - top > EPSILON && bottom - y > EPSILON).then_some(y)
+                })
+                .min_by(f64::total_cmp);
+            if let Some(y) = cut {
+                self.band(top, y, edges, depth + 1);
+                self.band(y, bottom, edges, depth + 1);
+                return;
+            }
+        }
+        let mut spans = vec![];
+        let mut winding = 0;
+        let mut left = None;
+        for (x_top, x_bottom, w) in crossings {
+            let was = self.rule.inside(winding);
+            winding += w;
+            let is = self.rule.inside(winding);
+            if !was && is {
+                left = Some((x_top, x_bottom));
+            } else if was
+                && !is
+                && let Some(left) = left.take()
+            {
+                spans.push((left, (x_top, x_bottom)));
+            }
+        }
+        for &((left_top, left_bottom), (right_top, right_bottom)) in &spans {
+            let corners = [
+                Point::new(left_top, top),
+                Point::new(right_top, top),
+                Point::new(right_bottom, bottom),
+                Point::new(left_bottom, bottom),
+            ];
+            self.triangle([(corners[0], 0.0), (corners[1], 0.0), (corners[2], 0.0)]);
+            self.triangle([(corners[0], 0.0), (corners[2], 0.0), (corners[3], 0.0)]);
+            let height = bottom - top;
+            let left = Vec2::new(-height, left_bottom - left_top).normalize();
+            let right = Vec2::new(height, right_top - right_bottom).normalize();
+            self.fringe(corners[0], corners[3], left);
+            self.fringe(corners[1], corners[2], right);
+        }
+        let tops = spans
+            .iter()
+            .map(|((left, _), (right, _))| (*left, *right))
+            .collect::<Vec<_>>();
+        let above = std::mem::take(&mut self.above);
+        for (left, right) in subtract(&tops, &above) {
+            self.fringe(
+                Point::new(left, top),
+                Point::new(right, top),
+                Vec2::new(0.0, -1.0),
+            );
+        }
+        for (left, right) in subtract(&above, &tops) {
+            self.fringe(
+                Point::new(left, top),
+                Point::new(right, top),
+                Vec2::new(0.0, 1.0),
+            );
+        }
+        self.above = spans
+            .iter()
+            .map(|((_, left), (_, right))| (*left, *right))
+            .collect();
+    }
+    /// A pixel-wide strip out from the boundary `a`..`b`, along `outward`.
+    fn fringe(&mut self, a: Point, b: Point, outward: Vec2) {
+        if !outward.is_finite() {
+            return;
+        }
+        let (a_out, b_out) = (a + outward, b + outward);
+        self.triangle([(a, 0.0), (b, 0.0), (b_out, 1.0)]);
+        self.triangle([(a, 0.0), (b_out, 1.0), (a_out, 1.0)]);
+    }
+    fn triangle(&mut self, corners: [(Point, f32); 3]) {
+        let [a, b, c] = corners.map(|(p, d)| [p.x as f32, p.y as f32, d]);
+        self.out.push(PathTriangle {
+            a,
+            b,
+            c,
+            paint: self.paint,
+        });
+    }
+}
+
+/// What of the sorted, disjoint intervals `a` the ones in `b` leave uncovered.
+fn subtract(a: &[(f64, f64)], b: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let mut left_over = vec![];
+    for &(start, end) in a {
+        let mut start = start;
+        for &(cut_start, cut_end) in b {
+            if cut_end <= start || cut_start >= end {
+                continue;
+            }
+            if cut_start - start > EPSILON {
+                left_over.push((start, cut_start));
+            }
+            start = start.max(cut_end);
+        }
+        if end - start > EPSILON {
+            left_over.push((start, end));
+        }
+    }
+    left_over
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EPSILON, PathTriangle, subtract, tessellate};
+    use crate::path::{FillRule, Stroke};
+    use kurbo::{BezPath, Circle, Shape};
+
+    fn mesh(shape: &BezPath, rule: FillRule) -> Vec<PathTriangle> {
+        tessellate(shape, rule, &Stroke::none(), 1.0)
+    }
+    fn svg(d: &str) -> BezPath {
+        BezPath::from_svg(d).unwrap()
+    }
+    /// The fill's own triangles, without the fringe round them.
+    fn body(triangles: &[PathTriangle]) -> Vec<PathTriangle> {
+        triangles
+            .iter()
+            .filter(|t| t.a[2] == 0.0 && t.b[2] == 0.0 && t.c[2] == 0.0)
+            .copied()
+            .collect()
+    }
+    fn area(triangles: &[PathTriangle]) -> f64 {
+        body(triangles)
+            .iter()
+            .map(|t| {
+                let (ab, ac) = (
+                    [t.b[0] - t.a[0], t.b[1] - t.a[1]],
+                    [t.c[0] - t.a[0], t.c[1] - t.a[1]],
+                );
+                (ab[0] * ac[1] - ab[1] * ac[0]).abs() as f64 / 2.0
+            })
+            .sum()
+    }
+    /// How many of the fill's triangles have `(x, y)` strictly inside.
+    fn coverage(triangles: &[PathTriangle], x: f32, y: f32) -> usize {
+        body(triangles)
+            .iter()
+            .filter(|t| {
+                let side = |p: [f32; 3], q: [f32; 3]| {
+                    (q[0] - p[0]) * (y - p[1]) - (q[1] - p[1]) * (x - p[0])
+                };
+                let sides = [side(t.a, t.b), side(t.b, t.c), side(t.c, t.a)];
+                sides.iter().all(|s| *s > 1e-4) || sides.iter().all(|s| *s < -1e-4)
+            })
+            .count()
+    }
+
+    #[test]
+    fn rect_area() {
+        let rect = svg("M0 0 L10 0 L10 20 L0 20 Z");
+        assert!((area(&mesh(&rect, FillRule::NonZero)) - 200.0).abs() < 1e-3);
+        let doubled = tessellate(&rect, FillRule::NonZero, &Stroke::none(), 2.0);
+        assert!((area(&doubled) - 800.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn circle_area() {
+        let circle = Circle::new((50.0, 50.0), 20.0).to_path(0.01);
+        let expected = std::f64::consts::PI * 400.0;
+        let area = area(&mesh(&circle, FillRule::NonZero));
+        assert!(
+            (area - expected).abs() / expected < 0.01,
+            "{area} vs {expected}"
+        );
+    }
+
+    #[test]
+    fn ring_hole_follows_rule() {
+        // Both squares wound the same way: non-zero fills the hole, even-odd leaves it.
+        let ring = svg("M0 0 L30 0 L30 30 L0 30 Z M10 10 L20 10 L20 20 L10 20 Z");
+        let even_odd = mesh(&ring, FillRule::EvenOdd);
+        assert!((area(&even_odd) - 800.0).abs() < 1e-3);
+        assert_eq!(coverage(&even_odd, 15.3, 14.7), 0);
+        assert_eq!(coverage(&even_odd, 5.3, 14.7), 1);
+        let non_zero = mesh(&ring, FillRule::NonZero);
+        assert!((area(&non_zero) - 900.0).abs() < 1e-3);
+        assert_eq!(coverage(&non_zero, 15.3, 14.7), 1);
+        // Wound against the outer square, the hole is empty under either rule.
+        let cut = svg("M0 0 L30 0 L30 30 L0 30 Z M10 10 L10 20 L20 20 L20 10 Z");
+        let non_zero = mesh(&cut, FillRule::NonZero);
+        assert!((area(&non_zero) - 800.0).abs() < 1e-3);
+        assert_eq!(coverage(&non_zero, 15.3, 14.7), 0);
+    }
+
+    #[test]
+    fn bowtie() {
+        // Crosses itself at (5, 5): two triangles, wound opposite ways.
+        let bowtie = svg("M0 0 L10 10 L10 0 L0 10 Z");
+        for rule in [FillRule::NonZero, FillRule::EvenOdd] {
+            let triangles = mesh(&bowtie, rule);
+            assert!((area(&triangles) - 50.0).abs() < 1e-3);
+            assert_eq!(coverage(&triangles, 1.3, 5.2), 1);
+            assert_eq!(coverage(&triangles, 5.2, 1.3), 0);
+        }
+    }
+
+    #[test]
+    fn translucent_fill_covers_once() {
+        // A pentagram: its middle is wound twice, and non-zero must still draw it once.
+        let points = (0..5)
+            .map(|i| {
+                let angle = std::f64::consts::TAU * (i * 2) as f64 / 5.0;
+                (50.0 + 40.0 * angle.sin(), 50.0 - 40.0 * angle.cos())
+            })
+            .collect::<Vec<_>>();
+        let mut star = BezPath::new();
+        star.move_to(points[0]);
+        for p in &points[1..] {
+            star.line_to(*p);
+        }
+        star.close_path();
+        let circle = Circle::new((50.0, 50.0), 30.0).to_path(0.01);
+        for shape in [star, circle] {
+            let triangles = mesh(&shape, FillRule::NonZero);
+            for i in 0..100 {
+                for j in 0..100 {
+                    let (x, y) = (i as f32 + 0.37, j as f32 + 0.61);
+                    assert!(coverage(&triangles, x, y) <= 1, "covered twice at {x}, {y}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn subtract_intervals() {
+        let a = [(0.0, 10.0), (20.0, 30.0)];
+        assert_eq!(subtract(&a, &[]), a.to_vec());
+        assert_eq!(subtract(&[], &a), vec![]);
+        assert_eq!(subtract(&a, &[(-5.0, 35.0)]), vec![]);
+        // Touching at an end takes nothing.
+        assert_eq!(subtract(&a, &[(10.0, 20.0)]), a.to_vec());
+        assert_eq!(
+            subtract(&a, &[(2.0, 4.0), (6.0, 8.0)]),
+            vec![(0.0, 2.0), (4.0, 6.0), (8.0, 10.0), (20.0, 30.0)]
+        );
+        // One cut across the gap trims both.
+        assert_eq!(subtract(&a, &[(5.0, 25.0)]), vec![(0.0, 5.0), (25.0, 30.0)]);
+        // What is left narrower than EPSILON is nothing.
+        assert_eq!(
+            subtract(&[(0.0, 10.0)], &[(EPSILON / 2.0, 10.0 - EPSILON / 2.0)]),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn degenerate() {
+        assert!(mesh(&BezPath::new(), FillRule::NonZero).is_empty());
+        let flat = svg("M0 0 L10 0 L20 0 Z");
+        assert!(mesh(&flat, FillRule::NonZero).is_empty());
+        let point = svg("M5 5 Z");
+        assert!(mesh(&point, FillRule::EvenOdd).is_empty());
+    }
+}
@@ -1,11 +1,15 @@
 use crate::boundary::bloom::Emissions;
+use crate::file_drop::{FileDrop, FileDropAdapter, FileDrops};
 use crate::foliage::Foliage;
 use crate::ginkgo::ScaleFactor;
 use crate::ginkgo::viewport::ViewportHandle;
+use crate::grid::zoom::ZoomInput;
+use crate::interaction::cursor::PointerCursor;
 use crate::interaction::{
-    Interaction, InteractionMethod, InteractionPhase, KeyboardAdapter, MouseAdapter, TouchAdapter,
+    Interaction, InteractionMethod, InteractionPhase, KeyboardAdapter, Modifiers, MouseAdapter,
+    TouchAdapter,
 };
-use crate::{Bloom, Layout, Position, Short};
+use crate::{AssetLoader, Bloom, Layout, Position, Short};
 use tracing::trace;
 use winit::application::ApplicationHandler;
 use winit::event::{MouseScrollDelta, WindowEvent};
@@ -85,6 +89,16 @@ impl ApplicationHandler for Foliage {
                 // dispatch before we even get control back)? A large `since_last` here narrows
                 // a multi-second stall to one side of that question.
                 self.main.run(&mut self.world);
+                if let Some(icon) = self
+                    .world
+                    .get_resource_mut::<PointerCursor>()
+                    .expect("pointer-cursor")
+                    .take_change()
+                {
+                    self.willow
+                        .window()
+                        .set_cursor(winit::window::CursorIcon::from(icon));
+                }
                 self.frame();
                 self.diff.run(&mut self.world);
                 self.willow.window().request_redraw();
@@ -134,9 +148,26 @@ impl Foliage {
                 event_loop.exit();
             }
             WindowEvent::Destroyed => {}
-            WindowEvent::DroppedFile(_) => {}
-            WindowEvent::HoveredFile(_) => {}
-            WindowEvent::HoveredFileCancelled => {}
+            // The key now and the bytes later, like any other load: a dropped file may be a
+            // video, and the loop busy reading it would be the loop not drawing.
+            WindowEvent::DroppedFile(path) => {
+                let key =
+                    FileDropAdapter::read(&path, &mut self.world.resource_mut::<AssetLoader>());
+                let cursor = self.world.resource::<MouseAdapter>().cursor;
+                self.world
+                    .resource_mut::<FileDrops>()
+                    .dropped(path, key, cursor);
+            }
+            WindowEvent::HoveredFile(path) => {
+                let cursor = self.world.resource::<MouseAdapter>().cursor;
+                self.world.resource_mut::<FileDrops>().hovered(path, cursor);
+            }
+            WindowEvent::HoveredFileCancelled => {
+                let cursor = self.world.resource::<MouseAdapter>().cursor;
+                self.world
+                    .resource_mut::<FileDrops>()
+                    .queue(FileDrop::Cancelled, cursor);
+            }
             WindowEvent::Focused(_) => {}
             WindowEvent::KeyboardInput {
                 device_id: _device_id,
@@ -160,6 +191,9 @@ impl Foliage {
                     let logical = adapter.parse(event.logical_key, event.state, event.repeat);
                     (logical, physical)
                 };
+                self.world
+                    .resource_mut::<crate::shortcut::Shortcuts>()
+                    .next_key();
                 if let Some(event) = logical {
                     self.world.trigger(event);
                 }
@@ -208,6 +242,8 @@ impl Foliage {
                 {
                     self.world.write_message(event);
                 }
+                let cursor = self.world.resource::<MouseAdapter>().cursor;
+                self.world.resource_mut::<FileDrops>().moved(cursor);
             }
             WindowEvent::CursorEntered { .. } => {}
             WindowEvent::CursorLeft { .. } => {}
@@ -216,27 +252,28 @@ impl Foliage {
                 delta,
                 phase: _phase,
             } => {
-                let px = match delta {
+                let travel = match delta {
                     MouseScrollDelta::LineDelta(x, y) => Position::logical((
                         x * Self::SCROLL_SENSITIVITY,
-                        y * Self::SCROLL_SENSITIVITY * Self::VIEW_SCROLLING,
+                        y * Self::SCROLL_SENSITIVITY,
                     )),
                     // Pixel-precise devices (trackpads, high-resolution wheels, web) already
                     // report real travel, so this path carries no sensitivity multiplier --
-                    // scaling it would double-count what the platform measured. The direction
-                    // convention is not device-specific though, and applying it on only one of
-                    // the two arms would leave the constant half-honoured the moment it is set
-                    // to `NATURAL_SCROLLING`.
+                    // scaling it would double-count what the platform measured.
                     MouseScrollDelta::PixelDelta(px) => {
-                        Position::physical((px.x as f32, px.y as f32 * Self::VIEW_SCROLLING))
-                            .to_logical(
-                                self.world
-                                    .get_resource::<ScaleFactor>()
-                                    .expect("scale-factor")
-                                    .value(),
-                            )
+                        Position::physical((px.x as f32, px.y as f32)).to_logical(
+                            self.world
+                                .get_resource::<ScaleFactor>()
+                                .expect("scale-factor")
+                                .value(),
+                        )
                     }
                 };
+                // The direction convention is applied once, to both kinds of device: on only
+                // one of them it would leave the constant half-honoured the moment it is set to
+                // `NATURAL_SCROLLING`. A zoom takes the travel without it -- which way the
+                // page follows the wheel says nothing about which way is closer.
+                let px = Position::logical((travel.left(), travel.top() * Self::VIEW_SCROLLING));
                 let cursor = self
                     .world
                     .get_resource::<MouseAdapter>()
@@ -248,6 +285,24 @@ impl Foliage {
                     .expect("vh")
                     .section()
                     .position;
+                // Ctrl (Cmd on macOS) turns the wheel into a zoom, the convention every canvas
+                // and map shares -- and what trackpads on some platforms report a pinch as.
+                // Only over something that zooms: anywhere else the wheel scrolls as it would
+                // have, rather than being spent on nothing.
+                let mods = self
+                    .world
+                    .get_resource::<KeyboardAdapter>()
+                    .expect("keyboard-adapter")
+                    .mods;
+                if mods.intersects(Modifiers::CONTROL | Modifiers::SUPER)
+                    && crate::grid::zoom::zoomable_at(&mut self.world, vh + cursor)
+                {
+                    self.world.write_message(ZoomInput {
+                        at: vh + cursor,
+                        factor: (travel.top() * Self::ZOOM_SENSITIVITY).exp(),
+                    });
+                    return;
+                }
                 let event = Interaction::new(
                     InteractionPhase::Start,
                     vh + cursor,
@@ -275,7 +330,23 @@ impl Foliage {
                     self.world.write_message(event);
                 }
             }
-            WindowEvent::PinchGesture { .. } => {}
+            WindowEvent::PinchGesture { delta, .. } => {
+                let cursor = self
+                    .world
+                    .get_resource::<MouseAdapter>()
+                    .expect("mouse-adapter")
+                    .cursor;
+                let vh = self
+                    .world
+                    .get_resource::<ViewportHandle>()
+                    .expect("vh")
+                    .section()
+                    .position;
+                self.world.write_message(ZoomInput {
+                    at: vh + cursor,
+                    factor: 1.0 + delta as f32,
+                });
+            }
             WindowEvent::PanGesture { .. } => {}
             WindowEvent::DoubleTapGesture { .. } => {}
             WindowEvent::RotationGesture { .. } => {}
@@ -292,6 +363,14 @@ impl Foliage {
                     .expect("vh")
                     .section()
                     .position;
+                if let Some(zoom) = self
+                    .world
+                    .get_resource_mut::<TouchAdapter>()
+                    .expect("touch-adapter")
+                    .pinch(t, viewport_position, scale_factor)
+                {
+                    self.world.write_message(zoom);
+                }
                 if let Some(event) = self
                     .world
                     .get_resource_mut::<TouchAdapter>()
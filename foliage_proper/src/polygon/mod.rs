@@ -1,15 +1,21 @@
 use crate::AsTree;
+use crate::InteractionShape;
 use crate::anim::interpolation::Interpolations;
 use crate::ash::clip::ClipContext;
+use crate::foliage::MainMarkers;
 use crate::grid::AspectRatio;
 use crate::opacity::BlendedOpacity;
 use crate::remove::Remove;
+use crate::transform::ResolvedTransform;
 use crate::{
-    Animate, Attachment, Author, Color, Component, Differential, Foliage, LeafSprout, Logical,
-    ResolvedElevation, Section, Visibility,
+    Animate, Attachment, Author, Color, Component, Differential, Fill, Foliage, LeafSprout,
+    Logical, ResolvedElevation, Section, Shadow, Visibility,
 };
 use bevy_ecs::bundle::Bundle;
 use bevy_ecs::lifecycle::HookContext;
+use bevy_ecs::prelude::IntoScheduleConfigs;
+use bevy_ecs::query::Changed;
+use bevy_ecs::system::Query;
 use bevy_ecs::world::DeferredWorld;
 use bytemuck::{Pod, Zeroable};
 
@@ -33,9 +39,12 @@ mod pipeline;
 #[derive(Component, Pod, Zeroable, Copy, Clone, Debug, PartialEq)]
 #[require(Differential<Self, Section<Logical>>)]
 #[require(Color, Differential<Self, Color>)]
+#[require(Fill, Differential<Self, Fill>)]
+#[require(Shadow, Differential<Self, Shadow>)]
 #[require(Differential<Self, ResolvedElevation>)]
 #[require(Differential<Self, BlendedOpacity>)]
 #[require(Differential<Self, ClipContext>)]
+#[require(Differential<Self, ResolvedTransform>)]
 #[require(Differential<Self, Self>)]
 #[component(on_add = Self::on_add)]
 pub struct Polygon {
@@ -61,7 +70,9 @@ impl Polygon {
     }
     fn on_add(mut world: DeferredWorld, ctx: HookContext) {
         let this = ctx.entity;
+        let polygon = *world.get::<Polygon>(this).unwrap();
         let mut tree = world.tree();
+        tree.write_to(this, InteractionShape::Polygon(polygon));
         tree.subscribe(this, Remove::push_remove_packet::<Self>);
         tree.subscribe(this, Visibility::push_remove_packet::<Self>);
     }
@@ -91,16 +102,37 @@ impl Attachment for Polygon {
         foliage.differential::<Self, Section<Logical>>();
         foliage.differential::<Self, Self>();
         foliage.differential::<Self, Color>();
+        foliage.differential::<Self, Fill>();
+        foliage.differential::<Self, Shadow>();
         foliage.differential::<Self, ResolvedElevation>();
         foliage.differential::<Self, ClipContext>();
         foliage.differential::<Self, BlendedOpacity>();
+        foliage.differential::<Self, ResolvedTransform>();
         foliage.enable_animation::<Self>();
+        foliage
+            .main
+            .add_systems(Self::reshape.in_set(MainMarkers::Process));
+    }
+}
+impl Polygon {
+    /// Keeps the hit area on the shape as it morphs. Written in place rather than through the
+    /// tree: it is the next press that reads it, and a `Polygon` animates every frame.
+    fn reshape(mut polygons: Query<(&Polygon, &mut InteractionShape), Changed<Polygon>>) {
+        for (polygon, mut shape) in polygons.iter_mut() {
+            if let InteractionShape::Polygon(current) = shape.as_mut()
+                && current != polygon
+            {
+                *current = *polygon;
+            }
+        }
     }
 }
 /// Builder for a [`Polygon`] entity -- see [`Polygon::new`].
 pub struct PolygonSprout {
     leaf: LeafSprout,
     color: Option<Color>,
+    fill: Option<Fill>,
+    shadow: Option<Shadow>,
     polygon: Polygon,
 }
 impl Default for PolygonSprout {
@@ -108,6 +140,8 @@ impl Default for PolygonSprout {
         Self {
             leaf: LeafSprout::default(),
             color: None,
+            fill: None,
+            shadow: None,
             polygon: Polygon::default(),
         }
     }
@@ -126,6 +160,8 @@ impl Author for PolygonSprout {
         (
             self.polygon,
             self.color.unwrap_or_default(),
+            self.fill.unwrap_or_default(),
+            self.shadow.unwrap_or_default(),
             AspectRatio::new().xs(1.0),
         )
     }
@@ -136,6 +172,17 @@ impl PolygonSprout {
         self.color = Some(c);
         self
     }
+    /// A gradient in place of the [`color`](Self::color), laid across the shape's box -- see
+    /// [`Fill`].
+    pub fn fill(mut self, f: Fill) -> Self {
+        self.fill = Some(f);
+        self
+    }
+    /// A soft shadow beneath, in the shape's own outline -- see [`Shadow`].
+    pub fn shadow(mut self, s: Shadow) -> Self {
+        self.shadow = Some(s);
+        self
+    }
     /// Number of sides, clamped to >=3 -- fractional values are valid (they drive the
     /// shader's side-count blend) but a shape needs at least a triangle's worth.
     pub fn sides(mut self, n: f32) -> Self {
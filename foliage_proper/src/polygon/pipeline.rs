@@ -3,17 +3,23 @@ use crate::ash::differential::RenderQueueHandle;
 use crate::ash::instance::{Instance, InstanceBuffer};
 use crate::ash::node::{Nodes, RemoveNode};
 use crate::ash::render::{Parameters, PipelineId, Render, RenderGroup, Renderer};
+use crate::fill::CReprFill;
 use crate::ginkgo::Ginkgo;
 use crate::opacity::BlendedOpacity;
 use crate::polygon::Polygon;
+use crate::shadow::CReprShadow;
+use crate::transform::{CReprTransform, ResolvedTransform};
 use crate::{
-    CReprColor, CReprSection, Color, Coordinates, Logical, Parent, ResolvedElevation, Section,
+    CReprColor, CReprSection, Color, Coordinates, Fill, Logical, Parent, ResolvedElevation,
+    Section, Shadow,
 };
+use bevy_ecs::entity::Entity;
 use bytemuck::{Pod, Zeroable};
 use std::collections::HashMap;
 use wgpu::{
     BindGroupDescriptor, BindGroupLayoutDescriptor, PipelineLayoutDescriptor, RenderPass,
-    RenderPipelineDescriptor, ShaderStages, VertexState, VertexStepMode, include_wgsl,
+    RenderPipelineDescriptor, ShaderModuleDescriptor, ShaderSource, ShaderStages, VertexState,
+    VertexStepMode,
 };
 
 #[repr(C)]
@@ -34,20 +40,52 @@ pub(crate) const VERTICES: [Vertex; 6] = [
     Vertex::new(Coordinates::new(0f32, 1f32)),
     Vertex::new(Coordinates::new(1f32, 1f32)),
 ];
-pub(crate) struct Resources {}
+pub(crate) struct Resources {
+    layers: HashMap<Entity, LayerAndOpacity>,
+}
 pub(crate) struct Group {
     sections: InstanceBuffer<CReprSection>,
-    elevations: InstanceBuffer<ResolvedElevation>,
+    layers: InstanceBuffer<LayerAndOpacity>,
     colors: InstanceBuffer<CReprColor>,
-    opacities: InstanceBuffer<BlendedOpacity>,
     params: InstanceBuffer<Polygon>,
+    transforms: InstanceBuffer<CReprTransform>,
+    fills: InstanceBuffer<CReprFill>,
+    shadows: InstanceBuffer<CReprShadow>,
+}
+/// Depth and opacity in one attribute -- a buffer apiece would take the pipeline past the
+/// eight vertex buffers a device is guaranteed.
+#[repr(C)]
+#[derive(Pod, Zeroable, Copy, Clone, Debug)]
+pub(crate) struct LayerAndOpacity {
+    elevation: ResolvedElevation,
+    opacity: f32,
+}
+impl Default for LayerAndOpacity {
+    fn default() -> Self {
+        Self {
+            elevation: ResolvedElevation::default(),
+            opacity: 1.0,
+        }
+    }
 }
 impl Render for Polygon {
     type Group = Group;
     type Resources = Resources;
 
     fn renderer(ginkgo: &Ginkgo) -> Renderer<Self> {
-        let shader = ginkgo.create_shader(include_wgsl!("polygon.wgsl"));
+        let shader = ginkgo.create_shader(ShaderModuleDescriptor {
+            label: Some("polygon-shader"),
+            source: ShaderSource::Wgsl(
+                format!(
+                    "{}{}{}{}",
+                    include_str!("../transform.wgsl"),
+                    include_str!("../fill.wgsl"),
+                    include_str!("../shadow.wgsl"),
+                    include_str!("polygon.wgsl")
+                )
+                .into(),
+            ),
+        });
         let vertex_buffer = ginkgo.create_vertex_buffer(VERTICES);
         let bind_group_layout = ginkgo.create_bind_group_layout(&BindGroupLayoutDescriptor {
             label: Some("polygon-bind-group-layout"),
@@ -81,21 +119,36 @@ impl Render for Polygon {
                         VertexStepMode::Instance,
                         &wgpu::vertex_attr_array![1 => Float32x4],
                     ),
-                    Ginkgo::vertex_buffer_layout::<ResolvedElevation>(
+                    Ginkgo::vertex_buffer_layout::<LayerAndOpacity>(
                         VertexStepMode::Instance,
-                        &wgpu::vertex_attr_array![2 => Float32],
+                        &wgpu::vertex_attr_array![2 => Float32x2],
                     ),
                     Ginkgo::vertex_buffer_layout::<CReprColor>(
                         VertexStepMode::Instance,
                         &wgpu::vertex_attr_array![3 => Float32x4],
                     ),
-                    Ginkgo::vertex_buffer_layout::<BlendedOpacity>(
+                    Ginkgo::vertex_buffer_layout::<Polygon>(
                         VertexStepMode::Instance,
-                        &wgpu::vertex_attr_array![4 => Float32],
+                        &wgpu::vertex_attr_array![4 => Float32x3],
                     ),
-                    Ginkgo::vertex_buffer_layout::<Polygon>(
+                    Ginkgo::vertex_buffer_layout::<CReprTransform>(
+                        VertexStepMode::Instance,
+                        &wgpu::vertex_attr_array![5 => Float32x4, 6 => Float32x2],
+                    ),
+                    Ginkgo::vertex_buffer_layout::<CReprFill>(
+                        VertexStepMode::Instance,
+                        &wgpu::vertex_attr_array![
+                            7 => Float32x4,
+                            8 => Float32x4,
+                            9 => Float32x4,
+                            10 => Float32x4,
+                            11 => Float32x4,
+                            12 => Float32x4
+                        ],
+                    ),
+                    Ginkgo::vertex_buffer_layout::<CReprShadow>(
                         VertexStepMode::Instance,
-                        &wgpu::vertex_attr_array![5 => Float32x3],
+                        &wgpu::vertex_attr_array![13 => Float32x4, 14 => Float32x4],
                     ),
                 ],
             },
@@ -115,10 +168,12 @@ impl Render for Polygon {
             0,
             RenderGroup::new(Group {
                 sections: InstanceBuffer::new(ginkgo, 1),
-                elevations: InstanceBuffer::new(ginkgo, 1),
+                layers: InstanceBuffer::new(ginkgo, 1),
                 colors: InstanceBuffer::new(ginkgo, 1),
-                opacities: InstanceBuffer::new(ginkgo, 1),
                 params: InstanceBuffer::new(ginkgo, 1),
+                transforms: InstanceBuffer::new(ginkgo, 1),
+                fills: InstanceBuffer::new(ginkgo, 1),
+                shadows: InstanceBuffer::new(ginkgo, 1),
             }),
         );
         Renderer {
@@ -126,7 +181,9 @@ impl Render for Polygon {
             vertex_buffer,
             bind_group,
             groups,
-            resources: Resources {},
+            resources: Resources {
+                layers: HashMap::new(),
+            },
         }
     }
 
@@ -144,12 +201,16 @@ impl Render for Polygon {
                 let order = group.coordinator.order(id);
                 group.coordinator.remove(order);
                 nodes.remove(RemoveNode::new(PipelineId::Polygon, 0, id));
+                renderer.resources.layers.remove(&entity);
                 queues.remove_attr::<Polygon, Section<Logical>>(entity);
                 queues.remove_attr::<Polygon, Polygon>(entity);
                 queues.remove_attr::<Polygon, ResolvedElevation>(entity);
                 queues.remove_attr::<Polygon, ClipContext>(entity);
                 queues.remove_attr::<Polygon, Color>(entity);
                 queues.remove_attr::<Polygon, BlendedOpacity>(entity);
+                queues.remove_attr::<Polygon, ResolvedTransform>(entity);
+                queues.remove_attr::<Polygon, Fill>(entity);
+                queues.remove_attr::<Polygon, Shadow>(entity);
             }
         }
         for (entity, elevation) in queues.attribute::<Polygon, ResolvedElevation>() {
@@ -161,7 +222,9 @@ impl Render for Polygon {
             } else {
                 group.coordinator.update_elevation(id, elevation);
             }
-            group.group.elevations.queue(id, elevation);
+            let layer = renderer.resources.layers.entry(entity).or_default();
+            layer.elevation = elevation;
+            group.group.layers.queue(id, *layer);
         }
         for (entity, section) in queues.attribute::<Polygon, Section<Logical>>() {
             let id = entity.to_bits();
@@ -182,52 +245,83 @@ impl Render for Polygon {
             group.group.colors.queue(id, color.c_repr());
         }
         for (entity, opacity) in queues.attribute::<Polygon, BlendedOpacity>() {
-            let id = entity.to_bits();
-            group.group.opacities.queue(id, opacity);
+            let layer = renderer.resources.layers.entry(entity).or_default();
+            layer.opacity = opacity.value;
+            group.group.layers.queue(entity.to_bits(), *layer);
         }
         for (entity, polygon) in queues.attribute::<Polygon, Polygon>() {
             let id = entity.to_bits();
             group.group.params.queue(id, polygon);
         }
+        for (entity, transform) in queues.attribute::<Polygon, ResolvedTransform>() {
+            let id = entity.to_bits();
+            let sf = ginkgo.configuration().scale_factor.value();
+            group.group.transforms.queue(id, transform.c_repr(sf));
+        }
+        for (entity, fill) in queues.attribute::<Polygon, Fill>() {
+            // Faded by the shader, alongside the color.
+            group.group.fills.queue(entity.to_bits(), fill.c_repr(1.0));
+        }
+        for (entity, shadow) in queues.attribute::<Polygon, Shadow>() {
+            let sf = ginkgo.configuration().scale_factor.value();
+            group
+                .group
+                .shadows
+                .queue(entity.to_bits(), shadow.c_repr(1.0, sf));
+        }
         if let Some(n) = group.coordinator.grown() {
             group.group.sections.grow(ginkgo, n);
-            group.group.elevations.grow(ginkgo, n);
+            group.group.layers.grow(ginkgo, n);
             group.group.colors.grow(ginkgo, n);
-            group.group.opacities.grow(ginkgo, n);
             group.group.params.grow(ginkgo, n);
+            group.group.transforms.grow(ginkgo, n);
+            group.group.fills.grow(ginkgo, n);
+            group.group.shadows.grow(ginkgo, n);
         }
         for swap in group.coordinator.sort() {
             group.group.sections.swap(swap);
-            group.group.elevations.swap(swap);
+            group.group.layers.swap(swap);
             group.group.colors.swap(swap);
-            group.group.opacities.swap(swap);
             group.group.params.swap(swap);
+            group.group.transforms.swap(swap);
+            group.group.fills.swap(swap);
+            group.group.shadows.swap(swap);
         }
         for (id, data) in group.group.sections.queued() {
             let order = group.coordinator.order(id);
             group.group.sections.write_cpu(order, data);
         }
-        for (id, data) in group.group.elevations.queued() {
+        for (id, data) in group.group.layers.queued() {
             let order = group.coordinator.order(id);
-            group.group.elevations.write_cpu(order, data);
+            group.group.layers.write_cpu(order, data);
         }
         for (id, data) in group.group.colors.queued() {
             let order = group.coordinator.order(id);
             group.group.colors.write_cpu(order, data);
         }
-        for (id, data) in group.group.opacities.queued() {
-            let order = group.coordinator.order(id);
-            group.group.opacities.write_cpu(order, data);
-        }
         for (id, data) in group.group.params.queued() {
             let order = group.coordinator.order(id);
             group.group.params.write_cpu(order, data);
         }
+        for (id, data) in group.group.transforms.queued() {
+            let order = group.coordinator.order(id);
+            group.group.transforms.write_cpu(order, data);
+        }
+        for (id, data) in group.group.fills.queued() {
+            let order = group.coordinator.order(id);
+            group.group.fills.write_cpu(order, data);
+        }
+        for (id, data) in group.group.shadows.queued() {
+            let order = group.coordinator.order(id);
+            group.group.shadows.write_cpu(order, data);
+        }
         group.group.sections.write_gpu(ginkgo);
-        group.group.elevations.write_gpu(ginkgo);
+        group.group.layers.write_gpu(ginkgo);
         group.group.colors.write_gpu(ginkgo);
-        group.group.opacities.write_gpu(ginkgo);
         group.group.params.write_gpu(ginkgo);
+        group.group.transforms.write_gpu(ginkgo);
+        group.group.fills.write_gpu(ginkgo);
+        group.group.shadows.write_gpu(ginkgo);
         for node in group.coordinator.updated_nodes(PipelineId::Polygon, 0) {
             nodes.update(node);
         }
@@ -240,10 +334,12 @@ impl Render for Polygon {
         render_pass.set_bind_group(0, &renderer.bind_group, &[]);
         render_pass.set_vertex_buffer(0, renderer.vertex_buffer.slice(..));
         render_pass.set_vertex_buffer(1, group.group.sections.buffer.slice(..));
-        render_pass.set_vertex_buffer(2, group.group.elevations.buffer.slice(..));
+        render_pass.set_vertex_buffer(2, group.group.layers.buffer.slice(..));
         render_pass.set_vertex_buffer(3, group.group.colors.buffer.slice(..));
-        render_pass.set_vertex_buffer(4, group.group.opacities.buffer.slice(..));
-        render_pass.set_vertex_buffer(5, group.group.params.buffer.slice(..));
+        render_pass.set_vertex_buffer(4, group.group.params.buffer.slice(..));
+        render_pass.set_vertex_buffer(5, group.group.transforms.buffer.slice(..));
+        render_pass.set_vertex_buffer(6, group.group.fills.buffer.slice(..));
+        render_pass.set_vertex_buffer(7, group.group.shadows.buffer.slice(..));
         render_pass.draw(0..VERTICES.len() as u32, parameters.range);
     }
 }
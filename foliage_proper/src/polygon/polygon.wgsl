@@ -4,25 +4,55 @@ var<uniform> viewport: mat4x4<f32>;
 struct Vertex {
     @location(0) vertex_pos: vec2<f32>,
     @location(1) section: vec4<f32>,
-    @location(2) layer: f32,
+    @location(2) layer_and_opacity: vec2<f32>,
     @location(3) color: vec4<f32>,
-    @location(4) opacity: f32,
-    @location(5) params: vec3<f32>,
+    @location(4) params: vec3<f32>,
+    @location(5) linear: vec4<f32>,
+    @location(6) translation: vec2<f32>,
+    @location(7) fill_geometry: vec4<f32>,
+    @location(8) fill_offsets: vec4<f32>,
+    @location(9) fill_c0: vec4<f32>,
+    @location(10) fill_c1: vec4<f32>,
+    @location(11) fill_c2: vec4<f32>,
+    @location(12) fill_c3: vec4<f32>,
+    @location(13) shadow_color: vec4<f32>,
+    @location(14) shadow_geometry: vec4<f32>,
 };
 struct Fragment {
     @builtin(position) position: vec4<f32>,
     @location(0) color: vec4f,
     @location(1) section: vec4f,
     @location(2) params: vec3f,
+    @location(3) local: vec2f,
+    @location(4) @interpolate(flat) fill_geometry: vec4f,
+    @location(5) @interpolate(flat) fill_offsets: vec4f,
+    @location(6) @interpolate(flat) fill_c0: vec4f,
+    @location(7) @interpolate(flat) fill_c1: vec4f,
+    @location(8) @interpolate(flat) fill_c2: vec4f,
+    @location(9) @interpolate(flat) fill_c3: vec4f,
+    @location(10) @interpolate(flat) shadow_color: vec4f,
+    @location(11) @interpolate(flat) shadow_geometry: vec4f,
 };
 @vertex
 fn vertex_entry(vertex: Vertex) -> Fragment {
-    let world_pos = vertex.section.xy + vertex.vertex_pos * vertex.section.zw;
+    // Grown past the section to hold the shadow; the polygon itself stays inside it.
+    let reach = shadow_reach(vertex.shadow_color, vertex.shadow_geometry);
+    let world_pos = vertex.section.xy - reach + vertex.vertex_pos * (vertex.section.zw + 2.0 * reach);
+    let fade = vec4f(1.0, 1.0, 1.0, vertex.layer_and_opacity.y);
     return Fragment(
-        viewport * vec4f(world_pos, vertex.layer, 1.0),
-        vertex.color * vec4f(1.0, 1.0, 1.0, vertex.opacity),
+        viewport * vec4f(transformed(world_pos, vertex.linear, vertex.translation), vertex.layer_and_opacity.x, 1.0),
+        vertex.color * fade,
         vertex.section,
         vertex.params,
+        world_pos,
+        vertex.fill_geometry,
+        vertex.fill_offsets,
+        vertex.fill_c0 * fade,
+        vertex.fill_c1 * fade,
+        vertex.fill_c2 * fade,
+        vertex.fill_c3 * fade,
+        vertex.shadow_color * fade,
+        vertex.shadow_geometry,
     );
 }
 fn floor_mod(x: f32, y: f32) -> f32 {
@@ -45,19 +75,16 @@ fn rotate(p: vec2f, angle: f32) -> vec2f {
     let s = sin(angle);
     return vec2f(p.x * c - p.y * s, p.x * s + p.y * c);
 }
-@fragment
-fn fragment_entry(frag: Fragment) -> @location(0) vec4<f32> {
-    let center = frag.section.xy + frag.section.zw * 0.5;
-    let apothem = min(frag.section.z, frag.section.w) * 0.5;
-    var p = frag.position.xy - center;
-    p = rotate(p, -frag.params.z);
+// The polygon `params` describes, sized to `apothem`, at `p` from its centre.
+fn sd_polygon(p_in: vec2f, apothem: f32, params: vec3f) -> f32 {
+    let p = rotate(p_in, -params.z);
     // rounded-shape trick: shrink the sharp polygon's apothem by the round amount, then
     // grow the boundary back out by that same amount -- at rounding=1 this degenerates
     // sd_regular_polygon's own apothem to 0 (a circle), so full rounding always lands on
     // a true circle regardless of side count.
-    let round_amount = clamp(frag.params.y, 0.0, 1.0) * apothem;
+    let round_amount = clamp(params.y, 0.0, 1.0) * apothem;
     let r = max(apothem - round_amount, 0.0);
-    let sides = max(frag.params.x, 3.0);
+    let sides = max(params.x, 3.0);
     let n0 = floor(sides);
     let n1 = ceil(sides);
     let d0 = sd_regular_polygon(p, r, n0);
@@ -65,8 +92,37 @@ fn fragment_entry(frag: Fragment) -> @location(0) vec4<f32> {
     // side-count "morph" is a distance-field blend, not a vertex-matched interpolation --
     // cheap, and every endpoint is already rounded, so there's no acute unrounded corner
     // for the blend to make look wrong mid-transition.
-    let d = mix(d0, d1, fract(sides)) - round_amount;
+    return mix(d0, d1, fract(sides)) - round_amount;
+}
+@fragment
+fn fragment_entry(frag: Fragment) -> @location(0) vec4<f32> {
+    let center = frag.section.xy + frag.section.zw * 0.5;
+    let apothem = min(frag.section.z, frag.section.w) * 0.5;
+    let p = frag.local - center;
+    let d = sd_polygon(p, apothem, frag.params);
     let aa = max(fwidth(d) * 0.5, 0.0001);
     let coverage = smoothstep(aa, -aa, d);
-    return vec4f(frag.color.rgb, frag.color.a * coverage);
+    let color = filled(
+        frag.color,
+        frag.local,
+        frag.section,
+        frag.fill_geometry,
+        frag.fill_offsets,
+        frag.fill_c0,
+        frag.fill_c1,
+        frag.fill_c2,
+        frag.fill_c3,
+    );
+    // The spread grows the apothem rather than offsetting the field, so the shadow keeps the
+    // shape's own rounding instead of bulging at the corners.
+    let caster = sd_polygon(
+        p - frag.shadow_geometry.xy,
+        max(apothem + frag.shadow_geometry.w, 0.0),
+        frag.params
+    );
+    let shadow = vec4f(
+        frag.shadow_color.rgb,
+        frag.shadow_color.a * shadow_coverage(caster, frag.shadow_geometry.z)
+    );
+    return over_shadow(vec4f(color.rgb, color.a * coverage), shadow, coverage);
 }
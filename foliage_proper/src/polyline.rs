@@ -102,7 +102,7 @@ impl Default for PolylineStyle {
 
 /// A repeating on/off run length along the path, in px, continuous across vertices (the
 /// pattern doesn't reset at a bend) -- see `dashed_segments` (engine-internal).
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct DashPattern {
     pub(crate) on: f32,
     pub(crate) off: f32,
@@ -4,8 +4,10 @@
 //! numbers, which is what lets a full-bleed image sit flush inside a rounded panel.
 
 use crate::{
-    AsTree, Component, CoordinateUnit, InteractionShape, Panel, Physical, Resolve, Section,
+    AsTree, Component, CoordinateContext, CoordinateUnit, InteractionShape, Panel, Physical,
+    Resolve, Section,
 };
+use bevy_ecs::entity::Entity;
 use bevy_ecs::lifecycle::HookContext;
 use bevy_ecs::world::DeferredWorld;
 
@@ -37,9 +39,12 @@ impl Rounding {
     /// *half*-side, landing exactly on the largest radius the corner discs in `sdf.wgsl` stay
     /// exact at. Every fixed radius is clamped to that same ceiling, so a chip smaller than
     /// its own bracket can't ask for more curve than its box has room for.
-    pub(crate) fn depth(
+    ///
+    /// Read in physical pixels by the renderers and in logical ones, at a scale of `1.0`, by
+    /// the hit test -- the same curve either way.
+    pub(crate) fn depth<C: CoordinateContext>(
         self,
-        section: Section<Physical>,
+        section: Section<C>,
         scale_factor: CoordinateUnit,
     ) -> CoordinateUnit {
         let min = section.width().min(section.height()) * 0.5;
@@ -54,11 +59,9 @@ impl Rounding {
     }
     fn on_insert(mut world: DeferredWorld, ctx: HookContext) {
         let this = ctx.entity;
-        if *world.get::<Rounding>(this).unwrap() == Rounding::Full {
-            world.tree().write_to(this, InteractionShape::Circle);
-        } else {
-            world.tree().write_to(this, InteractionShape::Rectangle);
-        }
+        let rounding = *world.get::<Rounding>(this).unwrap();
+        let side = world.get::<Side>(this).copied().unwrap_or_default();
+        Self::reshape(&mut world, this, rounding, side);
         // Only `Panel` needs poking: it resolves its radii from an observer, so a rounding
         // change with no section change would otherwise never reach it. `Image` resolves
         // its own in a `Changed<Rounding>` system, which this insert already satisfies.
@@ -67,6 +70,24 @@ impl Rounding {
         }
     }
 }
+impl Rounding {
+    /// Points the hit test at the corners as drawn. Leaves a shape that follows something
+    /// other than the corners alone -- an image's alpha, a polygon -- which already accounts
+    /// for everything a rounding could cut.
+    fn reshape(world: &mut DeferredWorld, this: Entity, rounding: Rounding, side: Side) {
+        if matches!(
+            world.get::<InteractionShape>(this),
+            Some(InteractionShape::Alpha(_) | InteractionShape::Polygon(_))
+        ) {
+            return;
+        }
+        let shape = match rounding {
+            Rounding::None => InteractionShape::Rectangle,
+            _ => InteractionShape::Rounded { rounding, side },
+        };
+        world.tree().write_to(this, shape);
+    }
+}
 /// Which corners [`Rounding`] actually applies to -- composes independently of the amount
 /// (a segmented control's middle segment wants `Side::none()`, its first wants
 /// `Side::left()`, sharing one `Rounding` throughout). Defaults to all four.
@@ -149,6 +170,10 @@ impl Side {
     }
     fn on_insert(mut world: DeferredWorld, ctx: HookContext) {
         let this = ctx.entity;
+        if let Some(rounding) = world.get::<Rounding>(this).copied() {
+            let side = *world.get::<Side>(this).unwrap();
+            Rounding::reshape(&mut world, this, rounding, side);
+        }
         if world.get::<Panel>(this).is_some() {
             world.tree().send_to(Resolve::<Panel>::new(), this);
         }
@@ -0,0 +1,178 @@
+use crate::AsTree;
+use crate::anim::interpolation::Interpolations;
+use crate::ash::clip::ClipContext;
+use crate::opacity::BlendedOpacity;
+use crate::remove::Remove;
+use crate::transform::ResolvedTransform;
+use crate::{
+    Animate, Attachment, Author, Component, Differential, Foliage, LeafSprout, Logical,
+    ResolvedElevation, Resource, Section, Visibility,
+};
+use bevy_ecs::bundle::Bundle;
+use bevy_ecs::lifecycle::HookContext;
+use bevy_ecs::world::DeferredWorld;
+use bytemuck::{Pod, Zeroable};
+
+mod pipeline;
+
+/// Names a fragment registered with [`Foliage::shader`](crate::Foliage::shader) -- the only
+/// way to get one, so a [`Shader`] never names WGSL that was not checked.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ShaderId(pub(crate) u32);
+/// An element drawn by the app's own WGSL -- for visualizations, animated backgrounds and
+/// anything else the built-in primitives do not draw.
+///
+/// The app registers a fragment once at startup with
+/// [`Foliage::shader`](crate::Foliage::shader), and every `Shader` naming its [`ShaderId`]
+/// runs it over its box. The fragment defines one function,
+///
+/// ```wgsl
+/// fn shade(shading: Shading) -> vec4<f32> {
+///     return vec4<f32>(shading.uv, 0.0, 1.0);
+/// }
+/// ```
+///
+/// where `Shading` holds `uv` (`(0, 0)` at the box's top-left to `(1, 1)` at its
+/// bottom-right), `size` (the box in logical px) and `uniforms` (the element's
+/// [`ShaderUniforms`], as `array<vec4<f32>, 4>`). It returns straight-alpha color.
+///
+/// Everything else is foliage's, as for any other primitive: the box comes from the
+/// element's `Location`, and it is stacked by its elevation, clipped with its parent, faded
+/// by its [`Opacity`](crate::Opacity) and turned by its [`Transform`](crate::Transform). The
+/// fragment sees nothing of the frame beyond its own box, and draws nothing outside it.
+///
+/// A shader has no clock of its own: drive motion through its uniforms, with
+/// [`Grows::uniforms`](crate::Grows::uniforms) or a tween of
+/// [`Motion::Uniforms`](crate::Motion::Uniforms).
+#[derive(Component, Copy, Clone, PartialEq, Debug)]
+#[require(ShaderUniforms, Differential<Self, ShaderUniforms>)]
+#[require(Differential<Self, Self>)]
+#[require(Differential<Self, Section<Logical>>)]
+#[require(Differential<Self, ResolvedElevation>)]
+#[require(Differential<Self, BlendedOpacity>)]
+#[require(Differential<Self, ClipContext>)]
+#[require(Differential<Self, ResolvedTransform>)]
+#[component(on_add = Self::on_add)]
+pub struct Shader {
+    id: ShaderId,
+}
+impl Shader {
+    /// Starts a [`Shader`] entity running the fragment `id` names.
+    pub fn new(id: ShaderId) -> ShaderSprout {
+        ShaderSprout {
+            leaf: LeafSprout::default(),
+            shader: Shader { id },
+            uniforms: ShaderUniforms::default(),
+        }
+    }
+    fn on_add(mut world: DeferredWorld, ctx: HookContext) {
+        let this = ctx.entity;
+        let mut tree = world.tree();
+        tree.subscribe(this, Remove::push_remove_packet::<Self>);
+        tree.subscribe(this, Visibility::push_remove_packet::<Self>);
+    }
+    /// `fragment` with everything foliage puts around it -- what is checked at registration
+    /// and what the pipeline builds.
+    pub(crate) fn source(fragment: &str) -> String {
+        format!(
+            "{}{}{}",
+            include_str!("../transform.wgsl"),
+            include_str!("shader.wgsl"),
+            fragment
+        )
+    }
+    /// Parses and validates `fragment` in place, so a mistake in it is reported against the
+    /// app's own WGSL at startup rather than by the GPU at the first frame.
+    pub(crate) fn check(fragment: &str) -> Result<(), String> {
+        use wgpu::naga::front::wgsl;
+        use wgpu::naga::valid::{Capabilities, ValidationFlags, Validator};
+        let source = Self::source(fragment);
+        let module = wgsl::parse_str(&source).map_err(|e| e.emit_to_string(&source))?;
+        Validator::new(ValidationFlags::all(), Capabilities::empty())
+            .validate(&module)
+            .map_err(|e| e.emit_to_string(&source))?;
+        Ok(())
+    }
+}
+impl Attachment for Shader {
+    fn attach(foliage: &mut Foliage) {
+        foliage.world.insert_resource(Shaders::default());
+        foliage.enable_animation::<ShaderUniforms>();
+        foliage.remove_queue::<Self>();
+        foliage.differential::<Self, Self>();
+        foliage.differential::<Self, ShaderUniforms>();
+        foliage.differential::<Self, Section<Logical>>();
+        foliage.differential::<Self, ResolvedElevation>();
+        foliage.differential::<Self, ClipContext>();
+        foliage.differential::<Self, BlendedOpacity>();
+        foliage.differential::<Self, ResolvedTransform>();
+    }
+}
+/// Every fragment registered, indexed by its [`ShaderId`]. Filled at startup and only read
+/// after, by the pipeline building each one the first time it prepares.
+#[derive(Resource, Default)]
+pub(crate) struct Shaders {
+    pub(crate) fragments: Vec<String>,
+}
+/// Sixteen numbers a [`Shader`]'s fragment reads as `shading.uniforms`, four `vec4`s -- its
+/// colors, its phase, whatever it is drawn from. Zero until set.
+#[repr(C)]
+#[derive(Component, Copy, Clone, PartialEq, Debug, Default, Pod, Zeroable)]
+pub struct ShaderUniforms(pub [[f32; 4]; 4]);
+impl ShaderUniforms {
+    /// All zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Sets `uniforms[slot]`.
+    ///
+    /// **Panics if `slot` is 4 or more.**
+    pub fn with(mut self, slot: usize, value: [f32; 4]) -> Self {
+        self.0[slot] = value;
+        self
+    }
+}
+impl From<[[f32; 4]; 4]> for ShaderUniforms {
+    fn from(value: [[f32; 4]; 4]) -> Self {
+        Self(value)
+    }
+}
+impl Animate for ShaderUniforms {
+    fn interpolations(start: &Self, end: &Self) -> Interpolations {
+        start
+            .0
+            .as_flattened()
+            .iter()
+            .zip(end.0.as_flattened())
+            .fold(Interpolations::new(), |i, (s, e)| i.with(*s, *e))
+    }
+
+    fn apply(&mut self, interpolations: &mut Interpolations) {
+        for (index, value) in self.0.as_flattened_mut().iter_mut().enumerate() {
+            if let Some(v) = interpolations.read(index) {
+                *value = v;
+            }
+        }
+    }
+}
+/// Builder for a [`Shader`] entity -- see [`Shader::new`].
+pub struct ShaderSprout {
+    leaf: LeafSprout,
+    shader: Shader,
+    uniforms: ShaderUniforms,
+}
+impl Author for ShaderSprout {
+    fn seed(&mut self) -> &mut LeafSprout {
+        &mut self.leaf
+    }
+    fn root(self) -> impl Bundle {
+        (self.shader, self.uniforms)
+    }
+}
+impl ShaderSprout {
+    /// What the fragment starts out reading.
+    pub fn uniforms(mut self, uniforms: ShaderUniforms) -> Self {
+        self.uniforms = uniforms;
+        self
+    }
+}
@@ -0,0 +1,293 @@
+use crate::ash::clip::ClipContext;
+use crate::ash::differential::RenderQueueHandle;
+use crate::ash::instance::InstanceId;
+use crate::ash::node::{Node, Nodes, RemoveNode};
+use crate::ash::render::{GroupId, Parameters, PipelineId, Render, RenderGroup, Renderer};
+use crate::ginkgo::{Ginkgo, Uniform};
+use crate::opacity::BlendedOpacity;
+use crate::shader::{Shader, ShaderId, ShaderUniforms, Shaders};
+use crate::transform::ResolvedTransform;
+use crate::{Coordinates, Logical, Parent, Physical, ResolvedElevation, Section};
+use bevy_ecs::entity::Entity;
+use bytemuck::{Pod, Zeroable};
+use std::collections::HashMap;
+use wgpu::{
+    BindGroupDescriptor, BindGroupLayoutDescriptor, PipelineLayout, PipelineLayoutDescriptor,
+    RenderPass, RenderPipeline, RenderPipelineDescriptor, ShaderModuleDescriptor, ShaderSource,
+    ShaderStages, VertexState, VertexStepMode,
+};
+
+/// The unit box as two triangles, scaled to the element's in the vertex stage.
+const VERTICES: [Coordinates; 6] = [
+    Coordinates::new(0f32, 0f32),
+    Coordinates::new(0f32, 1f32),
+    Coordinates::new(1f32, 0f32),
+    Coordinates::new(1f32, 0f32),
+    Coordinates::new(0f32, 1f32),
+    Coordinates::new(1f32, 1f32),
+];
+const ONE_NODE_PER_GROUP_OPTIMIZATION: InstanceId = 0;
+/// What `Renderer::pipeline` is built from: draws nothing, and only stands in for a shader
+/// whose own pipeline is not built yet.
+const STAND_IN: &str = "fn shade(shading: Shading) -> vec4<f32> { return vec4<f32>(0.0); }";
+
+pub(crate) struct Resources {
+    entity_to_group: HashMap<Entity, GroupId>,
+    group_layout: wgpu::BindGroupLayout,
+    pipeline_layout: PipelineLayout,
+    /// One per registered fragment, built the first time an element uses it.
+    pipelines: HashMap<ShaderId, RenderPipeline>,
+}
+/// Everything one shader element draws from: its box, its depth with opacity and the scale
+/// factor, the transform's matrix less identity, and the app's uniforms.
+#[repr(C)]
+#[derive(Pod, Zeroable, Copy, Clone, Default, PartialEq)]
+pub(crate) struct Placement {
+    section: [f32; 4],
+    depth: [f32; 4],
+    linear: [f32; 4],
+    uniforms: [[f32; 4]; 4],
+}
+/// One shader element -- a single node at its elevation, like a path.
+pub(crate) struct Group {
+    shader: Option<ShaderId>,
+    placement: Placement,
+    uniform: Uniform<Placement>,
+    bind_group: wgpu::BindGroup,
+    write_uniform: bool,
+    update_node: bool,
+    elevation: ResolvedElevation,
+    clip_context: Parent,
+    section: Section<Physical>,
+    transform: ResolvedTransform,
+}
+impl Group {
+    fn new(ginkgo: &Ginkgo, layout: &wgpu::BindGroupLayout, elevation: ResolvedElevation) -> Self {
+        let placement = Placement {
+            depth: [elevation.value(), 1.0, 1.0, 0.0],
+            ..Default::default()
+        };
+        let uniform = Uniform::new(ginkgo.context(), placement);
+        let bind_group = ginkgo.create_bind_group(&BindGroupDescriptor {
+            label: Some("shader-group"),
+            layout,
+            entries: &[Ginkgo::uniform_bind_group_entry(&uniform, 0)],
+        });
+        Self {
+            shader: None,
+            placement,
+            uniform,
+            bind_group,
+            write_uniform: false,
+            update_node: true,
+            elevation,
+            clip_context: Default::default(),
+            section: Section::default(),
+            transform: ResolvedTransform::IDENTITY,
+        }
+    }
+    /// Draws the box's corner where its transform takes it, and turns the box about it.
+    fn place(&mut self) {
+        let origin = self.transform.apply(self.section.position);
+        self.placement.section = [
+            origin.left(),
+            origin.top(),
+            self.section.width(),
+            self.section.height(),
+        ];
+        let [a, b, c, d] = self.transform.linear;
+        self.placement.linear = [a - 1.0, b, c, d - 1.0];
+        self.write_uniform = true;
+    }
+}
+/// Builds `fragment` into a pipeline. Checked when it was registered, so this only fails if
+/// the device refuses something validation allowed.
+fn pipeline(ginkgo: &Ginkgo, layout: &PipelineLayout, fragment: &str) -> RenderPipeline {
+    let shader = ginkgo.create_shader(ShaderModuleDescriptor {
+        label: Some("shader-shader"),
+        source: ShaderSource::Wgsl(Shader::source(fragment).into()),
+    });
+    ginkgo.create_pipeline(&RenderPipelineDescriptor {
+        label: Some("shader-render-pipeline"),
+        layout: Some(layout),
+        vertex: VertexState {
+            module: &shader,
+            entry_point: Option::from("vertex_entry"),
+            compilation_options: Default::default(),
+            buffers: &[Ginkgo::vertex_buffer_layout::<Coordinates>(
+                VertexStepMode::Vertex,
+                &wgpu::vertex_attr_array![0 => Float32x2],
+            )],
+        },
+        primitive: Ginkgo::triangle_list_primitive(),
+        depth_stencil: ginkgo.depth_stencil_state(),
+        multisample: ginkgo.msaa_state(),
+        fragment: Ginkgo::fragment_state(
+            &shader,
+            "fragment_entry",
+            &ginkgo.alpha_color_target_state(),
+        ),
+        multiview_mask: None,
+        cache: None,
+    })
+}
+impl Render for Shader {
+    type Group = Group;
+    type Resources = Resources;
+
+    fn renderer(ginkgo: &Ginkgo) -> Renderer<Self> {
+        let vertex_buffer = ginkgo.create_vertex_buffer(VERTICES);
+        let bind_group_layout = ginkgo.create_bind_group_layout(&BindGroupLayoutDescriptor {
+            label: Some("shader-bind-group-layout"),
+            entries: &[Ginkgo::bind_group_layout_entry(0)
+                .at_stages(ShaderStages::VERTEX)
+                .uniform_entry()],
+        });
+        let bind_group = ginkgo.create_bind_group(&BindGroupDescriptor {
+            label: Some("shader-bind-group"),
+            layout: &bind_group_layout,
+            entries: &[ginkgo.viewport_bind_group_entry(0)],
+        });
+        let group_layout = ginkgo.create_bind_group_layout(&BindGroupLayoutDescriptor {
+            label: Some("shader-group-bind-group-layout"),
+            entries: &[Ginkgo::bind_group_layout_entry(0)
+                .at_stages(ShaderStages::VERTEX | ShaderStages::FRAGMENT)
+                .uniform_entry()],
+        });
+        let pipeline_layout = ginkgo.create_pipeline_layout(&PipelineLayoutDescriptor {
+            label: Some("shader-pipeline-layout"),
+            bind_group_layouts: &[Some(&group_layout), Some(&bind_group_layout)],
+            immediate_size: 0,
+        });
+        Renderer {
+            pipeline: pipeline(ginkgo, &pipeline_layout, STAND_IN),
+            vertex_buffer,
+            bind_group,
+            groups: Default::default(),
+            resources: Resources {
+                entity_to_group: Default::default(),
+                group_layout,
+                pipeline_layout,
+                pipelines: Default::default(),
+            },
+        }
+    }
+
+    fn prepare(
+        renderer: &mut Renderer<Self>,
+        queues: &mut RenderQueueHandle,
+        ginkgo: &Ginkgo,
+    ) -> Nodes {
+        tracing::trace!("pipeline: shader prepare");
+        let mut nodes = Nodes::new();
+        for entity in queues.removes::<Shader>() {
+            if let Some(id) = renderer.resources.entity_to_group.remove(&entity) {
+                queues.remove_attr::<Shader, Shader>(entity);
+                queues.remove_attr::<Shader, ShaderUniforms>(entity);
+                queues.remove_attr::<Shader, Section<Logical>>(entity);
+                queues.remove_attr::<Shader, ResolvedElevation>(entity);
+                queues.remove_attr::<Shader, ClipContext>(entity);
+                queues.remove_attr::<Shader, BlendedOpacity>(entity);
+                queues.remove_attr::<Shader, ResolvedTransform>(entity);
+                renderer.groups.remove(&id);
+                nodes.remove(RemoveNode::new(
+                    PipelineId::Shader,
+                    id,
+                    ONE_NODE_PER_GROUP_OPTIMIZATION,
+                ));
+            }
+        }
+        for (entity, elevation) in queues.attribute::<Shader, ResolvedElevation>() {
+            if let std::collections::hash_map::Entry::Vacant(e) =
+                renderer.resources.entity_to_group.entry(entity)
+            {
+                let group = Group::new(ginkgo, &renderer.resources.group_layout, elevation);
+                renderer
+                    .groups
+                    .insert(entity.to_bits(), RenderGroup::new(group));
+                e.insert(entity.to_bits());
+            } else {
+                let id = renderer.resources.entity_to_group.get(&entity).unwrap();
+                let group = &mut renderer.groups.get_mut(id).unwrap().group;
+                group.elevation = elevation;
+                group.placement.depth[0] = elevation.value();
+                group.write_uniform = true;
+                group.update_node = true;
+            }
+        }
+        for (entity, shader) in queues.attribute::<Shader, Shader>() {
+            if !renderer.resources.pipelines.contains_key(&shader.id) {
+                let fragment = &queues.world.resource::<Shaders>().fragments[shader.id.0 as usize];
+                let built = pipeline(ginkgo, &renderer.resources.pipeline_layout, fragment);
+                renderer.resources.pipelines.insert(shader.id, built);
+            }
+            let id = renderer.resources.entity_to_group.get(&entity).unwrap();
+            let group = &mut renderer.groups.get_mut(id).unwrap().group;
+            group.shader.replace(shader.id);
+        }
+        let sf = ginkgo.configuration().scale_factor.value();
+        for (entity, clip) in queues.attribute::<Shader, ClipContext>() {
+            let id = renderer.resources.entity_to_group.get(&entity).unwrap();
+            let group = &mut renderer.groups.get_mut(id).unwrap().group;
+            group.clip_context = clip.0;
+            group.update_node = true;
+        }
+        for (entity, section) in queues.attribute::<Shader, Section<Logical>>() {
+            let id = renderer.resources.entity_to_group.get(&entity).unwrap();
+            let group = &mut renderer.groups.get_mut(id).unwrap().group;
+            group.section = section.to_physical(sf).rounded();
+            group.placement.depth[2] = sf;
+            group.place();
+        }
+        for (entity, transform) in queues.attribute::<Shader, ResolvedTransform>() {
+            let id = renderer.resources.entity_to_group.get(&entity).unwrap();
+            let group = &mut renderer.groups.get_mut(id).unwrap().group;
+            group.transform = transform.to_physical(sf);
+            group.place();
+        }
+        for (entity, opacity) in queues.attribute::<Shader, BlendedOpacity>() {
+            let id = renderer.resources.entity_to_group.get(&entity).unwrap();
+            let group = &mut renderer.groups.get_mut(id).unwrap().group;
+            group.placement.depth[1] = opacity.value;
+            group.write_uniform = true;
+        }
+        for (entity, uniforms) in queues.attribute::<Shader, ShaderUniforms>() {
+            let id = renderer.resources.entity_to_group.get(&entity).unwrap();
+            let group = &mut renderer.groups.get_mut(id).unwrap().group;
+            group.placement.uniforms = uniforms.0;
+            group.write_uniform = true;
+        }
+        for (group_id, render_group) in renderer.groups.iter_mut() {
+            let group = &mut render_group.group;
+            if group.write_uniform {
+                group.uniform.write(ginkgo.context(), group.placement);
+                group.write_uniform = false;
+            }
+            if group.update_node {
+                nodes.update(Node::new(
+                    group.elevation,
+                    PipelineId::Shader,
+                    *group_id,
+                    0,
+                    group.clip_context,
+                    ONE_NODE_PER_GROUP_OPTIMIZATION,
+                ));
+                group.update_node = false;
+            }
+        }
+        nodes
+    }
+
+    fn render(renderer: &mut Renderer<Self>, render_pass: &mut RenderPass, parameters: Parameters) {
+        let group = &renderer.groups.get(&parameters.group).unwrap().group;
+        let pipeline = group
+            .shader
+            .and_then(|id| renderer.resources.pipelines.get(&id))
+            .unwrap_or(&renderer.pipeline);
+        render_pass.set_pipeline(pipeline);
+        render_pass.set_bind_group(0, &group.bind_group, &[]);
+        render_pass.set_bind_group(1, &renderer.bind_group, &[]);
+        render_pass.set_vertex_buffer(0, renderer.vertex_buffer.slice(..));
+        render_pass.draw(0..VERTICES.len() as u32, 0..1);
+    }
+}
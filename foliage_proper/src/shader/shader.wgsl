@@ -0,0 +1,49 @@
+// What an app's `Shader` draws inside. The app's fragment follows this file and defines
+// `shade`, called once a fragment with where it falls in the element's box; what it returns
+// is straight-alpha color, faded by the element's opacity on the way out.
+struct Placement {
+    // x, y, width, height, in physical pixels
+    section: vec4<f32>,
+    // layer, opacity, scale factor, unused
+    depth: vec4<f32>,
+    linear: vec4<f32>,
+    uniforms: array<vec4<f32>, 4>,
+};
+@group(0)
+@binding(0)
+var<uniform> placement: Placement;
+@group(1)
+@binding(0)
+var<uniform> viewport: mat4x4<f32>;
+struct Shading {
+    // (0, 0) at the box's top-left to (1, 1) at its bottom-right.
+    uv: vec2<f32>,
+    // The box in logical pixels, for a shape that should keep its proportions.
+    size: vec2<f32>,
+    // What the app last set with `ShaderUniforms`.
+    uniforms: array<vec4<f32>, 4>,
+};
+struct Fragment {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+};
+@vertex
+fn vertex_entry(@location(0) corner: vec2<f32>) -> Fragment {
+    let local = corner * placement.section.zw;
+    let position = vec4<f32>(
+        placement.section.xy + transformed(local, placement.linear, vec2<f32>(0.0)),
+        placement.depth.x,
+        1.0
+    );
+    return Fragment(viewport * position, corner);
+}
+@fragment
+fn fragment_entry(frag: Fragment) -> @location(0) vec4<f32> {
+    let shading = Shading(
+        frag.uv,
+        placement.section.zw / placement.depth.z,
+        placement.uniforms
+    );
+    let color = shade(shading);
+    return vec4<f32>(color.rgb, color.a * placement.depth.y);
+}
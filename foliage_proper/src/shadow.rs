@@ -0,0 +1,151 @@
+use crate::anim::interpolation::Interpolations;
+use crate::{Animate, Attachment, Color, Component, Coordinates, Foliage};
+
+/// A soft shadow cast beneath a [`Panel`](crate::Panel) or [`Polygon`](crate::Polygon), so a
+/// card or a menu reads as raised off what it sits on.
+///
+/// Worked out in the shader from the shape's own distance field -- the same rounding, grown
+/// by [`spread`](Self::spread), moved by [`offset`](Self::offset) and blurred by
+/// [`blur`](Self::blur) -- so it needs no second element and follows the shape as it
+/// morphs. Drawn only outside the shape, as CSS draws `box-shadow`: a translucent card does
+/// not show its own shadow through it. Faded by [`Opacity`](crate::Opacity) with the rest of
+/// the element.
+///
+/// [`Elevation`](crate::Elevation) only orders draws; it casts nothing. Pair the two by hand,
+/// or take [`Shadow::elevated`], which casts what a surface that many steps up would.
+/// Animatable as [`Motion::Shadow`](crate::Motion::Shadow), so a card can lift on hover.
+#[derive(Component, Copy, Clone, Debug, PartialEq)]
+pub struct Shadow {
+    pub color: Color,
+    /// Logical pixels right and down from the shape.
+    pub offset: Coordinates,
+    /// Logical pixels, as CSS reads a blur radius -- twice the Gaussian's deviation.
+    pub blur: f32,
+    /// Logical pixels the shape grows by before it is blurred; negative shrinks it.
+    pub spread: f32,
+}
+impl Attachment for Shadow {
+    fn attach(foliage: &mut Foliage) {
+        foliage.enable_animation::<Self>();
+    }
+}
+impl Shadow {
+    /// A black shadow directly beneath, hard-edged until given a [`blur`](Self::blur).
+    pub fn new() -> Self {
+        Self {
+            color: Color::new(0.0, 0.0, 0.0, 0.3),
+            offset: Coordinates::new(0.0, 0.0),
+            blur: 0.0,
+            spread: 0.0,
+        }
+    }
+    /// No shadow -- what every element casts until given one.
+    pub fn none() -> Self {
+        Self {
+            color: Color::new(0.0, 0.0, 0.0, 0.0),
+            ..Self::new()
+        }
+    }
+    /// What a surface `steps` [`up`](crate::Elevation::up) from what it sits on casts,
+    /// after Material's elevation levels: each step drops the shadow further and softens
+    /// it. `0` and below cast nothing; past `5` it stops growing.
+    pub fn elevated(steps: i32) -> Self {
+        let (y, blur, alpha) = match steps {
+            i32::MIN..=0 => return Self::none(),
+            1 => (1.0, 3.0, 0.30),
+            2 => (2.0, 6.0, 0.28),
+            3 => (4.0, 10.0, 0.26),
+            4 => (6.0, 14.0, 0.24),
+            _ => (8.0, 18.0, 0.22),
+        };
+        Self::new()
+            .color(Color::new(0.0, 0.0, 0.0, alpha))
+            .offset(0.0, y)
+            .blur(blur)
+    }
+    /// The shadow's color; its alpha is how dark it is at its densest.
+    pub fn color(mut self, c: Color) -> Self {
+        self.color = c;
+        self
+    }
+    /// Moved `x` right and `y` down, in logical pixels.
+    pub fn offset(mut self, x: f32, y: f32) -> Self {
+        self.offset = Coordinates::new(x, y);
+        self
+    }
+    /// Softened over `radius` logical pixels either side of its edge; `0.0` is hard.
+    pub fn blur(mut self, radius: f32) -> Self {
+        self.blur = radius.max(0.0);
+        self
+    }
+    /// Grown by `s` logical pixels on every side before blurring.
+    pub fn spread(mut self, s: f32) -> Self {
+        self.spread = s;
+        self
+    }
+    /// Laid out for an instance buffer, in device pixels, faded by `opacity`.
+    pub(crate) fn c_repr(&self, opacity: f32, factor: f32) -> CReprShadow {
+        CReprShadow {
+            color: self.color.with_opacity(opacity).c_repr().value,
+            geometry: [
+                self.offset.a() * factor,
+                self.offset.b() * factor,
+                self.blur * factor,
+                self.spread * factor,
+            ],
+        }
+    }
+}
+impl Default for Shadow {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+impl Animate for Shadow {
+    fn interpolations(start: &Self, end: &Self) -> Interpolations {
+        Interpolations::new()
+            .with(start.color.r(), end.color.r())
+            .with(start.color.g(), end.color.g())
+            .with(start.color.b(), end.color.b())
+            .with(start.color.a(), end.color.a())
+            .with(start.offset.a(), end.offset.a())
+            .with(start.offset.b(), end.offset.b())
+            .with(start.blur, end.blur)
+            .with(start.spread, end.spread)
+    }
+
+    fn apply(&mut self, interpolations: &mut Interpolations) {
+        if let Some(r) = interpolations.read(0) {
+            self.color.set_red(r);
+        }
+        if let Some(g) = interpolations.read(1) {
+            self.color.set_green(g);
+        }
+        if let Some(b) = interpolations.read(2) {
+            self.color.set_blue(b);
+        }
+        if let Some(a) = interpolations.read(3) {
+            self.color.set_alpha(a);
+        }
+        if let Some(x) = interpolations.read(4) {
+            self.offset.set_horizontal(x);
+        }
+        if let Some(y) = interpolations.read(5) {
+            self.offset.set_vertical(y);
+        }
+        if let Some(b) = interpolations.read(6) {
+            self.blur = b;
+        }
+        if let Some(s) = interpolations.read(7) {
+            self.spread = s;
+        }
+    }
+}
+/// [`Shadow`] as the shader reads it: its color, then offset, blur and spread in device
+/// pixels. A zeroed slot is transparent, and casts nothing.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, bytemuck::Pod, bytemuck::Zeroable)]
+pub(crate) struct CReprShadow {
+    color: [f32; 4],
+    geometry: [f32; 4],
+}
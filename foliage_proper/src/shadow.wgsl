@@ -0,0 +1,36 @@
+// `Shadow`, prepended to every shader that casts one (see the `format!` in each pipeline's
+// `renderer`). `geometry` is the offset, then the blur and the spread, in physical pixels.
+
+// How far past its box a shadow reaches -- what the quad grows by on every side to hold it.
+// Three deviations of blur, past which the Gaussian has nothing left to show.
+fn shadow_reach(color: vec4<f32>, geometry: vec4<f32>) -> f32 {
+    if color.a <= 0.0 {
+        return 0.0;
+    }
+    let offset = max(abs(geometry.x), abs(geometry.y));
+    return max(geometry.w + 1.5 * geometry.z + offset, 0.0);
+}
+// The error function, to within a few thousandths -- WGSL has none.
+fn shadow_erf(x: f32) -> f32 {
+    let a = abs(x);
+    var r = 1.0 + (0.278393 + (0.230389 + 0.078108 * (a * a)) * a) * a;
+    r = r * r;
+    return sign(x) * (1.0 - 1.0 / (r * r));
+}
+// How much of a shadow lands where its shape's field reads `d`: a straight edge blurred by a
+// Gaussian of deviation `blur / 2`, which is exact along the sides and close at the corners.
+fn shadow_coverage(d: f32, blur: f32) -> f32 {
+    if blur <= 0.0 {
+        return clamp(0.5 - d, 0.0, 1.0);
+    }
+    return 0.5 - 0.5 * shadow_erf(d / (blur * 0.5 * 1.41421356));
+}
+// `fill` drawn over `shadow`, the shadow cut away wherever the shape itself covers (`inside`).
+fn over_shadow(fill: vec4<f32>, shadow: vec4<f32>, inside: f32) -> vec4<f32> {
+    let s = shadow.a * (1.0 - inside) * (1.0 - fill.a);
+    let a = fill.a + s;
+    if a <= 0.0 {
+        return vec4<f32>(0.0);
+    }
+    return vec4<f32>((fill.rgb * fill.a + shadow.rgb * s) / a, a);
+}
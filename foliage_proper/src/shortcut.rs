@@ -0,0 +1,190 @@
+use crate::interaction::CurrentInteraction;
+use crate::remove::Remove;
+use crate::text_input::{Handle, Input, keybindings::KeyBindings};
+use crate::{
+    Attachment, Event, Foliage, InputSequence, Parent, PhysicalInputSequence, PhysicalKey,
+    Resource, TextInput, Tree, Trigger,
+};
+use bevy_ecs::entity::Entity;
+use bevy_ecs::system::{Query, Res, ResMut};
+
+/// The keys a shortcut is pressed with: one [`PhysicalInputSequence`], or several pressed one
+/// after another -- Ctrl+K then Ctrl+S.
+///
+/// Physical rather than logical, so a shortcut stays on the same keys whatever the layout. A
+/// shortcut is a place the hand goes; on an AZERTY keyboard the Ctrl+Z a user reaches for is
+/// where the W is printed, and it should undo all the same.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Chord(Vec<PhysicalInputSequence>);
+impl From<PhysicalInputSequence> for Chord {
+    fn from(keys: PhysicalInputSequence) -> Self {
+        Self(vec![keys])
+    }
+}
+impl<const N: usize> From<[PhysicalInputSequence; N]> for Chord {
+    fn from(keys: [PhysicalInputSequence; N]) -> Self {
+        Self(keys.to_vec())
+    }
+}
+impl From<Vec<PhysicalInputSequence>> for Chord {
+    fn from(keys: Vec<PhysicalInputSequence>) -> Self {
+        Self(keys)
+    }
+}
+
+/// Every registered shortcut, and how far into a chord the keyboard has got.
+///
+/// A shortcut matches its keys exactly, modifiers included -- unlike
+/// [`KeyBindings`](crate::KeyBindings), which lets a binding on Ctrl also fire under Ctrl+Shift.
+/// That superset-match is right for a caret, where Shift only extends what the key does; across
+/// an app it would have Ctrl+Shift+S saving as well as saving-as.
+#[derive(Resource, Default)]
+pub(crate) struct Shortcuts {
+    bindings: Vec<Binding>,
+    /// The keys pressed so far of a chord still being typed.
+    pending: Vec<PhysicalInputSequence>,
+    /// Whether a text input took the key being resolved. Set from the logical half of a key
+    /// event, which the loop triggers ahead of the physical half.
+    consumed: bool,
+}
+struct Binding {
+    keys: Chord,
+    id: String,
+    /// Live only while focus is on this entity or inside it. `None` is app-wide.
+    scope: Option<Entity>,
+}
+impl Shortcuts {
+    /// Registers `keys` under `id`. Registering an `id` again replaces it, so a keymap can be
+    /// rebound without being unbound first.
+    pub(crate) fn bind(&mut self, keys: Chord, id: String, scope: Option<Entity>) {
+        self.unbind(&id);
+        if keys.0.is_empty() {
+            return;
+        }
+        self.bindings.push(Binding { keys, id, scope });
+    }
+    pub(crate) fn unbind(&mut self, id: &str) {
+        self.bindings.retain(|binding| binding.id != id);
+    }
+    /// Whether anything is bound within `scope`.
+    pub(crate) fn scoped(&self, scope: Entity) -> bool {
+        self.bindings.iter().any(|b| b.scope == Some(scope))
+    }
+    /// A key event is starting: whatever a text input took before it -- an IME commit has
+    /// a logical half and no physical one -- is not this key's to stand aside for.
+    pub(crate) fn next_key(&mut self) {
+        self.consumed = false;
+    }
+    /// Drops every shortcut scoped to an element as it withers. Its id would otherwise be
+    /// recycled into an element that never asked for them.
+    pub(crate) fn prune(trigger: Trigger<Remove>, mut shortcuts: ResMut<Shortcuts>) {
+        let gone = Some(trigger.event_target());
+        shortcuts.bindings.retain(|binding| binding.scope != gone);
+    }
+}
+
+/// A registered shortcut was pressed. Untargeted -- a shortcut names no element, and a scoped
+/// one is about where focus *was*, which the funnel has no reason to report.
+#[derive(Event, Clone)]
+pub(crate) struct ShortcutMatched(pub(crate) String);
+
+impl Attachment for Shortcuts {
+    fn attach(foliage: &mut Foliage) {
+        foliage.world.insert_resource(Shortcuts::default());
+        foliage.define(Shortcuts::note_consumption);
+        foliage.define(Shortcuts::resolve);
+    }
+}
+impl Shortcuts {
+    /// Records whether the focused text input has a use for this key, so the physical half
+    /// arriving next can stand aside. Typing an `s` into a field is not a request to save --
+    /// and neither is Ctrl+A there, which the field selects all with.
+    fn note_consumption(
+        trigger: Trigger<InputSequence>,
+        mut shortcuts: ResMut<Shortcuts>,
+        current: Res<CurrentInteraction>,
+        stems: Query<&Parent>,
+        text_inputs: Query<&TextInput>,
+        handles: Query<&Handle>,
+        key_bindings: Res<KeyBindings>,
+    ) {
+        shortcuts.consumed = Input::receiver(&current, &stems, &text_inputs, &handles)
+            .is_some_and(|_| Input::consumes(&key_bindings, trigger.event()));
+    }
+    /// Advances the chord in progress by one key, and reports the shortcut it completes.
+    ///
+    /// A key that continues no live chord starts again from itself, so a stray key between
+    /// Ctrl+K and Ctrl+S abandons the chord rather than leaving it waiting indefinitely. Keys
+    /// with no physical name -- modifiers pressed on their own among them -- are passed over
+    /// without touching it: holding Ctrl down between the two halves is how a chord is typed.
+    ///
+    /// Where two live shortcuts share keys, the one scoped closest to focus wins, and an
+    /// app-wide one only once every scoped one has passed -- the same order a click is offered
+    /// to the element pressed before anything around it. A shortcut fires as soon as its keys
+    /// are complete, so one that begins another -- Ctrl+K beside Ctrl+K, Ctrl+S -- leaves the
+    /// longer one unreachable for as long as both are live.
+    fn resolve(
+        trigger: Trigger<PhysicalInputSequence>,
+        mut shortcuts: ResMut<Shortcuts>,
+        current: Res<CurrentInteraction>,
+        parents: Query<&Parent>,
+        mut tree: Tree,
+    ) {
+        let key = *trigger.event();
+        if key.code == PhysicalKey::Other {
+            return;
+        }
+        if std::mem::take(&mut shortcuts.consumed) {
+            shortcuts.pending.clear();
+            return;
+        }
+        // How far up from focus each scope sits; `None` for one focus is not inside.
+        let depth = |scope: Option<Entity>| -> Option<usize> {
+            let Some(scope) = scope else {
+                return Some(usize::MAX);
+            };
+            let mut at = current.focused;
+            let mut depth = 0;
+            while let Some(id) = at {
+                if id == scope {
+                    return Some(depth);
+                }
+                depth += 1;
+                at = parents.get(id).ok().and_then(|p| p.id);
+            }
+            None
+        };
+        let mut typed = std::mem::take(&mut shortcuts.pending);
+        typed.push(key);
+        for attempt in [typed.clone(), vec![key]] {
+            let mut complete: Option<(usize, &str)> = None;
+            let mut continues = false;
+            for binding in shortcuts.bindings.iter() {
+                let Some(depth) = depth(binding.scope) else {
+                    continue;
+                };
+                if !binding.keys.0.starts_with(&attempt) {
+                    continue;
+                }
+                if binding.keys.0.len() == attempt.len() {
+                    if complete.is_none_or(|(best, _)| depth < best) {
+                        complete = Some((depth, &binding.id));
+                    }
+                } else {
+                    continues = true;
+                }
+            }
+            if let Some((_, id)) = complete {
+                tree.send(ShortcutMatched(id.to_string()));
+                return;
+            }
+            if continues {
+                shortcuts.pending = attempt;
+                return;
+            }
+            if attempt.len() == 1 {
+                return;
+            }
+        }
+    }
+}
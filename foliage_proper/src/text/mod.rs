@@ -16,6 +16,7 @@ use crate::opacity::BlendedOpacity;
 use crate::remove::Remove;
 use crate::text::glyph::{Glyph, GlyphColor, GlyphKey, ResolvedColors};
 use crate::text::monospaced::{FontId, MonospacedFont};
+use crate::transform::ResolvedTransform;
 use crate::{
     Attachment, Layout, LayoutSection, Location, Parent, Physical, Resolve, Resolved,
     ResolvedElevation, ResolvedVisibility, Short, Tree, View, Visibility,
@@ -72,6 +73,7 @@ impl Attachment for Text {
         foliage.differential::<Text, Section<Logical>>();
         foliage.differential::<Text, ResolvedElevation>();
         foliage.differential::<Text, ClipContext>();
+        foliage.differential::<Text, ResolvedTransform>();
         // ResolvedGlyphs gets its own queuing system, not the generic `differential()` --
         // see `glyph::glyph_differential`'s own doc comment for why.
         foliage
@@ -96,6 +98,7 @@ impl Attachment for Text {
 #[require(Differential<Text, Section<Logical>>)]
 #[require(Differential<Text, ResolvedElevation>)]
 #[require(Differential<Text, ClipContext>)]
+#[require(Differential<Text, ResolvedTransform>)]
 #[require(Differential<Text, ResolvedGlyphs>)]
 #[require(Differential<Text, ResolvedColors>)]
 #[require(TextBounds, Differential<Text, TextBounds>)]
@@ -497,14 +500,13 @@ impl Text {
                     // what carries the re-resolve cascade, so the caret and highlight
                     // panels inside a text input would keep laying out against a box the
                     // text has already outgrown. State the same box in both spaces.
-                    let accumulated = stems
+                    let in_layout_space = stems
                         .get(this)
                         .ok()
                         .and_then(|s| s.id)
-                        .and_then(|p| views.get(p).ok().map(|v| v.accumulated_offset))
-                        .unwrap_or_default();
-                    let mut in_layout_space = adjusted;
-                    in_layout_space.position += accumulated;
+                        .and_then(|p| views.get(p).ok())
+                        .map(|v| v.unplace(adjusted))
+                        .unwrap_or(adjusted);
                     // `Section` first: `LayoutSection`'s insert is what re-resolves whatever
                     // is anchored to this box, and an anchor value is read out of the
                     // target's `Section`. Inserted the other way round, a dependent resolves
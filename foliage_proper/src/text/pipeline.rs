@@ -9,14 +9,17 @@ use crate::text::glyph::{GlyphKey, GlyphOffset, ResolvedColors, ResolvedGlyphs};
 use crate::text::monospaced::{FontId, MonospacedFont};
 use crate::text::{ResolvedFontSize, TextBounds, UniqueCharacters};
 use crate::texture::{AtlasEntry, TextureAtlas, TextureCoordinates, VERTICES, Vertex};
-use crate::{CReprColor, CReprSection, Logical, Parent, ResolvedElevation, Section, Text};
+use crate::transform::ResolvedTransform;
+use crate::{
+    CReprColor, CReprSection, Logical, Parent, Physical, Position, ResolvedElevation, Section, Text,
+};
 use bevy_ecs::entity::Entity;
 use std::collections::HashMap;
 use std::sync::Arc;
 use wgpu::{
     BindGroupDescriptor, BindGroupLayoutDescriptor, PipelineLayoutDescriptor, RenderPass,
-    RenderPipelineDescriptor, ShaderStages, TextureSampleType, TextureViewDimension, VertexState,
-    VertexStepMode, include_wgsl,
+    RenderPipelineDescriptor, ShaderModuleDescriptor, ShaderSource, ShaderStages,
+    TextureSampleType, TextureViewDimension, VertexState, VertexStepMode,
 };
 
 /// Rasterizes a glyph through fontdue's subpixel path, then averages each pixel's three
@@ -63,6 +66,11 @@ pub(crate) struct Group {
     pub(crate) elevation: ResolvedElevation,
     pub(crate) clip_context: Parent,
     pub(crate) uniform: VectorUniform<f32>,
+    /// The matrix of this text's `ResolvedTransform`, less identity, which every glyph turns
+    /// by about the text's origin -- the offset is folded into where `uniform` puts that origin.
+    pub(crate) linear: VectorUniform<f32>,
+    pub(crate) position: Position<Physical>,
+    pub(crate) transform: ResolvedTransform,
     pub(crate) sections: InstanceBuffer<CReprSection>,
     pub(crate) colors: InstanceBuffer<CReprColor>,
     pub(crate) tex_coords: InstanceBuffer<TextureCoordinates>,
@@ -88,6 +96,9 @@ impl Group {
             elevation,
             clip_context: Default::default(),
             uniform,
+            linear: VectorUniform::new(ginkgo.context(), [0.0; 4]),
+            position: Position::default(),
+            transform: ResolvedTransform::IDENTITY,
             sections: InstanceBuffer::new(ginkgo, initial_capacity),
             colors: InstanceBuffer::new(ginkgo, initial_capacity),
             tex_coords: InstanceBuffer::new(ginkgo, initial_capacity),
@@ -100,13 +111,34 @@ impl Group {
             last_reference: Default::default(),
         }
     }
+    /// Draws the text's origin where its transform takes it, and turns its glyphs about it.
+    fn place(&mut self) {
+        let origin = self.transform.apply(self.position);
+        self.uniform.set(0, origin.left());
+        self.uniform.set(1, origin.top());
+        let [a, b, c, d] = self.transform.linear;
+        for (i, v) in [a - 1.0, b, c, d - 1.0].into_iter().enumerate() {
+            self.linear.set(i, v);
+        }
+        self.write_uniform = true;
+    }
 }
 const ONE_NODE_PER_GROUP_OPTIMIZATION: InstanceId = 0;
 impl Render for Text {
     type Group = Group;
     type Resources = Resources;
     fn renderer(ginkgo: &Ginkgo) -> Renderer<Self> {
-        let shader = ginkgo.create_shader(include_wgsl!("text.wgsl"));
+        let shader = ginkgo.create_shader(ShaderModuleDescriptor {
+            label: Some("text-shader"),
+            source: ShaderSource::Wgsl(
+                format!(
+                    "{}{}",
+                    include_str!("../transform.wgsl"),
+                    include_str!("text.wgsl")
+                )
+                .into(),
+            ),
+        });
         let vertex_buffer = ginkgo.create_vertex_buffer(VERTICES);
         let bind_group_layout = ginkgo.create_bind_group_layout(&BindGroupLayoutDescriptor {
             label: Some("text-bind-group-layout"),
@@ -145,6 +177,9 @@ impl Render for Text {
                 Ginkgo::bind_group_layout_entry(1)
                     .at_stages(ShaderStages::VERTEX)
                     .uniform_entry(),
+                Ginkgo::bind_group_layout_entry(2)
+                    .at_stages(ShaderStages::VERTEX)
+                    .uniform_entry(),
             ],
         });
         let pipeline_layout = ginkgo.create_pipeline_layout(&PipelineLayoutDescriptor {
@@ -225,6 +260,7 @@ impl Render for Text {
                 queues.remove_attr::<Text, ResolvedFontSize>(entity);
                 queues.remove_attr::<Text, ResolvedGlyphs>(entity);
                 queues.remove_attr::<Text, ResolvedColors>(entity);
+                queues.remove_attr::<Text, ResolvedTransform>(entity);
                 renderer.groups.remove(&id);
             }
             nodes.remove(RemoveNode::new(
@@ -264,13 +300,17 @@ impl Render for Text {
         for (entity, packet) in queues.attribute::<Text, Section<Logical>>() {
             let id = renderer.resources.entity_to_group.get(&entity).unwrap();
             let group = &mut renderer.groups.get_mut(id).unwrap().group;
-            let position = packet
+            group.position = packet
                 .position
                 .to_physical(ginkgo.configuration().scale_factor.value())
                 .rounded();
-            group.uniform.set(0, position.left());
-            group.uniform.set(1, position.top());
-            group.write_uniform = true;
+            group.place();
+        }
+        for (entity, packet) in queues.attribute::<Text, ResolvedTransform>() {
+            let id = renderer.resources.entity_to_group.get(&entity).unwrap();
+            let group = &mut renderer.groups.get_mut(id).unwrap().group;
+            group.transform = packet.to_physical(ginkgo.configuration().scale_factor.value());
+            group.place();
         }
         for (entity, bounds) in queues.attribute::<Text, TextBounds>() {
             let id = renderer.resources.entity_to_group.get(&entity).unwrap();
@@ -315,6 +355,7 @@ impl Render for Text {
                         0,
                     ),
                     Ginkgo::uniform_bind_group_entry(&group.uniform.uniform, 1),
+                    Ginkgo::uniform_bind_group_entry(&group.linear.uniform, 2),
                 ],
             });
             group.bind_group.replace(bind_group);
@@ -440,6 +481,7 @@ impl Render for Text {
                             0,
                         ),
                         Ginkgo::uniform_bind_group_entry(&group.group.uniform.uniform, 1),
+                        Ginkgo::uniform_bind_group_entry(&group.group.linear.uniform, 2),
                     ],
                 });
                 group.group.bind_group.replace(bind_group);
@@ -475,6 +517,7 @@ impl Render for Text {
         for (group_id, render_group) in renderer.groups.iter_mut() {
             if render_group.group.write_uniform {
                 render_group.group.uniform.write(ginkgo.context());
+                render_group.group.linear.write(ginkgo.context());
                 render_group.group.write_uniform = false;
             }
             if let Some(capacity) = render_group.coordinator.grown() {
@@ -524,7 +567,7 @@ impl Render for Text {
         let group = renderer.groups.get(&parameters.group).unwrap();
         let clip = parameters
             .clip
-            .intersection(group.group.bounds.0)
+            .intersection(group.group.transform.bounds(group.group.bounds.0))
             .unwrap_or_default();
         // Expanded outward to whole pixels, not truncated: `TextBounds` is an unrounded
         // physical rect, and `as u32` on a fractional height shaves the bottom row -- which
@@ -10,6 +10,9 @@ var text_texture: texture_2d<f32>;
 @group(0)
 @binding(1)
 var<uniform> per_group_data: vec4<f32>;
+@group(0)
+@binding(2)
+var<uniform> per_group_linear: vec4<f32>;
 struct Vertex {
     @location(0) vertex_pos: vec2f,
     @location(1) tx_index: vec2<u32>,
@@ -25,8 +28,9 @@ struct Fragment {
 @vertex
 fn vertex_entry(vertex: Vertex) -> Fragment {
     let tex_coord = vec2f(vertex.tex_coords[vertex.tx_index.x], vertex.tex_coords[vertex.tx_index.y]);
-    let position = vec4f(per_group_data.xy + vertex.vertex_pos * vertex.section.zw
-                         + vertex.section.xy, per_group_data.z, 1.0);
+    let glyph = vertex.vertex_pos * vertex.section.zw + vertex.section.xy;
+    let position = vec4f(per_group_data.xy + transformed(glyph, per_group_linear, vec2f(0.0)),
+                         per_group_data.z, 1.0);
     return Fragment(
         viewport * position,
         vertex.color * vec4f(1.0, 1.0, 1.0, per_group_data.w),
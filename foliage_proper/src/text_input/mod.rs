@@ -85,6 +85,7 @@ impl Attachment for TextInput {
         // sequencing, no re-entrant staleness risk.
         foliage.define(TextInputState::obs);
         foliage.define(PlaceCursor::obs);
+        foliage.define(TabbedTo::obs);
         foliage.define(Input::obs);
         foliage.define(Input::forward);
         foliage.define(InsertText::obs);
@@ -722,6 +723,49 @@ impl PlaceCursor {
         );
     }
 }
+/// Focus arrived by Tab rather than by a press: the input takes keys with its caret after the
+/// last letter, where a press at the end of its text would have put it. Sent by the focus
+/// traversal in place of a press, which could land on whatever overlaps the field.
+#[foliage_macros::targeted_event]
+#[derive(Copy)]
+pub(crate) struct TabbedTo {}
+impl TabbedTo {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn obs(
+        trigger: Trigger<TabbedTo>,
+        mut tree: Tree,
+        fonts: FontContext,
+        layout: Res<Layout>,
+        handles: Query<&Handle>,
+        values: Query<&TextValue>,
+        mut cursor: Query<&mut Cursor>,
+        glyphs: Query<&Glyphs>,
+        line_metrics: Query<&LineMetrics>,
+        mut selections: Query<&mut Selection>,
+        scroll: ScrollContext,
+    ) {
+        let this = trigger.event_target();
+        let font = fonts.font_ref(this);
+        TextInput::clear_selection(this, &mut selections);
+        tree.send_to(TextInputState::AwaitingInput, this);
+        let end = values.get(this).map(|v| v.0.len()).unwrap_or_default();
+        TextInput::move_cursor(
+            this,
+            &mut tree,
+            RequestedLocation::Offset(end),
+            &glyphs,
+            font,
+            &fonts,
+            *layout,
+            &handles,
+            &mut cursor,
+            &line_metrics,
+            &scroll,
+            true,
+            true,
+        );
+    }
+}
 /// Ephemeral hand-off value: was a `Component` (`RequestedLocation`) written by one system and
 /// immediately consumed by `MoveCursor`'s observer -- nothing else ever read it as persistent
 /// state, so it's now just an argument passed directly into `TextInput::move_cursor`.
@@ -1443,6 +1487,29 @@ pub(crate) struct Input {
     pub(crate) sequence: InputSequence,
 }
 impl Input {
+    /// The text input keys are going to: the one holding focus, when focus sits on the input
+    /// itself or one of the parts of it that take presses.
+    pub(crate) fn receiver(
+        current_interaction: &CurrentInteraction,
+        stems: &Query<&Parent>,
+        text_inputs: &Query<&TextInput>,
+        handles: &Query<&Handle>,
+    ) -> Option<Entity> {
+        let f = current_interaction.focused?;
+        let main = Parent::ascend_to::<TextInput>(f, stems, text_inputs);
+        let handle = handles.get(main).ok()?;
+        (f == main || f == handle.panel || f == handle.text || f == handle.cursor).then_some(main)
+    }
+    /// Whether a text input receiving `sequence` does something with it -- the same split
+    /// `obs` makes. A binding edits or moves the caret, except `Tab` and `Escape`, which are
+    /// only broadcast for an enclosing composite to route; any other character is typed.
+    pub(crate) fn consumes(key_bindings: &KeyBindings, sequence: &InputSequence) -> bool {
+        match key_bindings.action(sequence) {
+            Some(TextInputAction::Tab | TextInputAction::Escape) => false,
+            Some(_) => true,
+            None => matches!(sequence.key, Key::Character(_)),
+        }
+    }
     pub(crate) fn forward(
         trigger: Trigger<InputSequence>,
         mut tree: Tree,
@@ -1451,14 +1518,7 @@ impl Input {
         current_interaction: Res<CurrentInteraction>,
         handles: Query<&Handle>,
     ) {
-        if let Some(f) = current_interaction.focused {
-            let main = Parent::ascend_to::<TextInput>(f, &stems, &text_inputs);
-            let Ok(handle) = handles.get(main) else {
-                return;
-            };
-            if f != main && f != handle.panel && f != handle.text && f != handle.cursor {
-                return;
-            }
+        if let Some(main) = Input::receiver(&current_interaction, &stems, &text_inputs, &handles) {
             tree.send_to(
                 Input {
                     entity: Entity::PLACEHOLDER,
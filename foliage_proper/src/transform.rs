@@ -0,0 +1,343 @@
+use crate::anim::interpolation::Interpolations;
+use crate::ash::clip::{ClipToViewport, InheritedClip, ResolvedClip, clip_of};
+use crate::ginkgo::viewport::ViewportHandle;
+use crate::{
+    Animate, Attachment, Children, Component, CoordinateContext, Coordinates, Foliage, Logical,
+    Parent, Position, Resolve, Resource, Section, Tree, View, Zoom,
+};
+use bevy_ecs::entity::Entity;
+use bevy_ecs::prelude::IntoScheduleConfigs;
+use bevy_ecs::query::With;
+use bevy_ecs::system::{Query, Res, ResMut};
+use std::collections::HashSet;
+
+/// Turns and scales an element and everything beneath it as it is drawn, about a point in
+/// its own box -- given with [`Author::transform`](crate::Sprout::transform), set with
+/// [`Grows::transform`](crate::Grows::transform), and tweened as
+/// [`Motion::Transform`](crate::Motion::Transform).
+///
+/// Taken after layout, so nothing around it moves to make room: the box keeps the place its
+/// `Location` gave it, and what draws there is turned about [`origin`](Self::origin) by
+/// [`rotate`](Self::rotate) and stretched by [`scale`](Self::scale). A press lands where the
+/// element is drawn, and what it contains is clipped to the box as drawn. Nested, they
+/// compose -- a child turned inside a turned parent turns by both.
+#[derive(Component, Copy, Clone, Debug, PartialEq)]
+pub struct Transform {
+    /// Radians, clockwise on screen.
+    pub rotate: f32,
+    /// Horizontal and vertical factors; `1.0` is drawn as laid out.
+    pub scale: Coordinates,
+    /// The point held still, as fractions of the box -- `(0.5, 0.5)` its middle,
+    /// `(0.0, 0.0)` its top-left corner.
+    pub origin: Coordinates,
+}
+impl Attachment for Transform {
+    fn attach(foliage: &mut Foliage) {
+        foliage.enable_animation::<Self>();
+        foliage.world.insert_resource(Transformed::default());
+        foliage
+            .diff
+            .add_systems(resolve.in_set(crate::foliage::DiffMarkers::Finalize));
+    }
+}
+impl Transform {
+    /// Drawn as laid out, about the middle of the box.
+    pub fn new() -> Self {
+        Self {
+            rotate: 0.0,
+            scale: Coordinates::new(1.0, 1.0),
+            origin: Coordinates::new(0.5, 0.5),
+        }
+    }
+    /// Turned by `radians`, clockwise.
+    pub fn rotate(mut self, radians: f32) -> Self {
+        self.rotate = radians;
+        self
+    }
+    /// Scaled by `factor` on both axes.
+    pub fn scale(mut self, factor: f32) -> Self {
+        self.scale = Coordinates::new(factor, factor);
+        self
+    }
+    /// Scaled by `x` across and `y` down.
+    pub fn scale_xy(mut self, x: f32, y: f32) -> Self {
+        self.scale = Coordinates::new(x, y);
+        self
+    }
+    /// Held still at `(x, y)`, as fractions of the box.
+    pub fn origin(mut self, x: f32, y: f32) -> Self {
+        self.origin = Coordinates::new(x, y);
+        self
+    }
+    /// This transform about its origin in `section`, as the affine it draws with.
+    fn about(&self, section: Section<Logical>) -> ResolvedTransform {
+        let (sin, cos) = self.rotate.sin_cos();
+        let (sx, sy) = (self.scale.a(), self.scale.b());
+        let linear = [cos * sx, sin * sx, -sin * sy, cos * sy];
+        let pivot = Position::logical((
+            section.left() + section.width() * self.origin.a(),
+            section.top() + section.height() * self.origin.b(),
+        ));
+        // `pivot - M * pivot`, so the pivot is where it maps to.
+        let turned = ResolvedTransform {
+            linear,
+            translation: [0.0, 0.0],
+        }
+        .apply(pivot);
+        ResolvedTransform {
+            linear,
+            translation: [pivot.left() - turned.left(), pivot.top() - turned.top()],
+        }
+    }
+}
+impl Default for Transform {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl Animate for Transform {
+    fn interpolations(start: &Self, end: &Self) -> Interpolations {
+        Interpolations::new()
+            .with(start.rotate, end.rotate)
+            .with(start.scale.a(), end.scale.a())
+            .with(start.scale.b(), end.scale.b())
+            .with(start.origin.a(), end.origin.a())
+            .with(start.origin.b(), end.origin.b())
+    }
+
+    fn apply(&mut self, interpolations: &mut Interpolations) {
+        if let Some(r) = interpolations.read(0) {
+            self.rotate = r;
+        }
+        if let Some(x) = interpolations.read(1) {
+            self.scale.set_horizontal(x);
+        }
+        if let Some(y) = interpolations.read(2) {
+            self.scale.set_vertical(y);
+        }
+        if let Some(x) = interpolations.read(3) {
+            self.origin.set_horizontal(x);
+        }
+        if let Some(y) = interpolations.read(4) {
+            self.origin.set_vertical(y);
+        }
+    }
+}
+
+/// Every [`Transform`] above an entity, its own included, and every [`Zoom`](crate::Zoom)
+/// above it, composed into the one affine its `Section` is drawn through: `linear` is the 2x2
+/// matrix by columns, `translation` the offset after it, in logical pixels. Identity for
+/// anything nothing turns or magnifies -- which is everything, until a `Transform` is set or
+/// a view zooms. Read-only; maintained by [`resolve`].
+#[derive(Component, Copy, Clone, Debug, PartialEq)]
+pub(crate) struct ResolvedTransform {
+    pub(crate) linear: [f32; 4],
+    pub(crate) translation: [f32; 2],
+}
+impl ResolvedTransform {
+    pub(crate) const IDENTITY: Self = Self {
+        linear: [1.0, 0.0, 0.0, 1.0],
+        translation: [0.0, 0.0],
+    };
+    pub(crate) fn apply<C: CoordinateContext>(&self, p: Position<C>) -> Position<C> {
+        let [a, b, c, d] = self.linear;
+        Position::new((
+            a * p.left() + c * p.top() + self.translation[0],
+            b * p.left() + d * p.top() + self.translation[1],
+        ))
+    }
+    /// Where `p` was before this moved it -- how a press is taken back into the box it
+    /// landed on. `None` when the transform has collapsed the box to a line or a point,
+    /// which nothing can land on.
+    pub(crate) fn invert<C: CoordinateContext>(&self, p: Position<C>) -> Option<Position<C>> {
+        let [a, b, c, d] = self.linear;
+        let det = a * d - b * c;
+        if det.abs() <= f32::EPSILON {
+            return None;
+        }
+        let (x, y) = (
+            p.left() - self.translation[0],
+            p.top() - self.translation[1],
+        );
+        Some(Position::new((
+            (d * x - c * y) / det,
+            (a * y - b * x) / det,
+        )))
+    }
+    /// Scaled by `factor` about the origin -- a [`Zoom`](crate::Zoom), as
+    /// [`View::child_on_screen`](crate::View::child_on_screen) states it.
+    fn magnify(factor: f32) -> Self {
+        Self {
+            linear: [factor, 0.0, 0.0, factor],
+            translation: [0.0, 0.0],
+        }
+    }
+    /// `inner` first, then this.
+    fn then(&self, inner: &Self) -> Self {
+        let [a, b, c, d] = self.linear;
+        let [e, f, g, h] = inner.linear;
+        let moved = self.apply(Position::<Logical>::new((
+            inner.translation[0],
+            inner.translation[1],
+        )));
+        Self {
+            linear: [a * e + c * f, b * e + d * f, a * g + c * h, b * g + d * h],
+            translation: [moved.left(), moved.top()],
+        }
+    }
+    /// The upright box around `section` as drawn -- all a scissor, or a clip, can hold.
+    pub(crate) fn bounds<C: CoordinateContext>(&self, section: Section<C>) -> Section<C> {
+        if *self == Self::IDENTITY {
+            return section;
+        }
+        let corners = [
+            (section.left(), section.top()),
+            (section.right(), section.top()),
+            (section.left(), section.bottom()),
+            (section.right(), section.bottom()),
+        ]
+        .map(|c| self.apply(Position::<C>::new(c)));
+        let (mut l, mut t, mut r, mut b) = (f32::MAX, f32::MAX, f32::MIN, f32::MIN);
+        for p in corners {
+            l = l.min(p.left());
+            t = t.min(p.top());
+            r = r.max(p.left());
+            b = b.max(p.top());
+        }
+        Section::new((l, t), (r - l, b - t))
+    }
+    /// The same transform for device pixels: the matrix has no units, the offset does.
+    pub(crate) fn to_physical(self, factor: f32) -> Self {
+        Self {
+            linear: self.linear,
+            translation: self.translation.map(|t| t * factor),
+        }
+    }
+    /// Laid out for an instance buffer, in device pixels.
+    pub(crate) fn c_repr(&self, factor: f32) -> CReprTransform {
+        let [a, b, c, d] = self.linear;
+        CReprTransform {
+            linear: [a - 1.0, b, c, d - 1.0],
+            translation: self.translation.map(|t| t * factor),
+        }
+    }
+}
+impl Default for ResolvedTransform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+/// [`ResolvedTransform`] as six contiguous floats, for the GPU. The matrix is stored as its
+/// difference from identity, so a slot nothing has been written to yet -- all zeroes -- draws
+/// as laid out rather than collapsed to a point.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub(crate) struct CReprTransform {
+    linear: [f32; 4],
+    translation: [f32; 2],
+}
+
+/// Everything [`resolve`] reached last frame, so what it no longer reaches can be set back.
+#[derive(Resource, Default)]
+pub(crate) struct Transformed(HashSet<Entity>);
+
+/// Composes every [`Transform`] down its subtree into each entity's [`ResolvedTransform`],
+/// and clips what is inside a turned box to that box as drawn. A [`Zoom`](crate::Zoom) view
+/// is composed the same way, as the scale its children are drawn at.
+///
+/// Walked every frame from each outermost `Transform` or `Zoom`, rather than on change: what an entity
+/// is drawn through depends on every box above it up to that root, and any of them moving --
+/// a scroll, a reflow, a tween -- moves it. Writes land only where the value differs, so an
+/// untouched subtree wakes no differential.
+///
+/// The clips are written the way `propagate_offsets` writes them, straight onto the
+/// components: the observer path that also writes them knows only the box as laid out, and
+/// runs earlier in the frame, so this has the last word. An entity this no longer reaches is
+/// set back to identity and its clip resolved again from its ancestors.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn resolve(
+    transforms: Query<(Entity, &Transform)>,
+    zooms: Query<(Entity, &View), With<Zoom>>,
+    stems: Query<&Parent>,
+    branches: Query<&Children>,
+    sections: Query<&Section<Logical>>,
+    mut resolved: Query<&mut ResolvedTransform>,
+    mut clips: Query<&mut ResolvedClip>,
+    mut inherited_clips: Query<&mut InheritedClip>,
+    marked: Query<(), With<ClipToViewport>>,
+    viewport: Res<ViewportHandle>,
+    mut transformed: ResMut<Transformed>,
+    mut tree: Tree,
+) {
+    let viewport = viewport.section();
+    let roots = transforms
+        .iter()
+        .map(|(e, _)| e)
+        .chain(zooms.iter().map(|(e, _)| e));
+    let outermost = roots.filter(|e| {
+        let mut up = stems.get(*e).ok().and_then(|s| s.id);
+        while let Some(id) = up {
+            if transforms.contains(id) || zooms.contains(id) {
+                return false;
+            }
+            up = stems.get(id).ok().and_then(|s| s.id);
+        }
+        true
+    });
+    let mut reached = HashSet::new();
+    for root in outermost.collect::<Vec<_>>() {
+        let inherited = inherited_clips.get(root).map(|c| c.0).unwrap_or_default();
+        let mut stack = vec![(root, ResolvedTransform::IDENTITY, inherited)];
+        while let Some((entity, above, inherited)) = stack.pop() {
+            let Ok(section) = sections.get(entity).copied() else {
+                continue;
+            };
+            reached.insert(entity);
+            let own = match transforms.get(entity) {
+                Ok((_, t)) => above.then(&t.about(section)),
+                Err(_) => above,
+            };
+            if let Ok(mut current) = resolved.get_mut(entity)
+                && *current != own
+            {
+                *current = own;
+            }
+            let (clip, base) = clip_of(
+                own.bounds(section),
+                inherited,
+                marked.contains(entity),
+                viewport,
+            );
+            if let Ok(mut current) = clips.get_mut(entity)
+                && *current != clip
+            {
+                *current = clip;
+            }
+            if entity != root
+                && let Ok(mut current) = inherited_clips.get_mut(entity)
+                && current.0 != inherited
+            {
+                current.0 = inherited;
+            }
+            let within = match zooms.get(entity) {
+                Ok((_, view)) => own.then(&ResolvedTransform::magnify(view.zoom)),
+                Err(_) => own,
+            };
+            if let Ok(branch) = branches.get(entity) {
+                for child in branch.ids.iter().copied() {
+                    stack.push((child, within, Some(base)));
+                }
+            }
+        }
+    }
+    for left in transformed.0.difference(&reached) {
+        if !sections.contains(*left) {
+            continue;
+        }
+        if let Ok(mut current) = resolved.get_mut(*left) {
+            *current = ResolvedTransform::IDENTITY;
+        }
+        tree.send_to(Resolve::<InheritedClip>::new(), *left);
+    }
+    transformed.0 = reached;
+}
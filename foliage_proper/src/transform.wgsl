@@ -0,0 +1,8 @@
+// `ResolvedTransform`'s affine, prepended to every shader that draws through one (see the
+// `format!` in each pipeline's `renderer`). `linear` is the 2x2 matrix by columns, less
+// identity -- so an instance slot nothing has written yet draws where it was laid out --
+// and `translation` the offset after it, both in physical pixels.
+fn transformed(p: vec2<f32>, linear: vec4<f32>, translation: vec2<f32>) -> vec2<f32> {
+    let m = mat2x2<f32>(linear.xy + vec2<f32>(1.0, 0.0), linear.zw + vec2<f32>(0.0, 1.0));
+    return m * p + translation;
+}
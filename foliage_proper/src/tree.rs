@@ -5,7 +5,7 @@ use crate::asset::{AssetLoader, AssetRetrieval, OnRetrieval};
 use crate::disable::Disable;
 use crate::enable::Enable;
 use crate::node::Node;
-use crate::node::{Parent, SpawnedAt};
+use crate::node::{GrowOrder, Parent, SpawnedAt};
 use crate::ops::{Name, StoredKey};
 use crate::remove::Remove;
 use crate::{Animate, Animation, AssetKey, Author, TimeDelta, Timer};
@@ -256,6 +256,7 @@ impl<'w, 's> Tree<'w, 's> {
             this,
             (seed.location, seed.stem, elevation, spawned_at, spec.root()),
         );
+        self.commands.queue(GrowOrder::assign(this));
         S::build(this, self);
         this
     }
@@ -275,12 +276,33 @@ impl<'w, 's> Tree<'w, 's> {
         if let Some(grid) = seed.grid {
             self.write_to(this, grid);
         }
+        if let Some(flow) = seed.flow {
+            self.write_to(this, flow);
+        }
+        if let Some(flow_item) = seed.flow_item {
+            self.write_to(this, flow_item);
+        }
+        if let Some(template) = &seed.template {
+            self.write_to(this, template.clone());
+        }
+        if let Some(grid_item) = &seed.grid_item {
+            self.write_to(this, grid_item.clone());
+        }
         if let Some(anchor) = seed.anchor {
             self.write_to(this, anchor);
         }
+        if let Some(timing) = seed.transition.clone() {
+            self.write_to(this, crate::grid::transition::Transition(timing));
+        }
         if let Some(opacity) = seed.opacity {
             self.write_to(this, opacity);
         }
+        if let Some(transform) = seed.transform {
+            self.write_to(this, transform);
+        }
+        if seed.layer {
+            self.write_to(this, crate::Layer);
+        }
         if let Some((h, v)) = seed.alignment {
             self.write_to(this, (h, v));
         }
@@ -290,12 +312,21 @@ impl<'w, 's> Tree<'w, 's> {
         if let Some(propagation) = seed.propagation {
             self.write_to(this, propagation);
         }
-        if let Some(shape) = seed.shape {
+        if let Some(shape) = seed.shape.clone() {
             self.write_to(this, shape);
         }
         if seed.clip_to_viewport {
             self.write_to(this, crate::ClipToViewport);
         }
+        if seed.drop_target {
+            self.write_to(this, crate::DropTarget);
+        }
+        if let Some(tab_index) = seed.tab_index {
+            self.write_to(this, tab_index);
+        }
+        if let Some(cursor) = seed.cursor {
+            self.write_to(this, cursor);
+        }
         if let Some(aspect) = seed.aspect {
             self.write_to(this, aspect);
         }
@@ -308,6 +339,18 @@ impl<'w, 's> Tree<'w, 's> {
         if let Some(directional_lock) = seed.directional_lock {
             self.write_to(this, directional_lock);
         }
+        if let Some(scroll_snap) = seed.scroll_snap {
+            self.write_to(this, scroll_snap);
+        }
+        if let Some(sticky) = seed.sticky {
+            self.write_to(this, sticky);
+        }
+        if let Some(scrollbar) = seed.scrollbar {
+            self.write_to(this, scrollbar);
+        }
+        if let Some(zoom) = seed.zoom {
+            self.write_to(this, zoom);
+        }
         if let Some(font) = seed.font {
             self.write_to(this, font);
         }
@@ -342,6 +385,7 @@ impl<'w, 's> Tree<'w, 's> {
         });
         self.trimmings(at, &seed);
         self.write_to(at, bundle);
+        self.commands.queue(GrowOrder::assign(at));
         S::build(at, self);
     }
     /// Sprout `spec` as a top-level entity -- no parent, the [`branch`](Self::branch)